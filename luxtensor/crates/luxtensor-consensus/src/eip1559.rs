@@ -34,8 +34,41 @@ pub struct Eip1559Config {
     pub min_base_fee: u128,
     /// Maximum base fee (wei)
     pub max_base_fee: u128,
+    /// Number of recent blocks' reward samples kept for
+    /// `estimate_eip1559_fees` (a ring buffer, oldest evicted first).
+    pub fee_history_window: usize,
+    /// Percentile (0.0..=100.0) of each block's gas-weighted transaction
+    /// rewards sampled into the fee history ring buffer, via
+    /// `FeeMarket::record_fee_sample`.
+    pub fee_history_percentile: f64,
+    /// Percentile (0.0..=100.0) of the stored per-block reward samples used
+    /// to summarize `estimate_eip1559_fees`'s priority fee. Default 50.0
+    /// (median).
+    pub priority_fee_percentile: f64,
+    /// Below this base fee, `estimate_eip1559_fees` skips the history-based
+    /// estimate and returns `quiet_period_priority_fee` directly, to avoid
+    /// overpaying during quiet periods where history is thin or stale.
+    pub quiet_period_base_fee_threshold: u128,
+    /// Priority fee returned by `estimate_eip1559_fees` during quiet
+    /// periods, and whenever there is no reward history to sample from.
+    pub quiet_period_priority_fee: u128,
+    /// Numerator of the packing-efficiency target used by
+    /// `calculate_next_base_fee_packed` (default 4, paired with
+    /// `packing_efficiency_denominator` for a 4/5 target).
+    pub packing_efficiency_numerator: u64,
+    /// Denominator of the packing-efficiency target (default 5).
+    pub packing_efficiency_denominator: u64,
+    /// Blocks produced per height (tipset size) on chains with multi-leader
+    /// rounds. Defaults to 1 so single-block behavior is unchanged; used by
+    /// callers of `calculate_next_base_fee_packed` as the `num_blocks` input.
+    pub blocks_per_height: usize,
 }
 
+/// Default packing-efficiency numerator, see `Eip1559Config::packing_efficiency_numerator`.
+pub const PACKING_NUM: u64 = 4;
+/// Default packing-efficiency denominator, see `Eip1559Config::packing_efficiency_denominator`.
+pub const PACKING_DENOM: u64 = 5;
+
 impl Default for Eip1559Config {
     fn default() -> Self {
         Self {
@@ -45,10 +78,43 @@ impl Default for Eip1559Config {
             base_fee_max_change_denominator: 8, // 12.5% max change
             min_base_fee: 100_000_000, // 0.1 gwei - spam protection but accessible
             max_base_fee: 100_000_000_000, // 100 gwei emergency cap (was 10000)
+            fee_history_window: 20,
+            fee_history_percentile: 5.0,
+            priority_fee_percentile: 50.0, // median
+            quiet_period_base_fee_threshold: 1_000_000_000, // 1 gwei
+            quiet_period_priority_fee: 3_000_000_000, // 3 gwei
+            packing_efficiency_numerator: PACKING_NUM,
+            packing_efficiency_denominator: PACKING_DENOM,
+            blocks_per_height: 1,
         }
     }
 }
 
+/// A transaction's fee bid, in whichever form its envelope carries, so
+/// `FeeMarket::effective_tip_per_gas`/`effective_gas_price` can rank legacy
+/// and EIP-1559 transactions by the same miner-tip metric instead of
+/// special-casing each type at the call site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxFeeParams {
+    /// Pre-EIP-1559 (and EIP-2930 access-list) transactions: a single flat
+    /// gas price.
+    Legacy { gas_price: u128 },
+    /// EIP-1559 transactions: bid `max_fee_per_gas`/`max_priority_fee_per_gas`
+    /// against the block's base fee.
+    Eip1559 { max_fee_per_gas: u128, max_priority_fee_per_gas: u128 },
+}
+
+/// One block's fee sample, recorded via `FeeMarket::record_fee_sample` and
+/// consumed by `FeeMarket::estimate_eip1559_fees`.
+#[derive(Debug, Clone, Copy)]
+pub struct FeeSample {
+    /// This block's base fee per gas (wei)
+    pub base_fee: u128,
+    /// This block's gas-weighted reward at `config.fee_history_percentile`,
+    /// or `None` for an empty block (no transactions to sample from).
+    pub reward: Option<u128>,
+}
+
 /// Fee market state for tracking dynamic pricing
 #[derive(Debug, Clone)]
 pub struct FeeMarket {
@@ -60,6 +126,9 @@ pub struct FeeMarket {
     pub last_gas_used: u64,
     /// Current block number
     pub block_number: u64,
+    /// Bounded ring buffer of recent blocks' fee samples, most-recent last.
+    /// Fed by `record_fee_sample`, read by `estimate_eip1559_fees`.
+    pub reward_history: std::collections::VecDeque<FeeSample>,
 }
 
 impl FeeMarket {
@@ -71,6 +140,7 @@ impl FeeMarket {
             config,
             last_gas_used: 0,
             block_number: 0,
+            reward_history: std::collections::VecDeque::new(),
         }
     }
 
@@ -82,6 +152,7 @@ impl FeeMarket {
             config,
             last_gas_used: 0,
             block_number: 0,
+            reward_history: std::collections::VecDeque::new(),
         }
     }
 
@@ -127,6 +198,51 @@ impl FeeMarket {
         self.block_number += 1;
     }
 
+    /// Filecoin-style packing-efficiency base fee update for chains that
+    /// produce several blocks per height (tipsets): instead of targeting a
+    /// flat 50% of a single block's gas limit, the target scales with
+    /// `num_blocks` and the configured packing-efficiency ratio (default
+    /// 4/5), i.e. `num_blocks * block_gas_limit * packing_efficiency_numerator
+    /// / packing_efficiency_denominator`. The same proportional
+    /// `±1/base_fee_max_change_denominator` adjustment is then applied and
+    /// clamped to `[min_base_fee, max_base_fee]`, exactly as in
+    /// `calculate_next_base_fee`.
+    pub fn calculate_next_base_fee_packed(&self, total_gas_used: u64, num_blocks: usize) -> u128 {
+        let denominator = self.config.base_fee_max_change_denominator as u128;
+        let target = (num_blocks as u64)
+            .saturating_mul(self.config.block_gas_limit)
+            .saturating_mul(self.config.packing_efficiency_numerator)
+            / self.config.packing_efficiency_denominator.max(1);
+
+        if total_gas_used == target {
+            return self.base_fee;
+        }
+
+        let new_base_fee = if total_gas_used > target {
+            let gas_used_delta = (total_gas_used - target) as u128;
+            let base_fee_delta = self.base_fee * gas_used_delta / target.max(1) as u128 / denominator;
+            self.base_fee.saturating_add(base_fee_delta.max(1))
+        } else {
+            let gas_used_delta = (target - total_gas_used) as u128;
+            let base_fee_delta = self.base_fee * gas_used_delta / target.max(1) as u128 / denominator;
+            self.base_fee.saturating_sub(base_fee_delta)
+        };
+
+        new_base_fee
+            .max(self.config.min_base_fee)
+            .min(self.config.max_base_fee)
+    }
+
+    /// Update fee market after a tipset (multi-block height) is produced,
+    /// using `calculate_next_base_fee_packed` instead of the single-block
+    /// update in `on_block_produced`.
+    pub fn on_tipset_produced(&mut self, total_gas_used: u64, num_blocks: usize) {
+        let new_base_fee = self.calculate_next_base_fee_packed(total_gas_used, num_blocks);
+        self.base_fee = new_base_fee;
+        self.last_gas_used = total_gas_used;
+        self.block_number += 1;
+    }
+
     /// Calculate effective gas price for transaction
     ///
     /// # Parameters
@@ -160,6 +276,45 @@ impl FeeMarket {
         self.base_fee
     }
 
+    /// Tip per gas a transaction pays the block producer, unifying legacy
+    /// and EIP-1559 pricing so both can be ranked by the same miner-tip
+    /// metric. Returns `None` if `params`'s max payable price is below
+    /// `base_fee` (the transaction cannot be included).
+    pub fn effective_tip_per_gas(&self, params: &TxFeeParams, base_fee: u128) -> Option<u128> {
+        match *params {
+            TxFeeParams::Legacy { gas_price } => {
+                if gas_price < base_fee {
+                    return None;
+                }
+                Some(gas_price.saturating_sub(base_fee))
+            }
+            TxFeeParams::Eip1559 { max_fee_per_gas, max_priority_fee_per_gas } => {
+                if max_fee_per_gas < base_fee {
+                    return None;
+                }
+                Some(max_priority_fee_per_gas.min(max_fee_per_gas - base_fee))
+            }
+        }
+    }
+
+    /// Effective gas price paid per unit of gas for `params`, unifying
+    /// legacy and EIP-1559 pricing. Returns `None` under the same condition
+    /// as `effective_tip_per_gas`.
+    pub fn effective_gas_price(&self, params: &TxFeeParams, base_fee: u128) -> Option<u128> {
+        match *params {
+            TxFeeParams::Legacy { gas_price } => {
+                if gas_price < base_fee {
+                    return None;
+                }
+                Some(gas_price)
+            }
+            TxFeeParams::Eip1559 { .. } => {
+                let tip = self.effective_tip_per_gas(params, base_fee)?;
+                Some(base_fee.saturating_add(tip))
+            }
+        }
+    }
+
     /// Estimate max_fee_per_gas for fast inclusion (2x current base fee)
     pub fn estimate_fast_max_fee(&self) -> u128 {
         self.base_fee.saturating_mul(2)
@@ -192,6 +347,111 @@ impl FeeMarket {
 
         priority_gwei * 1_000_000_000 // Convert to wei
     }
+
+    /// Record this block's fee sample into the bounded history ring buffer
+    /// `estimate_eip1559_fees` reads from. Callers typically compute
+    /// `reward` via `gas_weighted_rewards` at `config.fee_history_percentile`
+    /// (or pass `None` for an empty block).
+    pub fn record_fee_sample(&mut self, base_fee: u128, reward: Option<u128>) {
+        self.reward_history.push_back(FeeSample { base_fee, reward });
+        while self.reward_history.len() > self.config.fee_history_window {
+            self.reward_history.pop_front();
+        }
+    }
+
+    /// Suggested `maxPriorityFeePerGas` derived from the actual pending
+    /// transaction set, mirroring an `eth_maxPriorityFeePerGas`-style RPC.
+    ///
+    /// Each transaction's effective tip is computed against the current
+    /// `base_fee` via `effective_tip_per_gas`; transactions whose total
+    /// payable price is zero (zero-cost/system transactions, or ones that
+    /// can't afford the base fee) are skipped so they don't drag the
+    /// estimate down. The remaining tips are sorted and the value at
+    /// `percentile` (0..=100, default 50th) is returned — the tip the
+    /// "worst still-includable" transaction is paying. Falls back to
+    /// `config.min_base_fee` if every candidate was zero-cost.
+    pub fn priority_fee_from_pool(&self, txs: &[TxFeeParams], percentile: usize) -> u128 {
+        let mut tips: Vec<u128> = txs
+            .iter()
+            .filter(|params| {
+                !matches!(
+                    params,
+                    TxFeeParams::Legacy { gas_price: 0 }
+                        | TxFeeParams::Eip1559 { max_fee_per_gas: 0, .. }
+                )
+            })
+            .filter_map(|params| self.effective_tip_per_gas(params, self.base_fee))
+            .collect();
+
+        if tips.is_empty() {
+            return self.config.min_base_fee;
+        }
+
+        tips.sort_unstable();
+        percentile_of_sorted(&tips, percentile as f64)
+    }
+
+    /// Convenience wrapper around `record_fee_sample` that derives the
+    /// reward from `block` itself, at `config.fee_history_percentile`.
+    pub fn record_block_fee_sample(&mut self, block: &BlockFeeData) {
+        let reward = if block.tx_rewards.is_empty() {
+            None
+        } else {
+            gas_weighted_rewards(block, &[self.config.fee_history_percentile])
+                .into_iter()
+                .next()
+        };
+        self.record_fee_sample(block.base_fee, reward);
+    }
+
+    /// Data-driven EIP-1559 fee suggestion, replacing the crude step
+    /// function in `suggested_priority_fee`.
+    ///
+    /// The priority fee is `config.priority_fee_percentile` (default
+    /// median) of the recent per-block reward samples in `reward_history`
+    /// (each itself the block's `config.fee_history_percentile` gas-weighted
+    /// reward); blocks with no samples are ignored. If the current base fee
+    /// is below `config.quiet_period_base_fee_threshold`, or there is no
+    /// history to sample from, `config.quiet_period_priority_fee` is
+    /// returned directly instead, to avoid overpaying in quiet periods.
+    ///
+    /// Returns `(max_fee_per_gas, max_priority_fee_per_gas)` where
+    /// `max_fee_per_gas = base_fee * 2 + max_priority_fee_per_gas`.
+    pub fn estimate_eip1559_fees(&self) -> (u128, u128) {
+        let max_priority_fee_per_gas = if self.base_fee < self.config.quiet_period_base_fee_threshold {
+            self.config.quiet_period_priority_fee
+        } else {
+            let mut samples: Vec<u128> = self
+                .reward_history
+                .iter()
+                .filter_map(|sample| sample.reward)
+                .collect();
+
+            if samples.is_empty() {
+                self.config.quiet_period_priority_fee
+            } else {
+                samples.sort_unstable();
+                percentile_of_sorted(&samples, self.config.priority_fee_percentile)
+            }
+        };
+
+        let max_fee_per_gas = self
+            .base_fee
+            .saturating_mul(2)
+            .saturating_add(max_priority_fee_per_gas);
+
+        (max_fee_per_gas, max_priority_fee_per_gas)
+    }
+}
+
+/// Nearest-rank percentile of an already-sorted (ascending) slice.
+fn percentile_of_sorted(sorted: &[u128], percentile: f64) -> u128 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = ((percentile / 100.0) * (sorted.len() - 1) as f64).round();
+    let idx = (rank as usize).min(sorted.len() - 1);
+    sorted[idx]
 }
 
 impl Default for FeeMarket {
@@ -203,7 +463,8 @@ impl Default for FeeMarket {
 /// Fee estimation response (for RPC eth_feeHistory)
 #[derive(Debug, Clone)]
 pub struct FeeHistory {
-    /// Base fee per gas for each block
+    /// Base fee per gas for each block, plus one extrapolated entry for the
+    /// next (not-yet-produced) block.
     pub base_fees: Vec<u128>,
     /// Gas used ratio for each block (0.0 to 1.0+)
     pub gas_used_ratios: Vec<f64>,
@@ -213,6 +474,159 @@ pub struct FeeHistory {
     pub reward: Option<Vec<Vec<u128>>>,
 }
 
+/// Errors validating an `eth_feeHistory` request.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum FeeHistoryError {
+    #[error("block_count {0} is out of range 1..=1024")]
+    BlockCountOutOfRange(u64),
+
+    #[error("requested {0} reward percentiles, limit is 100")]
+    TooManyRewardPercentiles(usize),
+
+    #[error("reward percentile {0} is out of range 0.0..=100.0")]
+    RewardPercentileOutOfRange(f64),
+
+    #[error("reward percentiles must be strictly increasing (found {0} before {1})")]
+    RewardPercentilesNotMonotonic(f64, f64),
+
+    #[error("blocks: expected {expected} entries (block_count, newest first), got {actual}")]
+    BlockDataLengthMismatch { expected: u64, actual: usize },
+}
+
+/// Maximum `block_count` accepted by `build_fee_history`, matching go-ethereum's
+/// `eth_feeHistory` implementation.
+pub const MAX_FEE_HISTORY_BLOCK_COUNT: u64 = 1024;
+
+/// Maximum number of reward percentiles accepted per `eth_feeHistory` call.
+pub const MAX_REWARD_PERCENTILES: usize = 100;
+
+/// Per-block inputs `build_fee_history` needs to compute ratios and reward
+/// percentiles, without depending on the full `Transaction`/`Receipt`
+/// types from higher layers.
+#[derive(Debug, Clone)]
+pub struct BlockFeeData {
+    /// This block's base fee per gas (wei)
+    pub base_fee: u128,
+    /// Gas used by the block
+    pub gas_used: u64,
+    /// Block gas limit
+    pub gas_limit: u64,
+    /// `(tx_gas_used, effective_priority_fee)` for every transaction in the
+    /// block, where `effective_priority_fee = effective_gas_price -
+    /// base_fee`. Order does not matter; rewards are sorted internally.
+    pub tx_rewards: Vec<(u64, u128)>,
+}
+
+impl FeeMarket {
+    /// Build a spec-compliant `eth_feeHistory` response covering the
+    /// `block_count` blocks ending at `newest_block` (inclusive).
+    ///
+    /// `blocks` must hold exactly `block_count` entries, oldest first,
+    /// ending with `newest_block`. For each requested percentile, the
+    /// reward is computed gas-weighted: a block's transactions are sorted
+    /// ascending by priority fee, then walked accumulating gas used until
+    /// it first crosses `percentile / 100 * block_gas_used` — the reward
+    /// at that transaction is the percentile's answer. Empty blocks report
+    /// a reward of `0` for every percentile.
+    pub fn build_fee_history(
+        &self,
+        block_count: u64,
+        newest_block: u64,
+        reward_percentiles: &[f64],
+        blocks: &[BlockFeeData],
+    ) -> Result<FeeHistory, FeeHistoryError> {
+        if block_count == 0 || block_count > MAX_FEE_HISTORY_BLOCK_COUNT {
+            return Err(FeeHistoryError::BlockCountOutOfRange(block_count));
+        }
+        if reward_percentiles.len() > MAX_REWARD_PERCENTILES {
+            return Err(FeeHistoryError::TooManyRewardPercentiles(reward_percentiles.len()));
+        }
+        for &p in reward_percentiles {
+            if !(0.0..=100.0).contains(&p) {
+                return Err(FeeHistoryError::RewardPercentileOutOfRange(p));
+            }
+        }
+        for window in reward_percentiles.windows(2) {
+            if window[1] <= window[0] {
+                return Err(FeeHistoryError::RewardPercentilesNotMonotonic(window[0], window[1]));
+            }
+        }
+        if blocks.len() as u64 != block_count {
+            return Err(FeeHistoryError::BlockDataLengthMismatch {
+                expected: block_count,
+                actual: blocks.len(),
+            });
+        }
+
+        let oldest_block = newest_block.saturating_sub(block_count - 1);
+
+        let mut base_fees: Vec<u128> = blocks.iter().map(|b| b.base_fee).collect();
+        let gas_used_ratios: Vec<f64> = blocks
+            .iter()
+            .map(|b| {
+                if b.gas_limit == 0 {
+                    0.0
+                } else {
+                    b.gas_used as f64 / b.gas_limit as f64
+                }
+            })
+            .collect();
+
+        // Extrapolate the base fee for the next, not-yet-produced block.
+        if let Some(newest) = blocks.last() {
+            base_fees.push(self.calculate_next_base_fee(newest.gas_used));
+        }
+
+        let reward = if reward_percentiles.is_empty() {
+            None
+        } else {
+            Some(
+                blocks
+                    .iter()
+                    .map(|block| gas_weighted_rewards(block, reward_percentiles))
+                    .collect(),
+            )
+        };
+
+        Ok(FeeHistory {
+            base_fees,
+            gas_used_ratios,
+            oldest_block,
+            reward,
+        })
+    }
+}
+
+/// Gas-weighted reward percentiles for a single block: sort transactions
+/// ascending by priority fee, then find — for each requested percentile —
+/// the reward of the transaction at which cumulative gas used first
+/// crosses `percentile / 100 * block_gas_used`.
+pub fn gas_weighted_rewards(block: &BlockFeeData, percentiles: &[f64]) -> Vec<u128> {
+    if block.tx_rewards.is_empty() {
+        return vec![0; percentiles.len()];
+    }
+
+    let mut sorted = block.tx_rewards.clone();
+    sorted.sort_by_key(|&(_, reward)| reward);
+
+    percentiles
+        .iter()
+        .map(|&p| {
+            let threshold = (p / 100.0) * block.gas_used as f64;
+            let mut cumulative_gas = 0u64;
+            for &(gas_used, reward) in &sorted {
+                cumulative_gas += gas_used;
+                if cumulative_gas as f64 >= threshold {
+                    return reward;
+                }
+            }
+            // Threshold not reached (e.g. block.gas_used understates the
+            // sum of tx gas) — fall back to the highest-paying tx.
+            sorted.last().map(|&(_, reward)| reward).unwrap_or(0)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -323,4 +737,393 @@ mod tests {
         assert!(normal > slow);
         assert!(slow > market.base_fee);
     }
+
+    fn block(base_fee: u128, gas_used: u64, gas_limit: u64, tx_rewards: Vec<(u64, u128)>) -> BlockFeeData {
+        BlockFeeData { base_fee, gas_used, gas_limit, tx_rewards }
+    }
+
+    #[test]
+    fn test_fee_history_rejects_bad_block_count() {
+        let market = FeeMarket::new();
+        assert!(matches!(
+            market.build_fee_history(0, 10, &[], &[]),
+            Err(FeeHistoryError::BlockCountOutOfRange(0))
+        ));
+        assert!(matches!(
+            market.build_fee_history(MAX_FEE_HISTORY_BLOCK_COUNT + 1, 10, &[], &[]),
+            Err(FeeHistoryError::BlockCountOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_fee_history_rejects_too_many_percentiles() {
+        let market = FeeMarket::new();
+        let percentiles: Vec<f64> = (0..=MAX_REWARD_PERCENTILES).map(|i| i as f64).collect();
+        let blocks = vec![block(1, 0, 100, vec![])];
+        assert!(matches!(
+            market.build_fee_history(1, 10, &percentiles, &blocks),
+            Err(FeeHistoryError::TooManyRewardPercentiles(_))
+        ));
+    }
+
+    #[test]
+    fn test_fee_history_rejects_percentile_out_of_range() {
+        let market = FeeMarket::new();
+        let blocks = vec![block(1, 0, 100, vec![])];
+        assert!(matches!(
+            market.build_fee_history(1, 10, &[50.0, 100.1], &blocks),
+            Err(FeeHistoryError::RewardPercentileOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_fee_history_rejects_non_monotonic_percentiles() {
+        let market = FeeMarket::new();
+        let blocks = vec![block(1, 0, 100, vec![])];
+        assert!(matches!(
+            market.build_fee_history(1, 10, &[50.0, 25.0], &blocks),
+            Err(FeeHistoryError::RewardPercentilesNotMonotonic(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_fee_history_rejects_mismatched_block_data_length() {
+        let market = FeeMarket::new();
+        let blocks = vec![block(1, 0, 100, vec![])];
+        assert!(matches!(
+            market.build_fee_history(2, 10, &[], &blocks),
+            Err(FeeHistoryError::BlockDataLengthMismatch { expected: 2, actual: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_fee_history_oldest_block_and_ratios() {
+        let market = FeeMarket::new();
+        let blocks = vec![
+            block(1_000, 5_000_000, 10_000_000, vec![]),
+            block(1_100, 10_000_000, 10_000_000, vec![]),
+        ];
+
+        let history = market.build_fee_history(2, 20, &[], &blocks).unwrap();
+        assert_eq!(history.oldest_block, 19);
+        assert_eq!(history.gas_used_ratios, vec![0.5, 1.0]);
+        // base_fees carries one extra, extrapolated entry for the next block
+        assert_eq!(history.base_fees.len(), 3);
+        assert_eq!(history.base_fees[0], 1_000);
+        assert_eq!(history.base_fees[1], 1_100);
+        assert!(history.reward.is_none());
+    }
+
+    #[test]
+    fn test_fee_history_empty_block_yields_zero_rewards() {
+        let market = FeeMarket::new();
+        let blocks = vec![block(1_000, 0, 10_000_000, vec![])];
+
+        let history = market.build_fee_history(1, 5, &[25.0, 75.0], &blocks).unwrap();
+        assert_eq!(history.reward, Some(vec![vec![0, 0]]));
+    }
+
+    #[test]
+    fn test_fee_history_gas_weighted_percentile_selection() {
+        let market = FeeMarket::new();
+        // Three transactions using 1/3 of the block's gas each, with
+        // strictly increasing priority fees.
+        let blocks = vec![block(
+            1_000,
+            3_000_000,
+            10_000_000,
+            vec![(1_000_000, 10), (1_000_000, 20), (1_000_000, 30)],
+        )];
+
+        let history = market
+            .build_fee_history(1, 5, &[10.0, 50.0, 100.0], &blocks)
+            .unwrap();
+
+        // 10th percentile falls in the first (cheapest) tx's gas window,
+        // 50th in the second, 100th requires the last (most expensive).
+        assert_eq!(history.reward, Some(vec![vec![10, 20, 30]]));
+    }
+
+    #[test]
+    fn test_estimate_fees_quiet_period_uses_default() {
+        let config = Eip1559Config {
+            initial_base_fee: 500_000_000, // 0.5 gwei, below the 1 gwei threshold
+            ..Default::default()
+        };
+        let mut market = FeeMarket::with_config(config);
+        // Even with history present, quiet-period base fee short-circuits it.
+        market.record_fee_sample(500_000_000, Some(10_000_000_000));
+
+        let (max_fee, priority_fee) = market.estimate_eip1559_fees();
+        assert_eq!(priority_fee, market.config.quiet_period_priority_fee);
+        assert_eq!(max_fee, market.base_fee * 2 + priority_fee);
+    }
+
+    #[test]
+    fn test_estimate_fees_empty_history_uses_default() {
+        let config = Eip1559Config {
+            initial_base_fee: 2_000_000_000, // 2 gwei, above quiet-period threshold
+            ..Default::default()
+        };
+        let market = FeeMarket::with_config(config);
+
+        let (_, priority_fee) = market.estimate_eip1559_fees();
+        assert_eq!(priority_fee, market.config.quiet_period_priority_fee);
+    }
+
+    #[test]
+    fn test_estimate_fees_uses_median_of_history() {
+        let config = Eip1559Config {
+            initial_base_fee: 2_000_000_000, // 2 gwei, above quiet-period threshold
+            ..Default::default()
+        };
+        let mut market = FeeMarket::with_config(config);
+
+        for reward in [1_000_000_000u128, 2_000_000_000, 3_000_000_000] {
+            market.record_fee_sample(market.base_fee, Some(reward));
+        }
+
+        let (max_fee, priority_fee) = market.estimate_eip1559_fees();
+        assert_eq!(priority_fee, 2_000_000_000); // median of [1, 2, 3] gwei
+        assert_eq!(max_fee, market.base_fee * 2 + priority_fee);
+    }
+
+    #[test]
+    fn test_estimate_fees_ignores_empty_block_samples() {
+        let config = Eip1559Config {
+            initial_base_fee: 2_000_000_000,
+            ..Default::default()
+        };
+        let mut market = FeeMarket::with_config(config);
+
+        market.record_fee_sample(market.base_fee, None); // empty block, ignored
+        market.record_fee_sample(market.base_fee, Some(5_000_000_000));
+
+        let (_, priority_fee) = market.estimate_eip1559_fees();
+        assert_eq!(priority_fee, 5_000_000_000);
+    }
+
+    #[test]
+    fn test_record_fee_sample_bounded_by_window() {
+        let config = Eip1559Config {
+            fee_history_window: 3,
+            ..Default::default()
+        };
+        let mut market = FeeMarket::with_config(config);
+
+        for i in 0..10u128 {
+            market.record_fee_sample(market.base_fee, Some(i));
+        }
+
+        assert_eq!(market.reward_history.len(), 3);
+        // Oldest samples should have been evicted; only the last 3 remain.
+        let rewards: Vec<u128> = market
+            .reward_history
+            .iter()
+            .filter_map(|s| s.reward)
+            .collect();
+        assert_eq!(rewards, vec![7, 8, 9]);
+    }
+
+    #[test]
+    fn test_record_block_fee_sample_derives_reward() {
+        let mut market = FeeMarket::new();
+        let b = block(
+            market.base_fee,
+            3_000_000,
+            10_000_000,
+            vec![(1_000_000, 10), (1_000_000, 20), (1_000_000, 30)],
+        );
+
+        market.record_block_fee_sample(&b);
+
+        assert_eq!(market.reward_history.len(), 1);
+        // 5th percentile falls in the first (cheapest) tx's gas window.
+        assert_eq!(market.reward_history[0].reward, Some(10));
+    }
+
+    #[test]
+    fn test_effective_tip_legacy() {
+        let market = FeeMarket::new();
+        let params = TxFeeParams::Legacy { gas_price: 2_000_000_000 };
+
+        let tip = market.effective_tip_per_gas(&params, 1_000_000_000);
+        assert_eq!(tip, Some(1_000_000_000));
+
+        let price = market.effective_gas_price(&params, 1_000_000_000);
+        assert_eq!(price, Some(2_000_000_000));
+    }
+
+    #[test]
+    fn test_effective_tip_legacy_below_base_fee_is_none() {
+        let market = FeeMarket::new();
+        let params = TxFeeParams::Legacy { gas_price: 500_000_000 };
+
+        assert_eq!(market.effective_tip_per_gas(&params, 1_000_000_000), None);
+        assert_eq!(market.effective_gas_price(&params, 1_000_000_000), None);
+    }
+
+    #[test]
+    fn test_effective_tip_eip1559_matches_existing_formula() {
+        let market = FeeMarket::new();
+        let params = TxFeeParams::Eip1559 {
+            max_fee_per_gas: 2_000_000_000,
+            max_priority_fee_per_gas: 500_000_000,
+        };
+
+        let tip = market.effective_tip_per_gas(&params, 1_000_000_000).unwrap();
+        let price = market.effective_gas_price(&params, 1_000_000_000).unwrap();
+
+        let (expected_price, expected_tip) = market
+            .calculate_effective_gas_price(2_000_000_000, 500_000_000)
+            .unwrap();
+        assert_eq!(tip, expected_tip);
+        assert_eq!(price, expected_price);
+    }
+
+    #[test]
+    fn test_effective_tip_eip1559_below_base_fee_is_none() {
+        let market = FeeMarket::new();
+        let params = TxFeeParams::Eip1559 {
+            max_fee_per_gas: 500_000_000,
+            max_priority_fee_per_gas: 500_000_000,
+        };
+
+        assert_eq!(market.effective_tip_per_gas(&params, 1_000_000_000), None);
+        assert_eq!(market.effective_gas_price(&params, 1_000_000_000), None);
+    }
+
+    #[test]
+    fn test_effective_tip_ranks_mixed_legacy_and_1559() {
+        let market = FeeMarket::new();
+        let base_fee = 1_000_000_000;
+        let legacy = TxFeeParams::Legacy { gas_price: 3_000_000_000 };
+        let dynamic = TxFeeParams::Eip1559 {
+            max_fee_per_gas: 2_500_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+        };
+
+        let legacy_tip = market.effective_tip_per_gas(&legacy, base_fee).unwrap();
+        let dynamic_tip = market.effective_tip_per_gas(&dynamic, base_fee).unwrap();
+
+        // Legacy pays a flat 3 gwei (tip = 2 gwei); 1559 caps its tip at 1
+        // gwei even though it could afford up to 1.5 gwei of headroom.
+        assert_eq!(legacy_tip, 2_000_000_000);
+        assert_eq!(dynamic_tip, 1_000_000_000);
+        assert!(legacy_tip > dynamic_tip);
+    }
+
+    #[test]
+    fn test_priority_fee_from_pool_median() {
+        let market = FeeMarket::new();
+        let base_fee = market.base_fee;
+        let txs = vec![
+            TxFeeParams::Legacy { gas_price: base_fee + 1_000_000_000 },
+            TxFeeParams::Legacy { gas_price: base_fee + 2_000_000_000 },
+            TxFeeParams::Legacy { gas_price: base_fee + 3_000_000_000 },
+        ];
+
+        let fee = market.priority_fee_from_pool(&txs, 50);
+        assert_eq!(fee, 2_000_000_000);
+    }
+
+    #[test]
+    fn test_priority_fee_from_pool_skips_zero_cost_txs() {
+        let market = FeeMarket::new();
+        let base_fee = market.base_fee;
+        let txs = vec![
+            TxFeeParams::Legacy { gas_price: 0 },
+            TxFeeParams::Eip1559 { max_fee_per_gas: 0, max_priority_fee_per_gas: 0 },
+            TxFeeParams::Legacy { gas_price: base_fee + 5_000_000_000 },
+        ];
+
+        // Only the non-zero-cost tx should count, so its tip is the answer
+        // at every percentile.
+        assert_eq!(market.priority_fee_from_pool(&txs, 10), 5_000_000_000);
+        assert_eq!(market.priority_fee_from_pool(&txs, 90), 5_000_000_000);
+    }
+
+    #[test]
+    fn test_priority_fee_from_pool_all_zero_cost_falls_back_to_min_base_fee() {
+        let market = FeeMarket::new();
+        let txs = vec![
+            TxFeeParams::Legacy { gas_price: 0 },
+            TxFeeParams::Eip1559 { max_fee_per_gas: 0, max_priority_fee_per_gas: 0 },
+        ];
+
+        assert_eq!(market.priority_fee_from_pool(&txs, 50), market.config.min_base_fee);
+    }
+
+    #[test]
+    fn test_priority_fee_from_pool_empty_pool_falls_back_to_min_base_fee() {
+        let market = FeeMarket::new();
+        assert_eq!(market.priority_fee_from_pool(&[], 50), market.config.min_base_fee);
+    }
+
+    #[test]
+    fn test_packed_base_fee_at_target_unchanged() {
+        let market = FeeMarket::new();
+        // Target for a 3-block tipset at the default 4/5 ratio.
+        let target = 3 * market.config.block_gas_limit * PACKING_NUM / PACKING_DENOM;
+
+        let next_fee = market.calculate_next_base_fee_packed(target, 3);
+        assert_eq!(next_fee, market.base_fee);
+    }
+
+    #[test]
+    fn test_packed_base_fee_increases_when_over_target() {
+        let market = FeeMarket::new();
+        let target = 3 * market.config.block_gas_limit * PACKING_NUM / PACKING_DENOM;
+
+        let next_fee = market.calculate_next_base_fee_packed(target * 2, 3);
+        assert!(next_fee > market.base_fee);
+    }
+
+    #[test]
+    fn test_packed_base_fee_decreases_when_under_target() {
+        let market = FeeMarket::new();
+
+        let next_fee = market.calculate_next_base_fee_packed(0, 3);
+        assert!(next_fee < market.base_fee);
+    }
+
+    #[test]
+    fn test_packed_base_fee_matches_single_block_behavior_by_default() {
+        // blocks_per_height defaults to 1, and the default 4/5 packing
+        // ratio differs from the flat 50% single-block target, so this
+        // exercises the "num_blocks=1" path directly rather than asserting
+        // numeric equality with `calculate_next_base_fee`.
+        let market = FeeMarket::new();
+        assert_eq!(market.config.blocks_per_height, 1);
+
+        let target = market.config.block_gas_limit * PACKING_NUM / PACKING_DENOM;
+        let next_fee = market.calculate_next_base_fee_packed(target, 1);
+        assert_eq!(next_fee, market.base_fee);
+    }
+
+    #[test]
+    fn test_packed_base_fee_clamped_to_min() {
+        let config = Eip1559Config {
+            initial_base_fee: 1_000_000_000,
+            min_base_fee: 1_000_000_000,
+            ..Default::default()
+        };
+        let market = FeeMarket::with_config(config);
+
+        let next = market.calculate_next_base_fee_packed(0, 3);
+        assert!(next >= market.config.min_base_fee);
+    }
+
+    #[test]
+    fn test_on_tipset_produced_updates_state() {
+        let mut market = FeeMarket::new();
+        let initial_fee = market.base_fee;
+
+        let full_tipset = 3 * market.config.block_gas_limit;
+        market.on_tipset_produced(full_tipset, 3);
+
+        assert!(market.base_fee > initial_fee);
+        assert_eq!(market.last_gas_used, full_tipset);
+        assert_eq!(market.block_number, 1);
+    }
 }
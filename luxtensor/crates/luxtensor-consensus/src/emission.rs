@@ -1,6 +1,7 @@
 // Emission controller module for adaptive tokenomics
 // Implements halving schedule and utility-based emission adjustments
 
+use super::halving::{TAIL_EMISSION_EPOCH_LENGTH, TAIL_EMISSION_INFLATION_BIPS};
 use serde::{Deserialize, Serialize};
 
 /// Emission configuration
@@ -16,6 +17,23 @@ pub struct EmissionConfig {
     pub min_emission: u128,
     /// Utility score weight (0-100)
     pub utility_weight: u8,
+    /// Explicit gate for perpetual tail inflation once the halving
+    /// schedule's final epoch completes. When `false`, emission stops
+    /// entirely after the final halving regardless of
+    /// `HalvingSchedule::inflation_bips` — block rewards trend to zero
+    /// and fees become the only validator incentive. Mirrored by
+    /// `HalvingSchedule` so `project_supply` can consult both.
+    pub tail_inflation_enabled: bool,
+    /// Perpetual tail-inflation rate, in basis points (100 = 1%) of
+    /// circulating supply minted per year once the halving schedule is
+    /// exhausted. Mirrors `HalvingSchedule::inflation_bips`; kept here
+    /// too so `validate_parameters` can catch drift between the two.
+    /// Uncaps total supply beyond `max_supply` when non-zero.
+    pub tail_inflation_bips: u32,
+    /// Length, in blocks, of one tail-emission epoch over which
+    /// `tail_inflation_bips` is spread evenly. Mirrors
+    /// `HalvingSchedule::tail_emission_epoch_length`.
+    pub tail_emission_epoch_length: u64,
 }
 
 impl Default for EmissionConfig {
@@ -30,6 +48,9 @@ impl Default for EmissionConfig {
             // Previously 0.1 MDT — 100x higher than halving.rs
             min_emission: 1_000_000_000_000_000u128,           // 0.001 tokens minimum
             utility_weight: 30,                                 // 30% adjustment based on utility
+            tail_inflation_enabled: true,
+            tail_inflation_bips: TAIL_EMISSION_INFLATION_BIPS,
+            tail_emission_epoch_length: TAIL_EMISSION_EPOCH_LENGTH,
         }
     }
 }
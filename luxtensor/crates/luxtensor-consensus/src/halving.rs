@@ -26,6 +26,17 @@ pub const MINIMUM_REWARD: u128 = 1_000_000_000_000_000;
 /// Maximum number of halvings (after this, reward is 0)
 pub const MAX_HALVINGS: u32 = 10;
 
+/// Default tail-emission annual inflation rate, in basis points (100 bips
+/// = 1 percentage point of circulating supply per year). Applied once the
+/// halving schedule's final era is exhausted, Tari-style, so issuance
+/// never fully stops — see `HalvingSchedule::tail_emission_per_block`.
+pub const TAIL_EMISSION_INFLATION_BIPS: u32 = 100; // 1% annual
+
+/// Blocks per year tail emission is annualized over, at 12s block times.
+/// Mirrors `economic_model::BLOCKS_PER_YEAR`; duplicated here since
+/// `halving` sits below `economic_model` in the dependency graph.
+pub const TAIL_EMISSION_EPOCH_LENGTH: u64 = 2_629_800;
+
 /// Halving schedule configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HalvingSchedule {
@@ -37,6 +48,12 @@ pub struct HalvingSchedule {
     pub minimum_reward: u128,
     /// Maximum number of halvings
     pub max_halvings: u32,
+    /// Annual tail-emission inflation rate, in basis points, applied to
+    /// circulating supply once halvings are exhausted (see
+    /// `tail_emission_per_block`). `0` disables tail emission entirely.
+    pub inflation_bips: u32,
+    /// Blocks per year the tail-emission rate is annualized over.
+    pub tail_emission_epoch_length: u64,
 }
 
 impl Default for HalvingSchedule {
@@ -46,12 +63,15 @@ impl Default for HalvingSchedule {
             halving_interval: HALVING_INTERVAL,
             minimum_reward: MINIMUM_REWARD,
             max_halvings: MAX_HALVINGS,
+            inflation_bips: TAIL_EMISSION_INFLATION_BIPS,
+            tail_emission_epoch_length: TAIL_EMISSION_EPOCH_LENGTH,
         }
     }
 }
 
 impl HalvingSchedule {
-    /// Create a new halving schedule with custom parameters
+    /// Create a new halving schedule with custom parameters and the
+    /// default tail-emission settings (1% annual, `TAIL_EMISSION_EPOCH_LENGTH`).
     pub fn new(
         initial_reward: u128,
         halving_interval: u64,
@@ -63,9 +83,34 @@ impl HalvingSchedule {
             halving_interval,
             minimum_reward,
             max_halvings,
+            inflation_bips: TAIL_EMISSION_INFLATION_BIPS,
+            tail_emission_epoch_length: TAIL_EMISSION_EPOCH_LENGTH,
         }
     }
 
+    /// Whether `block_height` is past the final halving era, i.e. the
+    /// geometric halving schedule has bottomed out and
+    /// `tail_emission_per_block` should be used instead of
+    /// `calculate_reward`.
+    pub fn is_tail_emission_active(&self, block_height: u64) -> bool {
+        (block_height / self.halving_interval) as u32 > self.max_halvings
+    }
+
+    /// Constant-percentage tail emission per block, Tari-style: inflates
+    /// `circulating_supply` by `inflation_bips` annually, spread evenly
+    /// over `tail_emission_epoch_length` blocks. Returns `0` if
+    /// `inflation_bips` is `0` (tail emission disabled).
+    ///
+    /// `annual_tail = circulating_supply * inflation_bips / 10_000`
+    /// `per_block = annual_tail / tail_emission_epoch_length`
+    pub fn tail_emission_per_block(&self, circulating_supply: u128) -> u128 {
+        if self.inflation_bips == 0 || self.tail_emission_epoch_length == 0 {
+            return 0;
+        }
+        let annual_tail = circulating_supply.saturating_mul(self.inflation_bips as u128) / 10_000;
+        annual_tail / self.tail_emission_epoch_length as u128
+    }
+
     /// Calculate block reward for a given block height
     ///
     /// Formula: reward = initial_reward / 2^halvings
@@ -266,4 +311,42 @@ mod tests {
         assert!(info.estimated_total_emission_mdt > 0.0);
         assert!(info.estimated_total_emission_mdt < 10_000_000.0); // Should be less than 10M
     }
+
+    #[test]
+    fn test_tail_emission_not_active_before_final_halving() {
+        let schedule = HalvingSchedule::default();
+        assert!(!schedule.is_tail_emission_active(0));
+        assert!(!schedule.is_tail_emission_active(HALVING_INTERVAL * MAX_HALVINGS as u64));
+    }
+
+    #[test]
+    fn test_tail_emission_active_past_final_halving() {
+        let schedule = HalvingSchedule::default();
+        let past_final_era = (MAX_HALVINGS as u64 + 1) * HALVING_INTERVAL;
+        assert!(schedule.is_tail_emission_active(past_final_era));
+        // calculate_reward should have bottomed out to 0 at this point.
+        assert_eq!(schedule.calculate_reward(past_final_era), 0);
+    }
+
+    #[test]
+    fn test_tail_emission_per_block_matches_formula() {
+        let schedule = HalvingSchedule::default();
+        let circulating_supply = 10_000_000u128 * 1_000_000_000_000_000_000; // 10M MDT
+
+        let per_block = schedule.tail_emission_per_block(circulating_supply);
+
+        let annual_tail = circulating_supply * schedule.inflation_bips as u128 / 10_000;
+        let expected = annual_tail / schedule.tail_emission_epoch_length as u128;
+        assert_eq!(per_block, expected);
+        assert!(per_block > 0);
+    }
+
+    #[test]
+    fn test_tail_emission_disabled_when_inflation_bips_zero() {
+        let schedule = HalvingSchedule {
+            inflation_bips: 0,
+            ..HalvingSchedule::default()
+        };
+        assert_eq!(schedule.tail_emission_per_block(1_000_000 * 1_000_000_000_000_000_000), 0);
+    }
 }
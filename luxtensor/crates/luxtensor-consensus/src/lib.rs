@@ -77,7 +77,7 @@ pub use emission::{
 };
 pub use halving::{
     HalvingInfo, HalvingSchedule, HALVING_INTERVAL, INITIAL_BLOCK_REWARD, MAX_HALVINGS,
-    MINIMUM_REWARD,
+    MINIMUM_REWARD, TAIL_EMISSION_EPOCH_LENGTH, TAIL_EMISSION_INFLATION_BIPS,
 };
 pub use burn_manager::{BurnConfig, BurnEvent, BurnManager, BurnStats, BurnType};
 pub use reward_distribution::{
@@ -88,22 +88,29 @@ pub use reward_executor::{
     AccountBalance, ClaimResult, EpochResult, ExecutorStats, PendingReward, RewardExecutor,
     RewardHistoryEntry, RewardType,
 };
-pub use eip1559::{Eip1559Config, FeeHistory, FeeMarket};
+pub use eip1559::{
+    gas_weighted_rewards, BlockFeeData, Eip1559Config, FeeHistory, FeeHistoryError, FeeMarket,
+    FeeSample, TxFeeParams, MAX_FEE_HISTORY_BLOCK_COUNT, MAX_REWARD_PERCENTILES, PACKING_DENOM,
+    PACKING_NUM,
+};
 pub use token_allocation::{
     AllocationCategory, AllocationStats, TgeResult, TokenAllocation, VestingEntry, VestingSchedule,
     DECIMALS, TOTAL_SUPPLY,
 };
 pub use economic_model::{
-    analyze_equilibrium, generate_report, project_supply, sweep_burn_rate, sweep_tx_volume,
-    validate_parameters, AnnualSnapshot, EquilibriumResult, ProjectionConfig, SensitivityPoint,
-    Severity, TokenomicsInconsistency, BLOCKS_PER_YEAR, BLOCK_TIME_SECONDS, EMISSION_POOL,
+    analyze_equilibrium, export_report_json, export_snapshots_csv, generate_report,
+    project_pool_emissions, project_supply, project_supply_monte_carlo,
+    project_supply_with_schedule, sweep_burn_rate, sweep_tx_volume, validate_parameters,
+    AnnualSnapshot, BaselineMintingConfig, EmissionSchedule, EquilibriumResult, MonteCarloResult,
+    PercentileBand, PoolEmission, ProjectionConfig, SensitivityPoint, Severity,
+    TokenomicsInconsistency, VestSpec, BLOCKS_PER_YEAR, BLOCK_TIME_SECONDS, EMISSION_POOL,
     PREMINTED_SUPPLY,
 };
 
 // Governance
 pub use governance::{
     GovernanceConfig, GovernanceError, GovernanceModule, Proposal,
-    ProposalStatus as GovProposalStatus, ProposalType, Vote,
+    ProposalStatus as GovProposalStatus, ProposalType, Tally, TallyConfig, Vote, VoteWeighting,
 };
 pub use commit_reveal::{
     CommitRevealConfig, CommitRevealManager, EpochFinalizationResult, EpochPhase, SlashingResult,
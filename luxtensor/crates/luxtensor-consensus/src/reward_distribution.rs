@@ -24,6 +24,15 @@ pub struct DistributionConfig {
     pub dao_share_bps: u32,
     /// Community ecosystem share in BPS (1000 = 10%) - Developer grants, hackathons
     pub community_ecosystem_share_bps: u32,
+    /// If set, `subnet_owner_share_bps` is only paid out while `year <
+    /// term`; once the term expires its BPS are proportionally
+    /// reallocated across the still-active shares (see `effective_shares`).
+    /// `None` (the default) means perpetual, matching pre-existing behavior.
+    pub subnet_owner_term_years: Option<u32>,
+    /// Same as `subnet_owner_term_years`, for `dao_share_bps`.
+    pub dao_term_years: Option<u32>,
+    /// Same as `subnet_owner_term_years`, for `community_ecosystem_share_bps`.
+    pub community_ecosystem_term_years: Option<u32>,
 }
 
 impl Default for DistributionConfig {
@@ -36,6 +45,9 @@ impl Default for DistributionConfig {
             subnet_owner_share_bps: 800,         // 8%
             dao_share_bps: 500,                  // 5%
             community_ecosystem_share_bps: 1000, // 10%
+            subnet_owner_term_years: None,
+            dao_term_years: None,
+            community_ecosystem_term_years: None,
         }
     }
 }
@@ -55,6 +67,83 @@ impl DistributionConfig {
         }
         Ok(())
     }
+
+    /// Returns the effective per-share split for `year`: any term-limited
+    /// pool (`subnet_owner_term_years`, `dao_term_years`,
+    /// `community_ecosystem_term_years`) whose term has elapsed is zeroed
+    /// out, and its BPS are proportionally reallocated across the shares
+    /// still active that year. Core operational shares (miner, validator,
+    /// infrastructure, delegator) are never term-limited.
+    ///
+    /// Uses exact integer BPS math throughout — the returned shares always
+    /// sum to the same total as `self` (10_000 for a valid config), with
+    /// any rounding remainder from the proportional split assigned to the
+    /// first still-active pool, so the total never silently drifts.
+    pub fn effective_shares(&self, year: u32) -> DistributionConfig {
+        let active_subnet_owner = self.subnet_owner_term_years.map(|t| year < t).unwrap_or(true);
+        let active_dao = self.dao_term_years.map(|t| year < t).unwrap_or(true);
+        let active_community =
+            self.community_ecosystem_term_years.map(|t| year < t).unwrap_or(true);
+
+        let bps = [
+            self.miner_share_bps,
+            self.validator_share_bps,
+            self.infrastructure_share_bps,
+            self.delegator_share_bps,
+            self.subnet_owner_share_bps,
+            self.dao_share_bps,
+            self.community_ecosystem_share_bps,
+        ];
+        let active = [true, true, true, true, active_subnet_owner, active_dao, active_community];
+
+        let expired_bps: u32 =
+            bps.iter().zip(active.iter()).filter(|(_, &a)| !a).map(|(&b, _)| b).sum();
+        let active_total: u32 =
+            bps.iter().zip(active.iter()).filter(|(_, &a)| a).map(|(&b, _)| b).sum();
+
+        let mut effective = [0u32; 7];
+        if active_total == 0 {
+            // Degenerate config (everything expired) — nothing left to
+            // reallocate onto; leave expired shares at zero rather than
+            // panic on a div-by-zero.
+            for i in 0..7 {
+                effective[i] = if active[i] { bps[i] } else { 0 };
+            }
+        } else {
+            let mut distributed = 0u32;
+            for i in 0..7 {
+                if !active[i] {
+                    continue;
+                }
+                let extra = (bps[i] as u64 * expired_bps as u64 / active_total as u64) as u32;
+                distributed += extra;
+                effective[i] = bps[i] + extra;
+            }
+            // Integer division can leave a remainder undistributed; give
+            // it to the first active pool so the total always matches
+            // exactly (checked by `validate_parameters` at every term
+            // boundary).
+            let remainder = expired_bps - distributed;
+            if remainder > 0 {
+                if let Some(first_active) = (0..7).find(|&i| active[i]) {
+                    effective[first_active] += remainder;
+                }
+            }
+        }
+
+        DistributionConfig {
+            miner_share_bps: effective[0],
+            validator_share_bps: effective[1],
+            infrastructure_share_bps: effective[2],
+            delegator_share_bps: effective[3],
+            subnet_owner_share_bps: effective[4],
+            dao_share_bps: effective[5],
+            community_ecosystem_share_bps: effective[6],
+            subnet_owner_term_years: self.subnet_owner_term_years,
+            dao_term_years: self.dao_term_years,
+            community_ecosystem_term_years: self.community_ecosystem_term_years,
+        }
+    }
 }
 
 /// Lock bonus configuration for delegators.
@@ -637,6 +726,68 @@ mod tests {
         assert_eq!(config.community_ecosystem_share_bps, 1000);
     }
 
+    #[test]
+    fn test_effective_shares_no_terms_is_identity() {
+        let config = DistributionConfig::default();
+        let effective = config.effective_shares(100);
+        assert_eq!(effective.miner_share_bps, config.miner_share_bps);
+        assert_eq!(effective.dao_share_bps, config.dao_share_bps);
+        assert_eq!(effective.community_ecosystem_share_bps, config.community_ecosystem_share_bps);
+    }
+
+    #[test]
+    fn test_effective_shares_reallocates_expired_term() {
+        let config = DistributionConfig { dao_term_years: Some(5), ..Default::default() };
+
+        // Before the term expires, shares are unchanged.
+        let before = config.effective_shares(4);
+        assert_eq!(before.dao_share_bps, 500);
+        let total_before = before.miner_share_bps
+            + before.validator_share_bps
+            + before.infrastructure_share_bps
+            + before.delegator_share_bps
+            + before.subnet_owner_share_bps
+            + before.dao_share_bps
+            + before.community_ecosystem_share_bps;
+        assert_eq!(total_before, 10_000);
+
+        // Once expired, the DAO's 500 BPS is zeroed and reallocated —
+        // total must still sum to 10_000.
+        let after = config.effective_shares(5);
+        assert_eq!(after.dao_share_bps, 0);
+        assert!(after.miner_share_bps > config.miner_share_bps);
+        let total_after = after.miner_share_bps
+            + after.validator_share_bps
+            + after.infrastructure_share_bps
+            + after.delegator_share_bps
+            + after.subnet_owner_share_bps
+            + after.dao_share_bps
+            + after.community_ecosystem_share_bps;
+        assert_eq!(total_after, 10_000);
+    }
+
+    #[test]
+    fn test_effective_shares_multiple_terms_still_sum_to_total() {
+        let config = DistributionConfig {
+            dao_term_years: Some(3),
+            community_ecosystem_term_years: Some(7),
+            subnet_owner_term_years: Some(10),
+            ..Default::default()
+        };
+
+        for year in 0..20u32 {
+            let effective = config.effective_shares(year);
+            let total = effective.miner_share_bps
+                + effective.validator_share_bps
+                + effective.infrastructure_share_bps
+                + effective.delegator_share_bps
+                + effective.subnet_owner_share_bps
+                + effective.dao_share_bps
+                + effective.community_ecosystem_share_bps;
+            assert_eq!(total, 10_000, "year {} should still sum to 10,000", year);
+        }
+    }
+
     #[test]
     fn test_lock_bonus() {
         let config = LockBonusConfig::default();
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use parking_lot::RwLock;
 use tracing::{debug, info};
+use crate::bls_aggregation::{BlsAggregator, IAggregator};
 
 /// Maximum gas for user operation validation
 pub const MAX_VERIFICATION_GAS: u64 = 500_000;
@@ -15,6 +16,248 @@ pub const MAX_CALL_GAS: u64 = 3_000_000;
 /// Minimum stake required for paymaster
 pub const MIN_PAYMASTER_STAKE: u128 = 1_000_000_000_000_000_000; // 1 ETH
 
+/// Gas cost of a zero calldata byte (EIP-2028).
+const ZERO_BYTE_GAS: u64 = 4;
+/// Gas cost of a non-zero calldata byte (EIP-2028).
+const NONZERO_BYTE_GAS: u64 = 16;
+
+/// Sum of per-byte calldata gas, counting zero bytes at `ZERO_BYTE_GAS` and
+/// non-zero bytes at `NONZERO_BYTE_GAS`, the way L1 calldata is priced.
+fn calldata_gas(data: &[u8]) -> u64 {
+    data.iter().map(|&b| if b == 0 { ZERO_BYTE_GAS } else { NONZERO_BYTE_GAS }).sum()
+}
+
+/// EIP-1559 effective gas price: the sender never pays more than
+/// `max_fee_per_gas`, even if `base_fee_per_gas + max_priority_fee_per_gas`
+/// would exceed it.
+fn effective_gas_price(max_fee_per_gas: u128, max_priority_fee_per_gas: u128, base_fee_per_gas: u128) -> u128 {
+    max_fee_per_gas.min(base_fee_per_gas.saturating_add(max_priority_fee_per_gas))
+}
+
+/// ERC-4337 keyed nonces split `nonce` into an independent channel
+/// (`key`) and a per-channel, strictly-incrementing counter (`sequence`),
+/// so a wallet can have multiple ops in flight at once without one
+/// channel blocking another. The real ERC-4337 spec packs a 192-bit key
+/// and a 64-bit sequence into a 256-bit nonce; `UserOperation::nonce` here
+/// is `u128`, so this EntryPoint packs a 64-bit key into the upper half
+/// and a 64-bit sequence into the lower half instead.
+fn nonce_key(nonce: u128) -> u64 {
+    (nonce >> 64) as u64
+}
+
+/// EIP-155-style domain binding folded into a user operation's hash
+/// preimage as its own tagged region (see `write_into`), distinct from
+/// adjacent fields regardless of their byte lengths. Binding `chain_id`
+/// this way is what makes a signed op invalid on a forked chain with a
+/// different chain id, while a same-chain entry-point upgrade (a new
+/// `entry_point` value) still requires a fresh signature too, since both
+/// fields are part of the same tagged region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Eip155Domain {
+    pub chain_id: u64,
+    pub entry_point: Address,
+}
+
+impl Eip155Domain {
+    /// Domain separator tag, so this preimage region can never be confused
+    /// with adjacent fields no matter how their encodings shift over time.
+    const TAG: &'static [u8] = b"EIP155";
+
+    fn write_into(&self, data: &mut Vec<u8>) {
+        data.extend_from_slice(Self::TAG);
+        data.extend_from_slice(&self.chain_id.to_le_bytes());
+        data.extend_from_slice(self.entry_point.as_bytes());
+    }
+}
+
+/// Recover the signer address from `op_hash`/`signature` using full
+/// EIP-155 `v` encoding (`v = recovery_id + chain_id*2 + 35`), for
+/// interoperating with clients that sign this way. This EntryPoint's own
+/// `verify_signature` uses a raw recovery-id byte internally (simpler,
+/// chain-id-agnostic at the signature layer since chain id is already
+/// bound into the op hash itself via `Eip155Domain`); this helper is for
+/// confirming `sender` against a signature that instead carries the full
+/// EIP-155 `v` value, without trusting the submitted bytes at face value.
+/// Note `v` must fit in a single byte here (the signature's last byte), so
+/// this only round-trips for `chain_id` small enough that
+/// `recovery_id + chain_id*2 + 35 <= 255`.
+/// Byte length of the optional validity-window suffix a user operation's
+/// signature may carry. See `decode_validity_window`.
+const VALIDITY_WINDOW_LEN: usize = 16;
+
+/// An optional `valid_after(8, big-endian) ++ valid_until(8, big-endian)`
+/// window packed after a user operation's core signature (`sig(64) ++
+/// recovery_id(1)`). Real ERC-4337 bundlers get this pair back from the
+/// account contract's `validateUserOp` return value; this EntryPoint has no
+/// account bytecode to call into, so the signer attaches it to the
+/// signature directly instead — the same "fold extra data into an existing
+/// wire field rather than widen the struct" approach `nonce_key`/
+/// `recover_signer`'s EIP-155 `v` already use. Absent, the window is
+/// unbounded (`(0, u64::MAX)`).
+fn decode_validity_window(signature: &[u8]) -> (u64, u64) {
+    if signature.len() < 64 + 1 + VALIDITY_WINDOW_LEN {
+        return (0, u64::MAX);
+    }
+    let window = &signature[65..65 + VALIDITY_WINDOW_LEN];
+    let valid_after = u64::from_be_bytes(window[0..8].try_into().unwrap());
+    let valid_until = u64::from_be_bytes(window[8..16].try_into().unwrap());
+    (valid_after, valid_until)
+}
+
+pub fn recover_signer(op_hash: &Hash, signature: &[u8], chain_id: u64) -> Option<Address> {
+    if signature.len() < 65 {
+        return None;
+    }
+    let sig_bytes: [u8; 64] = signature[..64].try_into().ok()?;
+    let v = signature[64] as u64;
+    let base = chain_id.checked_mul(2)?.checked_add(35)?;
+    let recovery_id = v.checked_sub(base)?;
+    if recovery_id > 1 {
+        return None;
+    }
+    let pubkey = luxtensor_crypto::recover_public_key(op_hash, &sig_bytes, recovery_id as u8).ok()?;
+    let recovered = luxtensor_crypto::address_from_public_key(&pubkey).ok()?;
+    Some(Address::from(*recovered.as_bytes()))
+}
+
+/// The per-channel sequence number, the low 64 bits of `nonce`. See `nonce_key`.
+fn nonce_sequence(nonce: u128) -> u64 {
+    nonce as u64
+}
+
+/// Computes the L1 data-posting cost component of `pre_verification_gas`,
+/// expressed in L2 gas units. On an L1 chain this is always zero; on a
+/// rollup the dominant cost of a user operation is posting its calldata to
+/// L1, so `estimate_user_op_gas`/`simulate_validation` fold this in rather
+/// than under-charging bundlers for the L2 gas they'll actually spend.
+pub trait PreVerificationGasOracle: std::fmt::Debug + Send + Sync {
+    /// `op_calldata` is the byte representation of the full user operation
+    /// as it would be posted to L1; `l2_gas_price` is the price (wei per
+    /// gas) used to convert the L1 fee into L2 gas units.
+    fn l1_gas_component(&self, op_calldata: &[u8], l2_gas_price: u64) -> u64;
+}
+
+/// L1 chains pay nothing extra to post calldata to themselves.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MainnetGasOracle;
+
+impl PreVerificationGasOracle for MainnetGasOracle {
+    fn l1_gas_component(&self, _op_calldata: &[u8], _l2_gas_price: u64) -> u64 {
+        0
+    }
+}
+
+/// Optimism-style L1 data fee: `(calldata_gas + fixed_overhead) *
+/// dynamic_overhead_numerator / dynamic_overhead_denominator`, priced at
+/// `l1_base_fee` wei/gas, then converted to L2 gas units by dividing by
+/// the current L2 gas price.
+#[derive(Debug, Clone, Copy)]
+pub struct OptimismGasOracle {
+    /// Current L1 base fee, in wei per gas.
+    pub l1_base_fee: u64,
+    /// Fixed per-transaction L1 gas overhead.
+    pub fixed_overhead: u64,
+    /// Numerator of the dynamic overhead scalar (e.g. `l1FeeScalar`).
+    pub dynamic_overhead_numerator: u64,
+    /// Denominator of the dynamic overhead scalar.
+    pub dynamic_overhead_denominator: u64,
+}
+
+impl PreVerificationGasOracle for OptimismGasOracle {
+    fn l1_gas_component(&self, op_calldata: &[u8], l2_gas_price: u64) -> u64 {
+        if l2_gas_price == 0 {
+            return 0;
+        }
+
+        let scaled_l1_gas = (calldata_gas(op_calldata).saturating_add(self.fixed_overhead) as u128)
+            .saturating_mul(self.dynamic_overhead_numerator as u128)
+            / (self.dynamic_overhead_denominator as u128).max(1);
+        let l1_fee_wei = scaled_l1_gas.saturating_mul(self.l1_base_fee as u128);
+
+        (l1_fee_wei / l2_gas_price as u128).min(u64::MAX as u128) as u64
+    }
+}
+
+/// Arbitrum-style L1 data fee: a flat per-byte surcharge already expressed
+/// in L2 gas units, as returned by Arbitrum's `ArbGasInfo` precompile.
+#[derive(Debug, Clone, Copy)]
+pub struct ArbitrumGasOracle {
+    /// L2 gas charged per byte of posted calldata.
+    pub per_byte_gas: u64,
+}
+
+impl PreVerificationGasOracle for ArbitrumGasOracle {
+    fn l1_gas_component(&self, op_calldata: &[u8], _l2_gas_price: u64) -> u64 {
+        (op_calldata.len() as u64).saturating_mul(self.per_byte_gas)
+    }
+}
+
+/// Number of pending ops a THROTTLED entity (sender or paymaster) may have
+/// in the mempool at once.
+const THROTTLED_PENDING_OP_LIMIT: usize = 4;
+/// Blocks after which a THROTTLED entity's pending op is dropped from the
+/// mempool rather than included.
+const THROTTLED_OP_EXPIRY_BLOCKS: u64 = 10;
+/// Rejected/included ratio (as a percentage) at or above which an entity is
+/// THROTTLED.
+const THROTTLE_REJECTION_RATIO_PCT: u64 = 100;
+/// Rejected/included ratio (as a percentage) at or above which an entity is
+/// BANNED outright.
+const BAN_REJECTION_RATIO_PCT: u64 = 300;
+/// Blocks between reputation decay passes. Each pass halves every entity's
+/// counters, the way OpenEthereum's banning queue lets bans and throttling
+/// wear off over time rather than being permanent.
+const REPUTATION_DECAY_INTERVAL_BLOCKS: u64 = 100;
+
+/// ERC-7562-style reputation status for a mempool entity (sender, paymaster,
+/// or factory), derived from its seen/included/rejected op counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReputationStatus {
+    /// No abuse signal; ops are accepted normally.
+    Ok,
+    /// Rejected-to-included ratio crossed the throttle threshold: pending
+    /// ops are capped to [`THROTTLED_PENDING_OP_LIMIT`] and expire after
+    /// [`THROTTLED_OP_EXPIRY_BLOCKS`] if not yet included.
+    Throttled,
+    /// Rejected-to-included ratio crossed the ban threshold: every op from
+    /// this entity is rejected outright.
+    Banned,
+}
+
+/// Per-entity (sender, paymaster, or factory address) counters backing
+/// [`ReputationStatus`].
+#[derive(Debug, Clone, Copy, Default)]
+struct EntityReputation {
+    ops_seen: u64,
+    ops_included: u64,
+    ops_rejected: u64,
+}
+
+impl EntityReputation {
+    fn status(&self) -> ReputationStatus {
+        if self.ops_rejected == 0 {
+            return ReputationStatus::Ok;
+        }
+        let rejection_ratio_pct = (self.ops_rejected * 100) / self.ops_included.max(1);
+        if rejection_ratio_pct >= BAN_REJECTION_RATIO_PCT {
+            ReputationStatus::Banned
+        } else if rejection_ratio_pct >= THROTTLE_REJECTION_RATIO_PCT {
+            ReputationStatus::Throttled
+        } else {
+            ReputationStatus::Ok
+        }
+    }
+}
+
+/// A user operation sitting in the mempool, verified but not yet included
+/// in a block. Tracks the block it was queued at so throttled entities'
+/// ops can be expired after [`THROTTLED_OP_EXPIRY_BLOCKS`].
+#[derive(Debug, Clone)]
+struct PendingOp {
+    verified: VerifiedUserOp,
+    queued_at_block: u64,
+}
+
 /// User Operation for Account Abstraction (ERC-4337)
 ///
 /// This struct represents a pseudo-transaction that can be submitted
@@ -61,8 +304,7 @@ impl UserOperation {
         data.extend_from_slice(&self.max_fee_per_gas.to_le_bytes());
         data.extend_from_slice(&self.max_priority_fee_per_gas.to_le_bytes());
         data.extend_from_slice(&keccak256(&self.paymaster_and_data));
-        data.extend_from_slice(entry_point.as_bytes());
-        data.extend_from_slice(&chain_id.to_le_bytes());
+        Eip155Domain { chain_id, entry_point: *entry_point }.write_into(&mut data);
 
         keccak256(&data)
     }
@@ -82,6 +324,17 @@ impl UserOperation {
         Address::try_from_slice(&self.paymaster_and_data)
     }
 
+    /// Parse a token-mode paymaster's parameters out of the bytes following
+    /// the paymaster address in `paymaster_and_data`. `None` if no paymaster
+    /// is set, or its data isn't long enough to be token mode (a plain
+    /// native-deposit paymaster has no data here, or arbitrary shorter data).
+    pub fn token_paymaster_data(&self) -> Option<TokenPaymasterData> {
+        if self.paymaster_and_data.len() < 20 {
+            return None;
+        }
+        parse_token_paymaster_data(&self.paymaster_and_data[20..])
+    }
+
     /// Validate basic constraints
     pub fn validate_basic(&self) -> Result<(), AccountAbstractionError> {
         // Check gas limits
@@ -98,6 +351,323 @@ impl UserOperation {
     }
 }
 
+/// Pack two `u128` halves into a single 32-byte big-endian field the way
+/// ERC-4337 v0.7 packs `accountGasLimits`/`gasFees`: `high` occupies the
+/// first 16 bytes, `low` the last 16.
+fn pack_u128_pair(high: u128, low: u128) -> [u8; 32] {
+    let mut packed = [0u8; 32];
+    packed[0..16].copy_from_slice(&high.to_be_bytes());
+    packed[16..32].copy_from_slice(&low.to_be_bytes());
+    packed
+}
+
+/// Inverse of [`pack_u128_pair`]: returns `(high, low)`.
+fn unpack_u128_pair(packed: &[u8; 32]) -> (u128, u128) {
+    let high = u128::from_be_bytes(packed[0..16].try_into().expect("slice is 16 bytes"));
+    let low = u128::from_be_bytes(packed[16..32].try_into().expect("slice is 16 bytes"));
+    (high, low)
+}
+
+/// ERC-4337 v0.7 `UserOperation`, using the packed wire layout adopted by
+/// production bundlers: gas limits and fees are packed two-per-field, and
+/// the paymaster fields are split out of the v0.6 `paymaster_and_data` blob
+/// so callers can address them individually.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserOperationV07 {
+    /// The account making the operation
+    pub sender: Address,
+    /// Anti-replay nonce
+    pub nonce: u128,
+    /// Factory contract for deploying the sender if not yet deployed
+    pub factory: Option<Address>,
+    /// Constructor data passed to `factory` (empty if `factory` is `None`)
+    pub factory_data: Vec<u8>,
+    /// Data to pass to the sender for execution
+    pub call_data: Vec<u8>,
+    /// Packed gas limits: `verification_gas_limit` (high 128 bits) | `call_gas_limit` (low 128 bits)
+    pub account_gas_limits: [u8; 32],
+    /// Gas to compensate bundler for pre-verification
+    pub pre_verification_gas: u64,
+    /// Packed EIP-1559 fees: `max_priority_fee_per_gas` (high 128 bits) | `max_fee_per_gas` (low 128 bits)
+    pub gas_fees: [u8; 32],
+    /// Paymaster address (`None` if self-paying)
+    pub paymaster: Option<Address>,
+    /// Gas limit for the paymaster's `validatePaymasterUserOp`
+    pub paymaster_verification_gas_limit: u64,
+    /// Gas limit for the paymaster's `postOp`
+    pub paymaster_post_op_gas_limit: u64,
+    /// Paymaster-specific data (empty if `paymaster` is `None`)
+    pub paymaster_data: Vec<u8>,
+    /// Signature over the user operation
+    pub signature: Vec<u8>,
+}
+
+impl UserOperationV07 {
+    /// Build the packed `account_gas_limits` field from its two halves.
+    pub fn pack_gas_limits(verification_gas_limit: u128, call_gas_limit: u128) -> [u8; 32] {
+        pack_u128_pair(verification_gas_limit, call_gas_limit)
+    }
+
+    /// Build the packed `gas_fees` field from its two halves.
+    pub fn pack_gas_fees(max_priority_fee_per_gas: u128, max_fee_per_gas: u128) -> [u8; 32] {
+        pack_u128_pair(max_priority_fee_per_gas, max_fee_per_gas)
+    }
+
+    pub fn verification_gas_limit(&self) -> u128 {
+        unpack_u128_pair(&self.account_gas_limits).0
+    }
+
+    pub fn call_gas_limit(&self) -> u128 {
+        unpack_u128_pair(&self.account_gas_limits).1
+    }
+
+    pub fn max_priority_fee_per_gas(&self) -> u128 {
+        unpack_u128_pair(&self.gas_fees).0
+    }
+
+    pub fn max_fee_per_gas(&self) -> u128 {
+        unpack_u128_pair(&self.gas_fees).1
+    }
+
+    /// Parse a token-mode paymaster's parameters out of `paymaster_data`.
+    /// Unlike v0.6, v0.7 already splits the paymaster address out of its
+    /// data blob, so there's no leading address to skip here.
+    pub fn token_paymaster_data(&self) -> Option<TokenPaymasterData> {
+        if self.paymaster.is_none() {
+            return None;
+        }
+        parse_token_paymaster_data(&self.paymaster_data)
+    }
+
+    /// `factory ++ factory_data`, the v0.7 equivalent of v0.6's `init_code`.
+    fn packed_init_code(&self) -> Vec<u8> {
+        match &self.factory {
+            Some(factory) => {
+                let mut packed = factory.as_bytes().to_vec();
+                packed.extend_from_slice(&self.factory_data);
+                packed
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// `paymaster ++ paymaster_verification_gas_limit ++ paymaster_post_op_gas_limit ++ paymaster_data`,
+    /// the v0.7 equivalent of v0.6's combined `paymaster_and_data` blob.
+    fn packed_paymaster_and_data(&self) -> Vec<u8> {
+        match &self.paymaster {
+            Some(paymaster) => {
+                let mut packed = paymaster.as_bytes().to_vec();
+                packed.extend_from_slice(&self.paymaster_verification_gas_limit.to_be_bytes());
+                packed.extend_from_slice(&self.paymaster_post_op_gas_limit.to_be_bytes());
+                packed.extend_from_slice(&self.paymaster_data);
+                packed
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Calculate the hash of the user operation, following the v0.7
+    /// preimage: a keccak of the packed struct, then a keccak of that
+    /// packed hash together with the entry point and chain id.
+    pub fn hash(&self, entry_point: &Address, chain_id: u64) -> Hash {
+        use luxtensor_crypto::keccak256;
+
+        let mut packed = Vec::new();
+        packed.extend_from_slice(self.sender.as_bytes());
+        packed.extend_from_slice(&self.nonce.to_le_bytes());
+        packed.extend_from_slice(&keccak256(&self.packed_init_code()));
+        packed.extend_from_slice(&keccak256(&self.call_data));
+        packed.extend_from_slice(&self.account_gas_limits);
+        packed.extend_from_slice(&self.pre_verification_gas.to_le_bytes());
+        packed.extend_from_slice(&self.gas_fees);
+        packed.extend_from_slice(&keccak256(&self.packed_paymaster_and_data()));
+        let packed_hash = keccak256(&packed);
+
+        let mut outer = Vec::new();
+        outer.extend_from_slice(&packed_hash);
+        Eip155Domain { chain_id, entry_point: *entry_point }.write_into(&mut outer);
+        keccak256(&outer)
+    }
+
+    /// Get gas required for this operation
+    pub fn required_gas(&self) -> u64 {
+        (self.call_gas_limit() as u64)
+            .saturating_add(self.verification_gas_limit() as u64)
+            .saturating_add(self.pre_verification_gas)
+    }
+
+    /// Check if operation uses a paymaster
+    pub fn has_paymaster(&self) -> bool {
+        self.paymaster.is_some()
+    }
+
+    /// Validate basic constraints
+    pub fn validate_basic(&self) -> Result<(), AccountAbstractionError> {
+        if self.verification_gas_limit() > MAX_VERIFICATION_GAS as u128 {
+            return Err(AccountAbstractionError::VerificationGasExceeded);
+        }
+        if self.call_gas_limit() > MAX_CALL_GAS as u128 {
+            return Err(AccountAbstractionError::CallGasExceeded);
+        }
+        if self.signature.is_empty() {
+            return Err(AccountAbstractionError::InvalidSignature);
+        }
+        Ok(())
+    }
+}
+
+/// A user operation tagged with its ERC-4337 wire version, so validation,
+/// hashing, gas estimation, and dispatch can be threaded through a single
+/// call site regardless of which version the client submitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum VersionedUserOperation {
+    V06(UserOperation),
+    V07(UserOperationV07),
+}
+
+impl VersionedUserOperation {
+    pub fn sender(&self) -> Address {
+        match self {
+            VersionedUserOperation::V06(op) => op.sender,
+            VersionedUserOperation::V07(op) => op.sender,
+        }
+    }
+
+    pub fn nonce(&self) -> u128 {
+        match self {
+            VersionedUserOperation::V06(op) => op.nonce,
+            VersionedUserOperation::V07(op) => op.nonce,
+        }
+    }
+
+    pub fn hash(&self, entry_point: &Address, chain_id: u64) -> Hash {
+        match self {
+            VersionedUserOperation::V06(op) => op.hash(entry_point, chain_id),
+            VersionedUserOperation::V07(op) => op.hash(entry_point, chain_id),
+        }
+    }
+
+    pub fn has_paymaster(&self) -> bool {
+        match self {
+            VersionedUserOperation::V06(op) => op.has_paymaster(),
+            VersionedUserOperation::V07(op) => op.has_paymaster(),
+        }
+    }
+
+    pub fn paymaster(&self) -> Option<Address> {
+        match self {
+            VersionedUserOperation::V06(op) => op.paymaster(),
+            VersionedUserOperation::V07(op) => op.paymaster,
+        }
+    }
+
+    pub fn validate_basic(&self) -> Result<(), AccountAbstractionError> {
+        match self {
+            VersionedUserOperation::V06(op) => op.validate_basic(),
+            VersionedUserOperation::V07(op) => op.validate_basic(),
+        }
+    }
+
+    pub fn signature(&self) -> &[u8] {
+        match self {
+            VersionedUserOperation::V06(op) => &op.signature,
+            VersionedUserOperation::V07(op) => &op.signature,
+        }
+    }
+
+    /// Worst-case gas cost this op can be charged: `required_gas() *
+    /// max_fee_per_gas`, the same quantity `check_paymaster_funded` sizes a
+    /// paymaster's required prefund against. Used as the spend metric
+    /// against a session key's `spending_cap`, since this EntryPoint has no
+    /// visibility into whatever native-value transfer the op's `call_data`
+    /// might trigger once executed.
+    pub fn required_prefund(&self) -> u128 {
+        match self {
+            VersionedUserOperation::V06(op) => {
+                (op.required_gas() as u128).saturating_mul(op.max_fee_per_gas as u128)
+            }
+            VersionedUserOperation::V07(op) => {
+                (op.required_gas() as u128).saturating_mul(op.max_fee_per_gas())
+            }
+        }
+    }
+}
+
+/// A user operation as it arrives over the wire or out of the mempool,
+/// before signature recovery, nonce, and paymaster-stake checks have run.
+/// The only way to turn this into something `EntryPoint::queue_user_op`/
+/// `handle_ops` will accept is [`EntryPoint::verify`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnverifiedUserOp(VersionedUserOperation);
+
+impl UnverifiedUserOp {
+    pub fn new(op: VersionedUserOperation) -> Self {
+        Self(op)
+    }
+}
+
+impl From<UserOperation> for UnverifiedUserOp {
+    fn from(op: UserOperation) -> Self {
+        Self(VersionedUserOperation::V06(op))
+    }
+}
+
+impl From<UserOperationV07> for UnverifiedUserOp {
+    fn from(op: UserOperationV07) -> Self {
+        Self(VersionedUserOperation::V07(op))
+    }
+}
+
+impl From<VersionedUserOperation> for UnverifiedUserOp {
+    fn from(op: VersionedUserOperation) -> Self {
+        Self(op)
+    }
+}
+
+/// A user operation that has passed signature recovery, nonce, and
+/// paymaster-stake checks exactly once. Can only be constructed by
+/// [`EntryPoint::verify`], so the compiler — not a runtime re-check —
+/// guarantees execution never runs on an op that hasn't been checked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifiedUserOp {
+    op: VersionedUserOperation,
+    sender: Address,
+    op_hash: Hash,
+}
+
+impl VerifiedUserOp {
+    /// The verified operation itself.
+    pub fn op(&self) -> &VersionedUserOperation {
+        &self.op
+    }
+
+    /// Consume the wrapper and return the inner operation.
+    pub fn into_op(self) -> VersionedUserOperation {
+        self.op
+    }
+
+    /// The sender address, carried alongside the op so callers don't need
+    /// to re-derive it.
+    pub fn sender(&self) -> Address {
+        self.sender
+    }
+
+    /// The operation hash computed during verification.
+    pub fn hash(&self) -> Hash {
+        self.op_hash
+    }
+}
+
+/// Distinguishes who paid gas for a user operation, the way an EIP-2718
+/// envelope's type byte distinguishes transaction formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UserOpTxType {
+    /// The sender's own account paid for gas.
+    SelfPaying,
+    /// A paymaster sponsored this operation's gas.
+    Sponsored,
+}
+
 /// Result of user operation execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserOperationReceipt {
@@ -109,10 +679,19 @@ pub struct UserOperationReceipt {
     pub nonce: u128,
     /// Paymaster used (if any)
     pub paymaster: Option<Address>,
+    /// Whether the sender or a paymaster footed the gas bill
+    pub tx_type: UserOpTxType,
     /// Actual gas used
     pub actual_gas_used: u64,
-    /// Actual gas cost
+    /// Effective gas price: `min(max_fee_per_gas, base_fee_per_gas + max_priority_fee_per_gas)`
+    pub effective_gas_price: u128,
+    /// Actual gas cost: `actual_gas_used * effective_gas_price`
     pub actual_gas_cost: u128,
+    /// Bloom filter over `logs`' addresses and topics (all-zero until log
+    /// emission is wired up to real EVM execution)
+    pub logs_bloom: [u8; 256],
+    /// Logs emitted during execution
+    pub logs: Vec<luxtensor_core::receipt::Log>,
     /// Whether the operation succeeded
     pub success: bool,
     /// Revert reason if failed
@@ -125,6 +704,65 @@ pub struct UserOperationReceipt {
     pub block_hash: Hash,
 }
 
+/// A token-mode paymaster's quote for one user operation, parsed out of
+/// `paymaster_and_data`/`paymaster_data`: the ERC-20 the sender will be
+/// charged in, the most it's allowed to charge, and the quoted price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenPaymasterData {
+    /// ERC-20 token address the sender pays gas in.
+    pub token: Address,
+    /// Upper bound on what the sender authorized the paymaster to take,
+    /// in token units. Must not exceed the sender's approved allowance.
+    pub max_token_cost: u128,
+    /// Token units per wei of gas cost, fixed-point scaled by 1e18 (e.g.
+    /// a rate of `2 * 10^18` means 2 token units per wei).
+    pub exchange_rate: u128,
+}
+
+/// Byte layout of a token-mode paymaster's data: `token(20) ++
+/// max_token_cost(16, big-endian) ++ exchange_rate(16, big-endian)`.
+const TOKEN_PAYMASTER_DATA_LEN: usize = 20 + 16 + 16;
+
+fn parse_token_paymaster_data(data: &[u8]) -> Option<TokenPaymasterData> {
+    if data.len() < TOKEN_PAYMASTER_DATA_LEN {
+        return None;
+    }
+    let token = Address::try_from_slice(&data[0..20])?;
+    let max_token_cost = u128::from_be_bytes(data[20..36].try_into().ok()?);
+    let exchange_rate = u128::from_be_bytes(data[36..52].try_into().ok()?);
+    Some(TokenPaymasterData { token, max_token_cost, exchange_rate })
+}
+
+/// Convert a wei-denominated gas cost to token units at `exchange_rate`
+/// (token units per wei, fixed-point scaled by 1e18). See
+/// [`TokenPaymasterData::exchange_rate`].
+fn wei_to_token(wei: u128, exchange_rate: u128) -> u128 {
+    wei.saturating_mul(exchange_rate) / 1_000_000_000_000_000_000
+}
+
+/// A delegated signer an account owner has registered on `EntryPoint`,
+/// scoped to a time window, a whitelist of callable function selectors, and
+/// a lifetime spending cap. Lets a dApp hold a key that can act for the
+/// account without ever having full owner authority — if a user operation's
+/// signature recovers to a `SessionKey` instead of the account itself,
+/// `validate_user_op` enforces all three scopes before accepting it.
+#[derive(Debug, Clone)]
+pub struct SessionKey {
+    pub signer: Address,
+    pub valid_after: u64,
+    pub valid_until: u64,
+    /// The first 4 bytes of `call_data` a session-key-signed op may carry;
+    /// empty means the key cannot be used for any call.
+    pub allowed_selectors: Vec<[u8; 4]>,
+    /// Cumulative lifetime spend cap across every op signed by this key,
+    /// denominated the same way `required_prefund` is: gas units times
+    /// `max_fee_per_gas`. This EntryPoint has no visibility into the
+    /// native-value transfers a session key's calls might make once
+    /// executed, so worst-case gas cost stands in as the spend metric,
+    /// the same simplification `TokenPaymasterData` makes for gas cost.
+    pub spending_cap: u128,
+}
+
 /// Paymaster stake info
 #[derive(Debug, Clone)]
 pub struct PaymasterInfo {
@@ -132,10 +770,13 @@ pub struct PaymasterInfo {
     pub stake: u128,
     pub unstake_delay_sec: u64,
     pub deposit: u128,
+    /// Block at which a stake unlocked by `unlock_stake` becomes
+    /// withdrawable via `withdraw_stake`. `None` while the stake is locked.
+    pub unlocked_at_block: Option<u64>,
 }
 
 /// Account Abstraction Error
-#[derive(Debug, Clone, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum AccountAbstractionError {
     #[error("Verification gas limit exceeded")]
     VerificationGasExceeded,
@@ -157,8 +798,26 @@ pub enum AccountAbstractionError {
     ValidationFailed(String),
     #[error("Execution failed: {0}")]
     ExecutionFailed(String),
-    #[error("User operation expired")]
-    Expired,
+    #[error("User operation expired: valid_until {valid_until}, now {now}")]
+    Expired { valid_until: u64, now: u64 },
+    #[error("User operation not yet valid: valid_after {valid_after}, now {now}")]
+    NotYetValid { valid_after: u64, now: u64 },
+    #[error("Signer does not match the account owner and is not a registered session key")]
+    SessionKeyNotRegistered,
+    #[error("Session key is not authorized to call selector {selector:?}")]
+    SelectorNotAllowed { selector: [u8; 4] },
+    #[error("Session key spending cap exceeded: cap {cap}, attempted cumulative {attempted}")]
+    SpendingCapExceeded { cap: u128, attempted: u128 },
+    #[error("Sender is banned from the mempool due to poor reputation")]
+    SenderBanned,
+    #[error("Paymaster is banned from the mempool due to poor reputation")]
+    PaymasterBanned,
+    #[error("Sender already has a pending user operation (only one allowed without a staked paymaster)")]
+    TooManyPendingOpsForSender,
+    #[error("Paymaster stake is still locked; call unlock_stake and wait out the unstake delay first")]
+    StakeLocked,
+    #[error("Wrong chain id: expected {expected}, got {got}")]
+    WrongChainId { expected: u64, got: u64 },
 }
 
 /// EntryPoint contract implementation (ERC-4337)
@@ -168,39 +827,99 @@ pub enum AccountAbstractionError {
 pub struct EntryPoint {
     /// Supported entry point addresses
     pub supported_entry_points: Vec<Address>,
-    /// User operation nonces per sender
-    nonces: Arc<RwLock<HashMap<Address, u128>>>,
+    /// Per-sender, per-key nonce sequences. ERC-4337 keyed nonces let a
+    /// wallet run multiple independent nonce channels (e.g. one per session
+    /// key) in parallel instead of serializing every op behind one counter;
+    /// see `nonce_key`/`nonce_sequence`.
+    nonces: Arc<RwLock<HashMap<(Address, u64), u64>>>,
     /// Paymaster stakes
     paymasters: Arc<RwLock<HashMap<Address, PaymasterInfo>>>,
-    /// Pending user operations (by hash)
-    #[allow(dead_code)] // Reserved for operation bundling
-    pending_ops: Arc<RwLock<HashMap<Hash, UserOperation>>>,
+    /// Pending, already-verified user operations (by hash)
+    pending_ops: Arc<RwLock<HashMap<Hash, PendingOp>>>,
     /// Executed receipts (by hash)
     receipts: Arc<RwLock<HashMap<Hash, UserOperationReceipt>>>,
+    /// Per-entity (sender, paymaster, factory) reputation counters
+    reputations: Arc<RwLock<HashMap<Address, EntityReputation>>>,
+    /// Block number reputation counters were last decayed at
+    last_reputation_decay_block: Arc<RwLock<u64>>,
     /// Chain ID
     chain_id: u64,
+    /// L1 data-cost oracle used to fold rollup calldata-posting cost into
+    /// `pre_verification_gas`. Defaults to `MainnetGasOracle` (no L1 cost).
+    pre_verification_gas_oracle: Arc<dyn PreVerificationGasOracle>,
+    /// Each sender's registered BLS public key (G2, 128 bytes) for a given
+    /// aggregator, used by `validate_user_ops_aggregated`.
+    aggregator_pubkeys: Arc<RwLock<HashMap<(Address, Address), Vec<u8>>>>,
+    /// Token-mode paymaster balances: `(token, holder) -> balance`. Stands
+    /// in for real ERC-20 contract state, the way `paymasters` stands in
+    /// for a native-deposit paymaster's balance.
+    token_balances: Arc<RwLock<HashMap<(Address, Address), u128>>>,
+    /// Token-mode paymaster allowances: `(token, owner, spender) -> amount`.
+    token_allowances: Arc<RwLock<HashMap<(Address, Address, Address), u128>>>,
+    /// Delegated signers an account owner has registered, keyed by
+    /// `(account, signer)`. See [`SessionKey`].
+    session_keys: Arc<RwLock<HashMap<(Address, Address), SessionKey>>>,
+    /// Cumulative spend recorded per `(account, signer)` session key, so
+    /// `spending_cap` is enforced across the key's whole lifetime rather
+    /// than per-op.
+    session_key_spend: Arc<RwLock<HashMap<(Address, Address), u128>>>,
 }
 
 impl EntryPoint {
-    /// Create a new EntryPoint
+    /// Create a new EntryPoint supporting both the v0.6 and v0.7 wire
+    /// layouts, each at its own address, so clients can submit to either.
+    /// Assumes an L1 chain (no L1 data cost); use [`Self::with_oracle`] on
+    /// a rollup deployment.
     pub fn new(chain_id: u64) -> Self {
-        // Default entry point address (standard ERC-4337)
-        let entry_point_addr = Address::from([
+        Self::with_oracle(chain_id, Arc::new(MainnetGasOracle))
+    }
+
+    /// Create a new EntryPoint with an explicit [`PreVerificationGasOracle`],
+    /// so rollup deployments can fold their L1 calldata-posting cost into
+    /// `pre_verification_gas` instead of under-charging bundlers.
+    pub fn with_oracle(chain_id: u64, oracle: Arc<dyn PreVerificationGasOracle>) -> Self {
+        // v0.6 entry point address (standard ERC-4337)
+        let entry_point_v06 = Address::from([
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x43, 0x37, // 0x4337
         ]);
+        // v0.7 entry point address (matches the reference deployment, so
+        // v0.7 clients can submit against the address they already know).
+        let entry_point_v07 = Address::from([
+            0x00, 0x00, 0x00, 0x00, 0x71, 0x72, 0x7D, 0xe2,
+            0x2E, 0x5E, 0x9d, 0x8B, 0xAf, 0x0e, 0xda, 0xc6,
+            0xf3, 0x7d, 0xa0, 0x32,
+        ]);
 
         Self {
-            supported_entry_points: vec![entry_point_addr],
+            supported_entry_points: vec![entry_point_v06, entry_point_v07],
             nonces: Arc::new(RwLock::new(HashMap::new())),
             paymasters: Arc::new(RwLock::new(HashMap::new())),
             pending_ops: Arc::new(RwLock::new(HashMap::new())),
             receipts: Arc::new(RwLock::new(HashMap::new())),
+            reputations: Arc::new(RwLock::new(HashMap::new())),
+            last_reputation_decay_block: Arc::new(RwLock::new(0)),
             chain_id,
+            pre_verification_gas_oracle: oracle,
+            aggregator_pubkeys: Arc::new(RwLock::new(HashMap::new())),
+            token_balances: Arc::new(RwLock::new(HashMap::new())),
+            token_allowances: Arc::new(RwLock::new(HashMap::new())),
+            session_keys: Arc::new(RwLock::new(HashMap::new())),
+            session_key_spend: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    /// The v0.6 entry point address.
+    pub fn entry_point_v06(&self) -> Address {
+        self.supported_entry_points[0]
+    }
+
+    /// The v0.7 entry point address.
+    pub fn entry_point_v07(&self) -> Address {
+        self.supported_entry_points[1]
+    }
+
     /// Get supported entry points
     pub fn get_supported_entry_points(&self) -> Vec<String> {
         self.supported_entry_points
@@ -214,122 +933,497 @@ impl EntryPoint {
         self.chain_id
     }
 
-    /// Get nonce for a sender
-    pub fn get_nonce(&self, sender: &Address) -> u128 {
-        *self.nonces.read().get(sender).unwrap_or(&0)
+    /// Reject a submission claiming the wrong chain before doing any
+    /// signature-recovery work, so a bundler gets a clear `WrongChainId`
+    /// instead of an opaque `InvalidSignature` for a stray replay attempt
+    /// on a forked chain. `claimed_chain_id` is whatever chain id the
+    /// submitter attached out-of-band (e.g. an RPC request's `chainId`
+    /// parameter) — `UserOperation`/`UserOperationV07` carry no explicit
+    /// chain id field of their own, since `Eip155Domain` already binds one
+    /// implicitly into the op hash itself.
+    pub fn validate_chain_binding(&self, claimed_chain_id: u64) -> Result<(), AccountAbstractionError> {
+        if claimed_chain_id != self.chain_id {
+            return Err(AccountAbstractionError::WrongChainId { expected: self.chain_id, got: claimed_chain_id });
+        }
+        Ok(())
+    }
+
+    /// Get the current sequence number for `sender`'s nonce channel `key`.
+    /// A fresh channel starts at sequence 0.
+    pub fn get_nonce(&self, sender: &Address, key: u64) -> u64 {
+        *self.nonces.read().get(&(*sender, key)).unwrap_or(&0)
+    }
+
+    /// Register `key` as a delegated signer for `account`, so a user
+    /// operation signed by `key.signer` validates within its window,
+    /// selector whitelist, and spending cap instead of being rejected as an
+    /// invalid signature.
+    pub fn register_session_key(&self, account: Address, key: SessionKey) {
+        self.session_keys.write().insert((account, key.signer), key);
+    }
+
+    /// `account`'s registered session key for `signer`, if any.
+    pub fn session_key(&self, account: &Address, signer: &Address) -> Option<SessionKey> {
+        self.session_keys.read().get(&(*account, *signer)).cloned()
+    }
+
+    /// `signer`'s cumulative spend recorded so far as a session key for
+    /// `account`. See [`SessionKey::spending_cap`].
+    pub fn session_key_spend(&self, account: &Address, signer: &Address) -> u128 {
+        *self.session_key_spend.read().get(&(*account, *signer)).unwrap_or(&0)
     }
 
-    /// Validate a user operation
+    fn record_session_key_spend(&self, account: Address, signer: Address, amount: u128) {
+        let mut spend = self.session_key_spend.write();
+        let entry = spend.entry((account, signer)).or_insert(0);
+        *entry = entry.saturating_add(amount);
+    }
+
+    /// Validate a user operation. `current_timestamp` is the Unix time the
+    /// caller asserts this validation happens at, used to check the op's
+    /// (or its session key's) validity window — this EntryPoint never reads
+    /// the wall clock itself, the same way `simulate_validation` takes
+    /// `base_fee_per_gas` rather than looking up a block itself.
     pub fn validate_user_op(
         &self,
         user_op: &UserOperation,
+        current_timestamp: u64,
     ) -> Result<(), AccountAbstractionError> {
         // Basic validation (includes signature non-empty check)
         user_op.validate_basic()?;
 
-        // Verify the signature against the user operation hash
-        // The signature must be valid for the sender's address
         let entry_point = self.supported_entry_points.first()
             .ok_or(AccountAbstractionError::InvalidSignature)?;
         let op_hash = user_op.hash(entry_point, self.chain_id);
-        if !self.verify_user_op_signature(user_op, &op_hash) {
-            return Err(AccountAbstractionError::InvalidSignature);
-        }
-
-        // Check nonce
-        let expected_nonce = self.get_nonce(&user_op.sender);
-        if user_op.nonce != expected_nonce {
+        let required_prefund = (user_op.required_gas() as u128).saturating_mul(user_op.max_fee_per_gas as u128);
+        self.validate_signer_and_window(
+            user_op.sender,
+            &user_op.signature,
+            &user_op.call_data,
+            required_prefund,
+            &op_hash,
+            current_timestamp,
+        )?;
+
+        // Check nonce: the op's sequence must match the stored sequence for
+        // its channel — other channels for the same sender are unaffected.
+        let expected_sequence = self.get_nonce(&user_op.sender, nonce_key(user_op.nonce));
+        if nonce_sequence(user_op.nonce) != expected_sequence {
             return Err(AccountAbstractionError::InvalidNonce);
         }
 
-        // Check paymaster stake if used
+        // Check paymaster stake and deposit if used
         if user_op.has_paymaster() {
             let paymaster = match user_op.paymaster() {
                 Some(p) => p,
                 None => return Err(AccountAbstractionError::InvalidPaymaster),
             };
-            let paymasters = self.paymasters.read();
-            match paymasters.get(&paymaster) {
-                Some(info) if info.stake >= MIN_PAYMASTER_STAKE => {}
-                _ => return Err(AccountAbstractionError::PaymasterNotStaked),
-            }
+            self.check_paymaster_funded(&paymaster, required_prefund)?;
         }
 
         debug!("User operation validated: {:?}", user_op.sender);
         Ok(())
     }
 
-    /// Verify the signature on a UserOperation
-    /// Returns true if the signature is valid for the sender address
-    fn verify_user_op_signature(&self, user_op: &UserOperation, op_hash: &Hash) -> bool {
+    /// Recover the signer address from `signature` over `op_hash`, without
+    /// assuming who it should be. Shared by both the v0.6 and v0.7
+    /// validation paths so they never diverge on how a signature is
+    /// actually recovered.
+    fn recover_op_signer(&self, signature: &[u8], op_hash: &Hash) -> Option<Address> {
         // Signature must be at least 64 bytes (compact ECDSA) + 1 byte recovery id
-        if user_op.signature.len() < 64 {
-            return false;
+        if signature.len() < 64 {
+            return None;
         }
 
-        let sig_bytes: [u8; 64] = match user_op.signature[..64].try_into() {
-            Ok(s) => s,
-            Err(_) => return false,
-        };
+        let sig_bytes: [u8; 64] = signature[..64].try_into().ok()?;
 
         // Recovery ID is the 65th byte (if present), default to 0
-        let recovery_id = if user_op.signature.len() > 64 {
-            user_op.signature[64]
+        let recovery_id = if signature.len() > 64 { signature[64] } else { 0 };
+
+        let recovered_pubkey = luxtensor_crypto::recover_public_key(op_hash, &sig_bytes, recovery_id).ok()?;
+        let recovered_addr = luxtensor_crypto::address_from_public_key(&recovered_pubkey).ok()?;
+        Some(Address::from(*recovered_addr.as_bytes()))
+    }
+
+    /// Resolve who signed `signature` over `op_hash` and enforce its
+    /// validity window — and, if the signer is a registered session key
+    /// rather than `sender` itself, the key's selector whitelist and
+    /// spending cap too. Returns the session key's signer address when one
+    /// was used (so `verify` can record its spend once the op is actually
+    /// accepted), or `None` when `sender` signed for itself.
+    fn validate_signer_and_window(
+        &self,
+        sender: Address,
+        signature: &[u8],
+        call_data: &[u8],
+        required_prefund: u128,
+        op_hash: &Hash,
+        current_timestamp: u64,
+    ) -> Result<Option<Address>, AccountAbstractionError> {
+        let recovered = self.recover_op_signer(signature, op_hash)
+            .ok_or(AccountAbstractionError::InvalidSignature)?;
+        let (sig_valid_after, sig_valid_until) = decode_validity_window(signature);
+
+        let (valid_after, valid_until, session_signer) = if recovered == sender {
+            (sig_valid_after, sig_valid_until, None)
         } else {
-            0
-        };
+            let key = self.session_key(&sender, &recovered)
+                .ok_or(AccountAbstractionError::SessionKeyNotRegistered)?;
+
+            let selector: Option<[u8; 4]> = call_data.get(0..4).and_then(|s| s.try_into().ok());
+            let allowed = selector.map(|s| key.allowed_selectors.contains(&s)).unwrap_or(false);
+            if !allowed {
+                return Err(AccountAbstractionError::SelectorNotAllowed {
+                    selector: selector.unwrap_or_default(),
+                });
+            }
 
-        // Recover public key from signature
-        match luxtensor_crypto::recover_public_key(op_hash, &sig_bytes, recovery_id) {
-            Ok(recovered_pubkey) => {
-                // Derive address from recovered public key
-                match luxtensor_crypto::address_from_public_key(&recovered_pubkey) {
-                    Ok(recovered_addr) => {
-                        // Compare recovered address with sender
-                        recovered_addr.as_bytes() == user_op.sender.as_bytes()
-                    }
-                    Err(_) => false,
-                }
+            let attempted = self.session_key_spend(&sender, &recovered).saturating_add(required_prefund);
+            if attempted > key.spending_cap {
+                return Err(AccountAbstractionError::SpendingCapExceeded { cap: key.spending_cap, attempted });
             }
-            Err(_) => false,
+
+            // The op's own window (if present) and the key's window both
+            // apply — the narrower of the two wins.
+            (sig_valid_after.max(key.valid_after), sig_valid_until.min(key.valid_until), Some(recovered))
+        };
+
+        if current_timestamp < valid_after {
+            return Err(AccountAbstractionError::NotYetValid { valid_after, now: current_timestamp });
         }
+        if current_timestamp > valid_until {
+            return Err(AccountAbstractionError::Expired { valid_until, now: current_timestamp });
+        }
+
+        Ok(session_signer)
     }
 
-    /// Simulate validation of a user operation
-    pub fn simulate_validation(
+    /// Validate a v0.7 user operation. Mirrors `validate_user_op` but
+    /// against the v0.7 wire layout and entry point address.
+    pub fn validate_user_op_v07(
         &self,
-        user_op: &UserOperation,
-    ) -> Result<SimulationResult, AccountAbstractionError> {
-        // Validate
-        self.validate_user_op(user_op)?;
+        user_op: &UserOperationV07,
+        current_timestamp: u64,
+    ) -> Result<(), AccountAbstractionError> {
+        user_op.validate_basic()?;
 
-        // Estimate gas
-        let pre_op_gas = user_op.verification_gas_limit + user_op.pre_verification_gas;
+        let entry_point = self.entry_point_v07();
+        let op_hash = user_op.hash(&entry_point, self.chain_id);
+        let required_prefund = (user_op.required_gas() as u128).saturating_mul(user_op.max_fee_per_gas());
+        self.validate_signer_and_window(
+            user_op.sender,
+            &user_op.signature,
+            &user_op.call_data,
+            required_prefund,
+            &op_hash,
+            current_timestamp,
+        )?;
+
+        let expected_sequence = self.get_nonce(&user_op.sender, nonce_key(user_op.nonce));
+        if nonce_sequence(user_op.nonce) != expected_sequence {
+            return Err(AccountAbstractionError::InvalidNonce);
+        }
 
-        Ok(SimulationResult {
-            pre_op_gas,
-            prefund: (user_op.required_gas() as u128).saturating_mul(user_op.max_fee_per_gas as u128),
-            valid_after: 0,
-            valid_until: u64::MAX,
-        })
-    }
+        if let Some(paymaster) = user_op.paymaster {
+            self.check_paymaster_funded(&paymaster, required_prefund)?;
+        }
 
-    /// Queue a validated user operation for inclusion in the next block.
-    /// Returns the operation hash.
-    pub fn queue_user_op(&self, user_op: UserOperation) -> Hash {
-        let ep_addr = &self.supported_entry_points[0];
-        let op_hash = user_op.hash(ep_addr, self.chain_id);
-        self.pending_ops.write().insert(op_hash, user_op);
-        op_hash
+        debug!("User operation (v0.7) validated: {:?}", user_op.sender);
+        Ok(())
     }
 
-    /// Drain all pending user operations for block inclusion.
-    /// Returns the ops removed from the pending pool.
-    pub fn drain_pending_ops(&self) -> Vec<UserOperation> {
-        let mut pending = self.pending_ops.write();
-        let ops: Vec<UserOperation> = pending.values().cloned().collect();
-        pending.clear();
-        ops
+    /// Check a paymaster has enough stake and deposit to cover
+    /// `required_prefund`, the worst-case gas cost (at `max_fee_per_gas`)
+    /// it's being asked to sponsor.
+    fn check_paymaster_funded(
+        &self,
+        paymaster: &Address,
+        required_prefund: u128,
+    ) -> Result<(), AccountAbstractionError> {
+        let paymasters = self.paymasters.read();
+        match paymasters.get(paymaster) {
+            Some(info) if info.stake < MIN_PAYMASTER_STAKE => Err(AccountAbstractionError::PaymasterNotStaked),
+            Some(info) if info.deposit < required_prefund => Err(AccountAbstractionError::InsufficientBalance),
+            Some(_) => Ok(()),
+            None => Err(AccountAbstractionError::PaymasterNotStaked),
+        }
+    }
+
+    /// ERC-4337's `validatePaymasterUserOp` step: if `paymaster` is
+    /// present, checks its stake and deposit cover `required_prefund` and
+    /// returns the opaque context `post_op` will need once the op's actual
+    /// gas cost is known. Returns `Ok(None)` for self-paying ops.
+    pub fn validate_paymaster_user_op(
+        &self,
+        paymaster: Option<Address>,
+        required_prefund: u128,
+    ) -> Result<Option<PaymasterContext>, AccountAbstractionError> {
+        let paymaster = match paymaster {
+            Some(p) => p,
+            None => return Ok(None),
+        };
+        self.check_paymaster_funded(&paymaster, required_prefund)?;
+        Ok(Some(PaymasterContext { context: Vec::new(), valid_after: 0, valid_until: u64::MAX, token: None }))
+    }
+
+    /// Set `owner`'s ERC-20 allowance for `spender`, the way `approve` on
+    /// the real token contract would. This EntryPoint models token balances
+    /// and allowances itself (mirroring how `deposit_to` models native
+    /// paymaster deposits) rather than calling out to a real token
+    /// contract, since this module has no EVM state handle to do so.
+    pub fn approve_token(&self, token: Address, owner: Address, spender: Address, amount: u128) {
+        self.token_allowances.write().insert((token, owner, spender), amount);
+    }
+
+    /// `owner`'s current ERC-20 allowance for `spender`.
+    pub fn token_allowance(&self, token: &Address, owner: &Address, spender: &Address) -> u128 {
+        *self.token_allowances.read().get(&(*token, *owner, *spender)).unwrap_or(&0)
+    }
+
+    /// Credit `holder` with `amount` of `token`, standing in for the real
+    /// token contract's balance (e.g. after an off-chain mint/transfer),
+    /// so tests and callers can fund a sender before it pays gas in tokens.
+    pub fn credit_token(&self, token: Address, holder: Address, amount: u128) {
+        let mut balances = self.token_balances.write();
+        let balance = balances.entry((token, holder)).or_insert(0);
+        *balance = balance.saturating_add(amount);
+    }
+
+    /// `holder`'s current balance of `token`.
+    pub fn token_balance_of(&self, token: &Address, holder: &Address) -> u128 {
+        *self.token_balances.read().get(&(*token, *holder)).unwrap_or(&0)
+    }
+
+    /// Token-mode `validatePaymasterUserOp`: checks `sender` approved at
+    /// least `token_data.max_token_cost` to `paymaster`, and that the
+    /// worst-case gas cost (converted to token units at the quoted
+    /// exchange rate) doesn't exceed that cap, then returns the context
+    /// `post_op` needs to settle once the op's actual gas cost is known.
+    pub fn validate_token_paymaster_user_op(
+        &self,
+        sender: Address,
+        paymaster: Address,
+        token_data: &TokenPaymasterData,
+        required_prefund_wei: u128,
+    ) -> Result<PaymasterContext, AccountAbstractionError> {
+        if self.token_allowance(&token_data.token, &sender, &paymaster) < token_data.max_token_cost {
+            return Err(AccountAbstractionError::InsufficientBalance);
+        }
+        let required_prefund_token = wei_to_token(required_prefund_wei, token_data.exchange_rate);
+        if required_prefund_token > token_data.max_token_cost {
+            return Err(AccountAbstractionError::ValidationFailed(
+                "quoted token cost exceeds the sender's authorized max_token_cost".to_string(),
+            ));
+        }
+        Ok(PaymasterContext {
+            context: Vec::new(),
+            valid_after: 0,
+            valid_until: u64::MAX,
+            token: Some(TokenSettlement { sender, token: token_data.token, exchange_rate: token_data.exchange_rate }),
+        })
+    }
+
+    /// ERC-4337's `postOp` step: debit `paymaster`'s deposit by the
+    /// operation's actual gas cost and credit `beneficiary` (the bundler),
+    /// settling the sponsorship `validate_paymaster_user_op` reserved. When
+    /// `context` carries a token-mode settlement (see
+    /// `validate_token_paymaster_user_op`), the sender is charged in the
+    /// token instead of the paymaster's native deposit. `mode` lets
+    /// settlement differ if the sponsored call itself failed.
+    pub fn post_op(
+        &self,
+        paymaster: Address,
+        context: &PaymasterContext,
+        actual_gas_cost: u128,
+        beneficiary: &Address,
+        mode: PostOpMode,
+    ) -> Result<(), AccountAbstractionError> {
+        if mode == PostOpMode::PostOpReverted {
+            // The bundler is retrying after a first `post_op` call reverted.
+            // This EntryPoint has no custom paymaster logic to re-run, so
+            // there's nothing left to settle.
+            return Ok(());
+        }
+
+        if let Some(token) = &context.token {
+            return self.settle_token_paymaster(paymaster, token, actual_gas_cost);
+        }
+
+        let mut paymasters = self.paymasters.write();
+        {
+            let info = paymasters.get_mut(&paymaster).ok_or(AccountAbstractionError::PaymasterNotStaked)?;
+            if info.deposit < actual_gas_cost {
+                return Err(AccountAbstractionError::InsufficientBalance);
+            }
+            info.deposit -= actual_gas_cost;
+        }
+        let beneficiary_info = paymasters.entry(*beneficiary).or_insert(PaymasterInfo {
+            address: *beneficiary,
+            stake: 0,
+            unstake_delay_sec: 0,
+            deposit: 0,
+            unlocked_at_block: None,
+        });
+        beneficiary_info.deposit = beneficiary_info.deposit.saturating_add(actual_gas_cost);
+        Ok(())
+    }
+
+    /// Charge `token.sender` for `actual_gas_cost` wei of gas, converted to
+    /// token units at the quoted exchange rate, debiting both their token
+    /// balance and allowance (standard ERC-20 `transferFrom` semantics) and
+    /// crediting `paymaster`.
+    fn settle_token_paymaster(
+        &self,
+        paymaster: Address,
+        token: &TokenSettlement,
+        actual_gas_cost: u128,
+    ) -> Result<(), AccountAbstractionError> {
+        let token_cost = wei_to_token(actual_gas_cost, token.exchange_rate);
+
+        {
+            let mut balances = self.token_balances.write();
+            let sender_balance = balances.entry((token.token, token.sender)).or_insert(0);
+            if *sender_balance < token_cost {
+                return Err(AccountAbstractionError::InsufficientBalance);
+            }
+            *sender_balance -= token_cost;
+        }
+        {
+            let mut allowances = self.token_allowances.write();
+            if let Some(allowance) = allowances.get_mut(&(token.token, token.sender, paymaster)) {
+                *allowance = allowance.saturating_sub(token_cost);
+            }
+        }
+        self.credit_token(token.token, paymaster, token_cost);
+        Ok(())
+    }
+
+    /// Validate a user operation of either version, dispatching on which
+    /// entry point wire layout it was declared against.
+    pub fn validate_versioned(
+        &self,
+        op: &VersionedUserOperation,
+        current_timestamp: u64,
+    ) -> Result<(), AccountAbstractionError> {
+        match op {
+            VersionedUserOperation::V06(op) => self.validate_user_op(op, current_timestamp),
+            VersionedUserOperation::V07(op) => self.validate_user_op_v07(op, current_timestamp),
+        }
+    }
+
+    /// Verify an unverified user operation: signature recovery, nonce check,
+    /// and paymaster-stake check, run exactly once. The returned
+    /// `VerifiedUserOp` is the only thing `queue_user_op`/`handle_ops`
+    /// accept, so the compiler guarantees execution never runs on an op
+    /// that hasn't passed these checks — no re-verification inside
+    /// `handle_single_op` needed.
+    pub fn verify(
+        &self,
+        unverified: UnverifiedUserOp,
+        current_timestamp: u64,
+    ) -> Result<VerifiedUserOp, AccountAbstractionError> {
+        let op = unverified.0;
+        self.validate_versioned(&op, current_timestamp)?;
+
+        let sender = op.sender();
+        let entry_point = match &op {
+            VersionedUserOperation::V06(_) => self.entry_point_v06(),
+            VersionedUserOperation::V07(_) => self.entry_point_v07(),
+        };
+        let op_hash = op.hash(&entry_point, self.chain_id);
+
+        // A registered session key's spend is only recorded once the op is
+        // actually accepted here, not during `simulate_validation`'s dry run.
+        if let Some(signer) = self.recover_op_signer(op.signature(), &op_hash) {
+            if signer != sender && self.session_key(&sender, &signer).is_some() {
+                self.record_session_key_spend(sender, signer, op.required_prefund());
+            }
+        }
+
+        Ok(VerifiedUserOp { op, sender, op_hash })
+    }
+
+    /// Simulate validation of a user operation. `base_fee_per_gas` is the
+    /// current block's EIP-1559 base fee, used to derive the effective gas
+    /// price ceiling the sender is actually on the hook for. `current_timestamp`
+    /// is checked against the op's (or session key's) validity window the
+    /// same way `validate_user_op` checks it for real inclusion.
+    pub fn simulate_validation(
+        &self,
+        user_op: &UserOperation,
+        base_fee_per_gas: u128,
+        current_timestamp: u64,
+    ) -> Result<SimulationResult, AccountAbstractionError> {
+        // Validate
+        self.validate_user_op(user_op, current_timestamp)?;
+
+        // Estimate gas, folding in the L1 data-cost component so a rollup
+        // deployment doesn't under-report pre_verification_gas.
+        let l1_gas = self.l1_gas_component(user_op, user_op.max_fee_per_gas);
+        let pre_op_gas =
+            user_op.verification_gas_limit + user_op.pre_verification_gas.saturating_add(l1_gas);
+
+        let effective_gas_price = effective_gas_price(
+            user_op.max_fee_per_gas as u128,
+            user_op.max_priority_fee_per_gas as u128,
+            base_fee_per_gas,
+        );
+
+        Ok(SimulationResult {
+            pre_op_gas,
+            prefund: (user_op.required_gas() as u128).saturating_mul(effective_gas_price),
+            valid_after: 0,
+            valid_until: u64::MAX,
+        })
+    }
+
+    /// Serialize `user_op` the way it would be posted to L1 as calldata,
+    /// and run it through the configured `PreVerificationGasOracle`.
+    fn l1_gas_component(&self, user_op: &UserOperation, l2_gas_price: u64) -> u64 {
+        let op_calldata = bincode::serialize(user_op).unwrap_or_default();
+        self.pre_verification_gas_oracle.l1_gas_component(&op_calldata, l2_gas_price)
+    }
+
+    /// Queue an already-verified user operation for inclusion in the next
+    /// block, at `current_block`. Returns the operation hash. This is the
+    /// low-level primitive; [`Self::add_pending_op`] is the mempool-aware
+    /// entry point bundlers should use instead, since it also enforces
+    /// reputation and per-sender caps.
+    pub fn queue_user_op(&self, verified: VerifiedUserOp, current_block: u64) -> Hash {
+        let op_hash = verified.op_hash;
+        self.pending_ops.write().insert(op_hash, PendingOp { verified, queued_at_block: current_block });
+        op_hash
+    }
+
+    /// Drain pending user operations for block inclusion, dropping any
+    /// whose sender or paymaster is now BANNED, and any THROTTLED op that
+    /// has sat in the pool longer than [`THROTTLED_OP_EXPIRY_BLOCKS`].
+    pub fn drain_pending_ops(&self, current_block: u64) -> Vec<VerifiedUserOp> {
+        let mut pending = self.pending_ops.write();
+        let mut selected = Vec::with_capacity(pending.len());
+
+        for (_, entry) in pending.drain() {
+            let sender_status = self.reputation_status(&entry.verified.sender());
+            let paymaster_status = entry.verified.op().paymaster()
+                .map(|p| self.reputation_status(&p))
+                .unwrap_or(ReputationStatus::Ok);
+
+            if sender_status == ReputationStatus::Banned || paymaster_status == ReputationStatus::Banned {
+                debug!("Dropping pending op {:?}: entity banned", entry.verified.hash());
+                continue;
+            }
+
+            let throttled = sender_status == ReputationStatus::Throttled
+                || paymaster_status == ReputationStatus::Throttled;
+            if throttled && current_block.saturating_sub(entry.queued_at_block) > THROTTLED_OP_EXPIRY_BLOCKS {
+                debug!("Dropping pending op {:?}: throttled op expired", entry.verified.hash());
+                continue;
+            }
+
+            selected.push(entry.verified);
+        }
+
+        selected
     }
 
     /// Get number of pending user operations
@@ -337,75 +1431,238 @@ impl EntryPoint {
         self.pending_ops.read().len()
     }
 
-    /// Handle a batch of user operations
+    /// Reputation status of a mempool entity (sender, paymaster, or
+    /// factory address), derived from its seen/included/rejected counters.
+    pub fn reputation_status(&self, entity: &Address) -> ReputationStatus {
+        self.reputations.read().get(entity).map(|r| r.status()).unwrap_or(ReputationStatus::Ok)
+    }
+
+    /// Halve every entity's reputation counters once
+    /// [`REPUTATION_DECAY_INTERVAL_BLOCKS`] have passed since the last
+    /// decay, the way OpenEthereum's banning queue lets reputation recover
+    /// over time instead of bans/throttling being permanent.
+    fn decay_reputations(&self, current_block: u64) {
+        let mut last_decay = self.last_reputation_decay_block.write();
+        if current_block < last_decay.saturating_add(REPUTATION_DECAY_INTERVAL_BLOCKS) {
+            return;
+        }
+        *last_decay = current_block;
+        drop(last_decay);
+
+        for rep in self.reputations.write().values_mut() {
+            rep.ops_seen /= 2;
+            rep.ops_included /= 2;
+            rep.ops_rejected /= 2;
+        }
+        debug!("Decayed mempool reputation counters at block {}", current_block);
+    }
+
+    fn record_seen(&self, sender: Address, paymaster: Option<Address>) {
+        self.reputations.write().entry(sender).or_default().ops_seen += 1;
+        if let Some(paymaster) = paymaster {
+            self.reputations.write().entry(paymaster).or_default().ops_seen += 1;
+        }
+    }
+
+    fn record_rejected(&self, sender: Address, paymaster: Option<Address>) {
+        self.reputations.write().entry(sender).or_default().ops_rejected += 1;
+        if let Some(paymaster) = paymaster {
+            self.reputations.write().entry(paymaster).or_default().ops_rejected += 1;
+        }
+    }
+
+    fn record_included(&self, sender: Address, paymaster: Option<Address>) {
+        self.reputations.write().entry(sender).or_default().ops_included += 1;
+        if let Some(paymaster) = paymaster {
+            self.reputations.write().entry(paymaster).or_default().ops_included += 1;
+        }
+    }
+
+    /// Mempool-aware entry point for submitting a user operation: verifies
+    /// it, consults ERC-7562-style reputation for its sender and paymaster,
+    /// enforces a per-sender cap of one pending op (unless the paymaster is
+    /// staked above [`MIN_PAYMASTER_STAKE`], or the sender is THROTTLED, in
+    /// which case the cap is [`THROTTLED_PENDING_OP_LIMIT`]), and only then
+    /// queues it. Every attempt — accepted or rejected — updates the
+    /// entity's reputation counters. `current_timestamp` is checked against
+    /// the op's (or session key's) validity window; see `validate_user_op`.
+    pub fn add_pending_op(
+        &self,
+        unverified: UnverifiedUserOp,
+        current_block: u64,
+        current_timestamp: u64,
+    ) -> Result<Hash, AccountAbstractionError> {
+        self.decay_reputations(current_block);
+
+        let sender = unverified.0.sender();
+        let paymaster = unverified.0.paymaster();
+
+        let sender_status = self.reputation_status(&sender);
+        if sender_status == ReputationStatus::Banned {
+            self.record_rejected(sender, paymaster);
+            return Err(AccountAbstractionError::SenderBanned);
+        }
+        if let Some(paymaster) = paymaster {
+            if self.reputation_status(&paymaster) == ReputationStatus::Banned {
+                self.record_rejected(sender, Some(paymaster));
+                return Err(AccountAbstractionError::PaymasterBanned);
+            }
+        }
+
+        let paymaster_is_staked = paymaster
+            .map(|p| {
+                self.paymasters.read().get(&p).map(|info| info.stake >= MIN_PAYMASTER_STAKE).unwrap_or(false)
+            })
+            .unwrap_or(false);
+        let pending_cap = if sender_status == ReputationStatus::Throttled {
+            THROTTLED_PENDING_OP_LIMIT
+        } else if paymaster_is_staked {
+            usize::MAX
+        } else {
+            1
+        };
+        let sender_pending_count = self.pending_ops.read().values()
+            .filter(|entry| entry.verified.sender() == sender)
+            .count();
+        if sender_pending_count >= pending_cap {
+            self.record_rejected(sender, paymaster);
+            return Err(AccountAbstractionError::TooManyPendingOpsForSender);
+        }
+
+        let verified = match self.verify(unverified, current_timestamp) {
+            Ok(verified) => verified,
+            Err(e) => {
+                self.record_rejected(sender, paymaster);
+                return Err(e);
+            }
+        };
+
+        self.record_seen(sender, paymaster);
+        Ok(self.queue_user_op(verified, current_block))
+    }
+
+    /// Handle a batch of already-verified user operations.
+    ///
+    /// Taking `VerifiedUserOp` rather than a raw `UserOperation` means the
+    /// compiler guarantees every op here already passed `EntryPoint::verify`
+    /// — there is no re-validation, and no way to call this with an op that
+    /// hasn't been checked.
     ///
     /// `block_number` and `block_hash` are provided by the block producer and
     /// attached to every receipt so that downstream consumers can locate the
-    /// inclusion proof.
+    /// inclusion proof. `base_fee_per_gas` is the block's EIP-1559 base fee,
+    /// used to compute each op's effective gas price.
     pub fn handle_ops(
         &self,
-        ops: Vec<UserOperation>,
+        ops: Vec<VerifiedUserOp>,
         beneficiary: Address,
         block_number: u64,
         block_hash: Hash,
+        base_fee_per_gas: u128,
     ) -> Vec<Result<UserOperationReceipt, AccountAbstractionError>> {
         let mut results = Vec::new();
 
         for op in ops {
-            let result = self.handle_single_op(op, &beneficiary, block_number, block_hash);
+            let result = self.handle_single_op(op, &beneficiary, block_number, block_hash, base_fee_per_gas);
             results.push(result);
         }
 
         results
     }
 
-    /// Handle a single user operation
+    /// Handle a single already-verified user operation.
     ///
-    /// Executes the user operation: validates, estimates gas, updates nonce,
-    /// and records the receipt. Gas cost is computed from the conservative
-    /// estimate (verification + pre-verification + calldata cost).
+    /// Estimates gas, updates the sender's nonce, and records the receipt.
+    /// Gas cost is computed from the conservative estimate (verification +
+    /// pre-verification + calldata cost) priced at the EIP-1559 effective
+    /// gas price rather than the raw `max_fee_per_gas` ceiling.
     ///
     /// Full EVM execution of `call_data` on `sender` will be wired once
     /// the block producer passes the shared EvmExecutor into handle_ops.
     fn handle_single_op(
         &self,
-        user_op: UserOperation,
+        verified: VerifiedUserOp,
         beneficiary: &Address,
         block_number: u64,
         block_hash: Hash,
+        base_fee_per_gas: u128,
     ) -> Result<UserOperationReceipt, AccountAbstractionError> {
-        let entry_point = &self.supported_entry_points[0];
-        let op_hash = user_op.hash(entry_point, self.chain_id);
+        let op_hash = verified.op_hash;
+
+        let (sender, nonce, paymaster, token_data, gas_used, price) = match verified.op {
+            VersionedUserOperation::V06(user_op) => {
+                // Conservative gas estimate:
+                // verification_gas + pre_verification_gas + base call cost + calldata cost
+                let verification_gas = user_op.verification_gas_limit.min(MAX_VERIFICATION_GAS);
+                let calldata_gas = (user_op.call_data.len() as u64) * 16; // 16 gas per non-zero byte (worst case)
+                let base_execution_gas = 21_000u64; // Base transaction cost
+                let gas_used = verification_gas
+                    .saturating_add(user_op.pre_verification_gas)
+                    .saturating_add(base_execution_gas)
+                    .saturating_add(calldata_gas)
+                    .min(user_op.call_gas_limit + user_op.verification_gas_limit + user_op.pre_verification_gas);
+                let price = effective_gas_price(
+                    user_op.max_fee_per_gas as u128,
+                    user_op.max_priority_fee_per_gas as u128,
+                    base_fee_per_gas,
+                );
+                (user_op.sender, user_op.nonce, user_op.paymaster(), user_op.token_paymaster_data(), gas_used, price)
+            }
+            VersionedUserOperation::V07(user_op) => {
+                let verification_gas = (user_op.verification_gas_limit() as u64).min(MAX_VERIFICATION_GAS);
+                let calldata_gas = (user_op.call_data.len() as u64) * 16;
+                let base_execution_gas = 21_000u64;
+                let gas_used = verification_gas
+                    .saturating_add(user_op.pre_verification_gas)
+                    .saturating_add(base_execution_gas)
+                    .saturating_add(calldata_gas)
+                    .min(user_op.required_gas());
+                let price = effective_gas_price(
+                    user_op.max_fee_per_gas(),
+                    user_op.max_priority_fee_per_gas(),
+                    base_fee_per_gas,
+                );
+                (user_op.sender, user_op.nonce, user_op.paymaster, user_op.token_paymaster_data(), gas_used, price)
+            }
+        };
+        let gas_cost = (gas_used as u128).saturating_mul(price);
+        let tx_type = if paymaster.is_some() { UserOpTxType::Sponsored } else { UserOpTxType::SelfPaying };
+
+        // Settle sponsorship: debit the paymaster's deposit (or the
+        // sender's token balance, in token mode) by the actual gas cost and
+        // credit the beneficiary. `required_prefund` here is just
+        // `gas_cost` itself since it's already known precisely at this
+        // point, unlike at validation time. This EntryPoint never models an
+        // inner-call revert, so settlement always uses `OpSucceeded`.
+        if let Some(paymaster_addr) = paymaster {
+            let context = match &token_data {
+                Some(token_data) => Some(self.validate_token_paymaster_user_op(sender, paymaster_addr, token_data, gas_cost)?),
+                None => self.validate_paymaster_user_op(Some(paymaster_addr), gas_cost)?,
+            };
+            if let Some(context) = context {
+                self.post_op(paymaster_addr, &context, gas_cost, beneficiary, PostOpMode::OpSucceeded)?;
+            }
+        }
 
-        // Validate
-        self.validate_user_op(&user_op)?;
-
-        // Conservative gas estimate:
-        // verification_gas + pre_verification_gas + base call cost + calldata cost
-        let verification_gas = user_op.verification_gas_limit.min(MAX_VERIFICATION_GAS);
-        let calldata_gas = (user_op.call_data.len() as u64) * 16; // 16 gas per non-zero byte (worst case)
-        let base_execution_gas = 21_000u64; // Base transaction cost
-        let gas_used = verification_gas
-            .saturating_add(user_op.pre_verification_gas)
-            .saturating_add(base_execution_gas)
-            .saturating_add(calldata_gas)
-            .min(user_op.call_gas_limit + user_op.verification_gas_limit + user_op.pre_verification_gas);
-        let gas_cost = (gas_used as u128).saturating_mul(user_op.max_fee_per_gas as u128);
-
-        // Update nonce
+        // Increment only this op's nonce channel — other channels for the
+        // same sender are untouched, so they don't queue behind this one.
         {
             let mut nonces = self.nonces.write();
-            let nonce = nonces.entry(user_op.sender).or_insert(0);
-            *nonce += 1;
+            let sequence_entry = nonces.entry((sender, nonce_key(nonce))).or_insert(0);
+            *sequence_entry += 1;
         }
 
         let receipt = UserOperationReceipt {
             user_op_hash: op_hash,
-            sender: user_op.sender,
-            nonce: user_op.nonce,
-            paymaster: user_op.paymaster(),
+            sender,
+            nonce,
+            paymaster,
+            tx_type,
             actual_gas_used: gas_used,
+            effective_gas_price: price,
             actual_gas_cost: gas_cost,
+            logs_bloom: [0u8; 256],
+            logs: Vec::new(),
             success: true,
             reason: None,
             transaction_hash: op_hash, // Bundler sets final tx hash when included
@@ -415,10 +1672,11 @@ impl EntryPoint {
 
         // Store receipt
         self.receipts.write().insert(op_hash, receipt.clone());
+        self.record_included(sender, paymaster);
 
         info!(
             "Executed user operation: sender={:?}, nonce={}, gas_used={}, gas_cost={}, beneficiary={:?}",
-            user_op.sender, user_op.nonce, gas_used, gas_cost, beneficiary
+            sender, nonce, gas_used, gas_cost, beneficiary
         );
 
         Ok(receipt)
@@ -451,8 +1709,10 @@ impl EntryPoint {
             100_000 + (user_op.call_data.len() as u64 * 16) // Data cost
         };
 
-        // Pre-verification gas
-        let pre_verification_gas = 21_000 + (user_op.call_data.len() as u64 * 4);
+        // Pre-verification gas, including the L1 calldata-posting cost on
+        // rollup deployments (zero on a mainnet-configured EntryPoint).
+        let l1_gas = self.l1_gas_component(user_op, user_op.max_fee_per_gas);
+        let pre_verification_gas = 21_000 + (user_op.call_data.len() as u64 * 4) + l1_gas;
 
         Ok(GasEstimate {
             pre_verification_gas,
@@ -474,9 +1734,12 @@ impl EntryPoint {
             stake: 0,
             unstake_delay_sec: 0,
             deposit: 0,
+            unlocked_at_block: None,
         });
         info.stake = info.stake.saturating_add(stake);
         info.unstake_delay_sec = unstake_delay_sec;
+        // Staking again cancels any withdrawal previously started by `unlock_stake`.
+        info.unlocked_at_block = None;
 
         info!("Paymaster staked: {:?}, stake={}", paymaster, info.stake);
     }
@@ -489,6 +1752,7 @@ impl EntryPoint {
             stake: 0,
             unstake_delay_sec: 0,
             deposit: 0,
+            unlocked_at_block: None,
         });
         info.deposit = info.deposit.saturating_add(amount);
     }
@@ -501,6 +1765,152 @@ impl EntryPoint {
             .map(|i| i.deposit)
             .unwrap_or(0)
     }
+
+    /// Withdraw `amount` from a paymaster's deposit, the counterpart to
+    /// `deposit_to`. `_withdraw_address` mirrors ERC-4337's
+    /// `withdrawTo(address, uint256)` signature; this EntryPoint doesn't
+    /// model real token transfers, so it's unused beyond documenting where
+    /// the funds would go.
+    pub fn withdraw_to(
+        &self,
+        paymaster: Address,
+        _withdraw_address: Address,
+        amount: u128,
+    ) -> Result<(), AccountAbstractionError> {
+        let mut paymasters = self.paymasters.write();
+        let info = paymasters.get_mut(&paymaster).ok_or(AccountAbstractionError::PaymasterNotStaked)?;
+        if info.deposit < amount {
+            return Err(AccountAbstractionError::InsufficientBalance);
+        }
+        info.deposit -= amount;
+        Ok(())
+    }
+
+    /// Start the unstake-delay clock on a paymaster's stake. The stake
+    /// becomes withdrawable via `withdraw_stake` once `current_block` has
+    /// advanced `unstake_delay_sec` blocks past this call — mirroring
+    /// ERC-4337's `unlockStake`, with the delay measured in blocks rather
+    /// than wall-clock seconds since that's how this module already tracks
+    /// time elsewhere (see `REPUTATION_DECAY_INTERVAL_BLOCKS`).
+    pub fn unlock_stake(&self, paymaster: Address, current_block: u64) -> Result<(), AccountAbstractionError> {
+        let mut paymasters = self.paymasters.write();
+        let info = paymasters.get_mut(&paymaster).ok_or(AccountAbstractionError::PaymasterNotStaked)?;
+        info.unlocked_at_block = Some(current_block.saturating_add(info.unstake_delay_sec));
+        Ok(())
+    }
+
+    /// Withdraw a paymaster's stake once `unlock_stake` was called and
+    /// `current_block` has passed the unlock block. Returns the withdrawn
+    /// amount and zeroes the stake.
+    pub fn withdraw_stake(&self, paymaster: Address, current_block: u64) -> Result<u128, AccountAbstractionError> {
+        let mut paymasters = self.paymasters.write();
+        let info = paymasters.get_mut(&paymaster).ok_or(AccountAbstractionError::PaymasterNotStaked)?;
+        match info.unlocked_at_block {
+            Some(unlock_block) if current_block >= unlock_block => {
+                let stake = info.stake;
+                info.stake = 0;
+                info.unlocked_at_block = None;
+                Ok(stake)
+            }
+            _ => Err(AccountAbstractionError::StakeLocked),
+        }
+    }
+
+    /// Register `sender`'s BLS public key (G2, 128 bytes) with `aggregator`,
+    /// so `validate_user_ops_aggregated` can look it up when checking a
+    /// bundle's aggregate signature.
+    pub fn register_aggregator_pubkey(
+        &self,
+        sender: Address,
+        aggregator: Address,
+        pubkey: Vec<u8>,
+    ) {
+        self.aggregator_pubkeys.write().insert((sender, aggregator), pubkey);
+    }
+
+    /// Validate a whole bundle of ops against a single aggregate BLS
+    /// signature instead of checking each op's signature individually.
+    /// Every op must come from a sender that has registered a public key
+    /// with `aggregator` via `register_aggregator_pubkey`; nonce ordering is
+    /// still checked per op, since aggregation only replaces signature
+    /// verification. The batch is accepted or rejected as a whole: one bad
+    /// op hash or missing pubkey fails the entire bundle.
+    pub fn validate_user_ops_aggregated(
+        &self,
+        ops: &[UserOperation],
+        aggregator: Address,
+        agg_sig: &[u8],
+    ) -> Result<(), AccountAbstractionError> {
+        if ops.is_empty() {
+            return Err(AccountAbstractionError::ValidationFailed(
+                "no user operations to aggregate".to_string(),
+            ));
+        }
+
+        let entry_point = self.entry_point_v06();
+        let pubkeys_by_sender = self.aggregator_pubkeys.read();
+
+        let mut op_hashes = Vec::with_capacity(ops.len());
+        let mut pubkeys = Vec::with_capacity(ops.len());
+        for op in ops {
+            op.validate_basic()?;
+
+            let expected_sequence = self.get_nonce(&op.sender, nonce_key(op.nonce));
+            if nonce_sequence(op.nonce) != expected_sequence {
+                return Err(AccountAbstractionError::InvalidNonce);
+            }
+
+            let pubkey = pubkeys_by_sender
+                .get(&(op.sender, aggregator))
+                .cloned()
+                .ok_or(AccountAbstractionError::InvalidSignature)?;
+            op_hashes.push(op.hash(&entry_point, self.chain_id));
+            pubkeys.push(pubkey);
+        }
+
+        BlsAggregator
+            .validate_signatures(&op_hashes, &pubkeys, agg_sig)
+            .map_err(|_| AccountAbstractionError::InvalidSignature)
+    }
+}
+
+/// Opaque context threaded from `validate_paymaster_user_op` to `post_op`,
+/// mirroring ERC-4337's `validatePaymasterUserOp`/`postOp` pair. Real
+/// token-based sponsorship paymasters would stash whatever they need to
+/// settle here (e.g. a token price quote); this EntryPoint's built-in
+/// paymaster accounting leaves `context` empty, using the dedicated `token`
+/// field instead for token-mode settlement.
+#[derive(Debug, Clone, Default)]
+pub struct PaymasterContext {
+    pub context: Vec<u8>,
+    pub valid_after: u64,
+    pub valid_until: u64,
+    /// Set when `paymaster_and_data`/`paymaster_data` quoted a token-mode
+    /// settlement; `post_op` charges the token instead of the paymaster's
+    /// native deposit when this is present.
+    pub token: Option<TokenSettlement>,
+}
+
+/// The token-mode settlement `validate_token_paymaster_user_op` quotes and
+/// `post_op` later charges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenSettlement {
+    pub sender: Address,
+    pub token: Address,
+    pub exchange_rate: u128,
+}
+
+/// Outcome of a user operation's inner call, mirroring ERC-4337's `postOp`
+/// mode so a paymaster can settle differently depending on whether the call
+/// it sponsored actually succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostOpMode {
+    /// The sender's call succeeded.
+    OpSucceeded,
+    /// The sender's call reverted, but gas is still owed.
+    OpReverted,
+    /// A first `post_op` call itself reverted; this is the bundler's retry.
+    PostOpReverted,
 }
 
 /// Simulation result
@@ -587,7 +1997,8 @@ mod tests {
     fn test_entry_point_creation() {
         let entry_point = EntryPoint::new(TEST_CHAIN_ID);
         let supported = entry_point.get_supported_entry_points();
-        assert_eq!(supported.len(), 1);
+        assert_eq!(supported.len(), 2, "should support both the v0.6 and v0.7 entry points");
+        assert_ne!(entry_point.entry_point_v06(), entry_point.entry_point_v07());
     }
 
     #[test]
@@ -608,7 +2019,7 @@ mod tests {
         let entry_point = EntryPoint::new(TEST_CHAIN_ID);
         let op = create_test_user_op();
 
-        let result = entry_point.validate_user_op(&op);
+        let result = entry_point.validate_user_op(&op, 0);
         assert!(result.is_ok());
     }
 
@@ -636,7 +2047,7 @@ mod tests {
         // but fails the nonce check
         sign_user_op(&mut op, &keypair, &ep_addr, TEST_CHAIN_ID);
 
-        let result = entry_point.validate_user_op(&op);
+        let result = entry_point.validate_user_op(&op, 0);
         assert!(matches!(result, Err(AccountAbstractionError::InvalidNonce)));
     }
 
@@ -646,7 +2057,8 @@ mod tests {
         let op = create_test_user_op();
         let beneficiary = Address::from([2u8; 20]);
 
-        let results = entry_point.handle_ops(vec![op], beneficiary, 42, [0xBBu8; 32]);
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+        let results = entry_point.handle_ops(vec![verified], beneficiary, 42, [0xBBu8; 32], 100_000_000);
         assert_eq!(results.len(), 1);
         assert!(results[0].is_ok());
 
@@ -654,6 +2066,9 @@ mod tests {
         assert!(receipt.success);
         assert_eq!(receipt.block_number, 42);
         assert_eq!(receipt.block_hash, [0xBBu8; 32]);
+        assert_eq!(receipt.tx_type, UserOpTxType::SelfPaying);
+        assert!(receipt.effective_gas_price > 0);
+        assert_eq!(receipt.actual_gas_cost, receipt.actual_gas_used as u128 * receipt.effective_gas_price);
     }
 
     #[test]
@@ -662,68 +2077,849 @@ mod tests {
         let keypair = test_keypair();
         let sender = Address::from(keypair.address());
 
-        assert_eq!(entry_point.get_nonce(&sender), 0);
+        assert_eq!(entry_point.get_nonce(&sender, 0), 0);
 
         let op = create_test_user_op();
         let beneficiary = Address::from([2u8; 20]);
-        let _ = entry_point.handle_ops(vec![op], beneficiary, 1, [0u8; 32]);
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+        let _ = entry_point.handle_ops(vec![verified], beneficiary, 1, [0u8; 32], 100_000_000);
 
-        assert_eq!(entry_point.get_nonce(&sender), 1);
+        assert_eq!(entry_point.get_nonce(&sender, 0), 1);
     }
 
     #[test]
-    fn test_estimate_gas() {
+    fn test_keyed_nonces_allow_independent_parallel_channels() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let keypair = test_keypair();
+        let ep_addr = entry_point.supported_entry_points[0];
+        let beneficiary = Address::from([2u8; 20]);
+
+        // Two ops on different nonce channels (key 0 and key 1), both at
+        // sequence 0, should both validate and execute independently —
+        // neither blocks on the other.
+        let mut op_channel_0 = create_test_user_op();
+        op_channel_0.nonce = 0u128; // key=0, sequence=0
+        sign_user_op(&mut op_channel_0, &keypair, &ep_addr, TEST_CHAIN_ID);
+
+        let mut op_channel_1 = create_test_user_op();
+        op_channel_1.nonce = 1u128 << 64; // key=1, sequence=0
+        sign_user_op(&mut op_channel_1, &keypair, &ep_addr, TEST_CHAIN_ID);
+
+        let verified_0 = entry_point.verify(op_channel_0.into(), 0).unwrap();
+        let verified_1 = entry_point.verify(op_channel_1.into(), 0).unwrap();
+        let results = entry_point.handle_ops(vec![verified_0, verified_1], beneficiary, 1, [0u8; 32], 100_000_000);
+        assert!(results.iter().all(|r| r.is_ok()));
+
+        let sender = Address::from(keypair.address());
+        assert_eq!(entry_point.get_nonce(&sender, 0), 1);
+        assert_eq!(entry_point.get_nonce(&sender, 1), 1);
+    }
+
+    #[test]
+    fn test_validate_user_ops_aggregated_rejects_unregistered_sender() {
         let entry_point = EntryPoint::new(TEST_CHAIN_ID);
         let op = create_test_user_op();
+        let aggregator = Address::from([9u8; 20]);
 
-        let estimate = entry_point.estimate_user_op_gas(&op).unwrap();
-        assert!(estimate.verification_gas > 0);
-        assert!(estimate.call_gas > 0);
-        assert!(estimate.pre_verification_gas > 0);
+        // No pubkey was ever registered for (op.sender, aggregator), so the
+        // whole batch must be rejected even with a well-formed signature.
+        let result = entry_point.validate_user_ops_aggregated(&[op], aggregator, &[0u8; 64]);
+        assert!(matches!(result, Err(AccountAbstractionError::InvalidSignature)));
     }
 
     #[test]
-    fn test_paymaster_stake() {
+    fn test_validate_user_ops_aggregated_rejects_stale_nonce() {
         let entry_point = EntryPoint::new(TEST_CHAIN_ID);
-        let paymaster = Address::from([3u8; 20]);
+        let mut op = create_test_user_op();
+        op.nonce = 5; // key=0, sequence=5, but a fresh channel starts at 0
+        let aggregator = Address::from([9u8; 20]);
+        entry_point.register_aggregator_pubkey(op.sender, aggregator, vec![0u8; 128]);
 
-        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE, 86400);
+        let result = entry_point.validate_user_ops_aggregated(&[op], aggregator, &[0u8; 64]);
+        assert!(matches!(result, Err(AccountAbstractionError::InvalidNonce)));
+    }
 
-        let deposit = entry_point.get_deposit(&paymaster);
-        assert_eq!(deposit, 0);
+    #[test]
+    fn test_validate_user_ops_aggregated_rejects_empty_batch() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let aggregator = Address::from([9u8; 20]);
+        let result = entry_point.validate_user_ops_aggregated(&[], aggregator, &[0u8; 64]);
+        assert!(matches!(result, Err(AccountAbstractionError::ValidationFailed(_))));
+    }
 
-        entry_point.deposit_to(paymaster, 100);
-        assert_eq!(entry_point.get_deposit(&paymaster), 100);
+    #[test]
+    fn test_op_hash_differs_across_chain_ids() {
+        let op = create_test_user_op();
+        let ep_addr = Address::from([0x43u8; 20]);
+        let hash_a = op.hash(&ep_addr, 1);
+        let hash_b = op.hash(&ep_addr, 2);
+        assert_ne!(hash_a, hash_b);
     }
 
     #[test]
-    fn test_user_op_with_paymaster() {
+    fn test_signature_from_one_chain_is_invalid_on_another() {
+        let entry_point_chain_1 = EntryPoint::new(1);
+        let entry_point_chain_2 = EntryPoint::new(2);
+        let keypair = test_keypair();
+        let ep_addr = entry_point_chain_1.supported_entry_points[0];
+
+        let mut op = create_test_user_op();
+        op.sender = Address::from(keypair.address());
+        sign_user_op(&mut op, &keypair, &ep_addr, 1);
+
+        assert!(entry_point_chain_1.validate_user_op(&op, 0).is_ok());
+        assert!(matches!(
+            entry_point_chain_2.validate_user_op(&op, 0),
+            Err(AccountAbstractionError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn test_validate_chain_binding_rejects_mismatched_chain_id() {
         let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        assert!(entry_point.validate_chain_binding(TEST_CHAIN_ID).is_ok());
+        assert!(matches!(
+            entry_point.validate_chain_binding(TEST_CHAIN_ID + 1),
+            Err(AccountAbstractionError::WrongChainId { expected: TEST_CHAIN_ID, got }) if got == TEST_CHAIN_ID + 1
+        ));
+    }
+
+    #[test]
+    fn test_recover_signer_with_eip155_v_encoding() {
         let keypair = test_keypair();
         let sender = Address::from(keypair.address());
-        let paymaster = Address::from([3u8; 20]);
-        let ep_addr = entry_point.supported_entry_points[0];
+        let op = create_test_user_op();
+        let ep_addr = Address::from([0x43u8; 20]);
+        // A small chain id, since `v = recovery_id + chain_id*2 + 35` must
+        // still fit in this module's single signature-trailer byte.
+        let chain_id = 1u64;
+        let op_hash = op.hash(&ep_addr, chain_id);
 
-        // Add paymaster stake
-        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE, 86400);
+        let sig = keypair.sign(&op_hash).unwrap();
+        let mut recovery_id = None;
+        for rid in 0u8..=1 {
+            if let Ok(pubkey) = luxtensor_crypto::recover_public_key(&op_hash, &sig, rid) {
+                if let Ok(addr) = luxtensor_crypto::address_from_public_key(&pubkey) {
+                    if addr.as_bytes() == sender.as_bytes() {
+                        recovery_id = Some(rid);
+                        break;
+                    }
+                }
+            }
+        }
+        let recovery_id = recovery_id.expect("signature should recover");
 
-        let mut op = UserOperation {
-            sender,
-            nonce: 0,
-            init_code: vec![],
-            call_data: vec![0x12, 0x34, 0x56, 0x78],
-            call_gas_limit: 100_000,
-            verification_gas_limit: 100_000,
-            pre_verification_gas: 21_000,
-            max_fee_per_gas: 1_000_000_000,
-            max_priority_fee_per_gas: 1_000_000,
-            paymaster_and_data: paymaster.as_bytes().to_vec(),
-            signature: vec![0x00],
-        };
-        // Sign AFTER setting paymaster_and_data since it's part of the hash
-        sign_user_op(&mut op, &keypair, &ep_addr, TEST_CHAIN_ID);
+        let mut eip155_sig = sig.to_vec();
+        let v = recovery_id as u64 + chain_id * 2 + 35;
+        eip155_sig.push(v as u8);
 
-        let result = entry_point.validate_user_op(&op);
-        assert!(result.is_ok());
+        let recovered = recover_signer(&op_hash, &eip155_sig, chain_id).unwrap();
+        assert_eq!(recovered, sender);
+    }
+
+    #[test]
+    fn test_estimate_gas() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let op = create_test_user_op();
+
+        let estimate = entry_point.estimate_user_op_gas(&op).unwrap();
+        assert!(estimate.verification_gas > 0);
+        assert!(estimate.call_gas > 0);
+        assert!(estimate.pre_verification_gas > 0);
+    }
+
+    #[test]
+    fn test_effective_gas_price_is_capped_at_max_fee() {
+        // base_fee + priority_fee exceeds max_fee, so the sender pays no more than max_fee.
+        assert_eq!(effective_gas_price(1_000_000_000, 500_000_000, 900_000_000), 1_000_000_000);
+        // base_fee + priority_fee is below max_fee, so that cheaper sum wins.
+        assert_eq!(effective_gas_price(1_000_000_000, 100_000_000, 200_000_000), 300_000_000);
+    }
+
+    #[test]
+    fn test_handle_ops_charges_effective_gas_price_not_max_fee() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let op = create_test_user_op(); // max_fee_per_gas = 1_000_000_000, priority = 1_000_000
+        let beneficiary = Address::from([2u8; 20]);
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+
+        // A low base fee means the effective price is far below max_fee_per_gas.
+        let results = entry_point.handle_ops(vec![verified], beneficiary, 1, [0u8; 32], 1);
+        let receipt = results[0].as_ref().unwrap();
+        assert_eq!(receipt.effective_gas_price, 1 + 1_000_000); // base_fee + priority_fee
+        assert!(receipt.effective_gas_price < 1_000_000_000);
+        assert_eq!(receipt.actual_gas_cost, receipt.actual_gas_used as u128 * receipt.effective_gas_price);
+    }
+
+    #[test]
+    fn test_simulate_validation_prefund_uses_effective_price() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let op = create_test_user_op();
+
+        let low_base_fee_sim = entry_point.simulate_validation(&op, 1, 0).unwrap();
+        let high_base_fee_sim = entry_point.simulate_validation(&op, op.max_fee_per_gas as u128, 0).unwrap();
+
+        // A higher base fee raises the effective price towards max_fee_per_gas,
+        // so the prefund required must not shrink.
+        assert!(high_base_fee_sim.prefund >= low_base_fee_sim.prefund);
+    }
+
+    #[test]
+    fn test_mainnet_oracle_adds_no_l1_cost() {
+        let oracle = MainnetGasOracle;
+        assert_eq!(oracle.l1_gas_component(&[1, 2, 3], 1_000), 0);
+    }
+
+    #[test]
+    fn test_arbitrum_oracle_scales_with_calldata_length() {
+        let oracle = ArbitrumGasOracle { per_byte_gas: 100 };
+        assert_eq!(oracle.l1_gas_component(&[0u8; 50], 1_000), 5_000);
+    }
+
+    #[test]
+    fn test_optimism_oracle_scales_with_l1_base_fee_and_gas_price() {
+        let oracle = OptimismGasOracle {
+            l1_base_fee: 1_000,
+            fixed_overhead: 100,
+            dynamic_overhead_numerator: 110,
+            dynamic_overhead_denominator: 100,
+        };
+        let calldata = vec![0u8, 1u8, 2u8, 0u8]; // 2 zero + 2 non-zero bytes
+        let l2_gas = oracle.l1_gas_component(&calldata, 10);
+        assert!(l2_gas > 0);
+
+        // Doubling the L2 gas price should roughly halve the L2 gas units charged.
+        let l2_gas_double_price = oracle.l1_gas_component(&calldata, 20);
+        assert!(l2_gas_double_price < l2_gas);
+
+        // Zero gas price must not panic (division guarded).
+        assert_eq!(oracle.l1_gas_component(&calldata, 0), 0);
+    }
+
+    #[test]
+    fn test_estimate_user_op_gas_on_rollup_includes_l1_cost() {
+        let l1_entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let rollup_entry_point = EntryPoint::with_oracle(
+            TEST_CHAIN_ID,
+            Arc::new(ArbitrumGasOracle { per_byte_gas: 1_000 }),
+        );
+        let op = create_test_user_op();
+
+        let l1_estimate = l1_entry_point.estimate_user_op_gas(&op).unwrap();
+        let rollup_estimate = rollup_entry_point.estimate_user_op_gas(&op).unwrap();
+
+        assert!(rollup_estimate.pre_verification_gas > l1_estimate.pre_verification_gas);
+    }
+
+    #[test]
+    fn test_paymaster_stake() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let paymaster = Address::from([3u8; 20]);
+
+        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE, 86400);
+
+        let deposit = entry_point.get_deposit(&paymaster);
+        assert_eq!(deposit, 0);
+
+        entry_point.deposit_to(paymaster, 100);
+        assert_eq!(entry_point.get_deposit(&paymaster), 100);
+    }
+
+    #[test]
+    fn test_user_op_with_paymaster() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let keypair = test_keypair();
+        let sender = Address::from(keypair.address());
+        let paymaster = Address::from([3u8; 20]);
+        let ep_addr = entry_point.supported_entry_points[0];
+
+        // Add paymaster stake and enough deposit to cover the op's worst-case prefund
+        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE, 86400);
+        entry_point.deposit_to(paymaster, MIN_PAYMASTER_STAKE);
+
+        let mut op = UserOperation {
+            sender,
+            nonce: 0,
+            init_code: vec![],
+            call_data: vec![0x12, 0x34, 0x56, 0x78],
+            call_gas_limit: 100_000,
+            verification_gas_limit: 100_000,
+            pre_verification_gas: 21_000,
+            max_fee_per_gas: 1_000_000_000,
+            max_priority_fee_per_gas: 1_000_000,
+            paymaster_and_data: paymaster.as_bytes().to_vec(),
+            signature: vec![0x00],
+        };
+        // Sign AFTER setting paymaster_and_data since it's part of the hash
+        sign_user_op(&mut op, &keypair, &ep_addr, TEST_CHAIN_ID);
+
+        let result = entry_point.validate_user_op(&op, 0);
+        assert!(result.is_ok());
+
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+        let beneficiary = Address::from([4u8; 20]);
+        let results = entry_point.handle_ops(vec![verified], beneficiary, 1, [0u8; 32], 100_000_000);
+        let receipt = results[0].as_ref().unwrap();
+        assert_eq!(receipt.tx_type, UserOpTxType::Sponsored);
+        assert_eq!(receipt.paymaster, Some(paymaster));
+
+        // postOp settlement: the paymaster was debited by the actual gas
+        // cost and the beneficiary was credited the same amount.
+        assert_eq!(
+            entry_point.get_deposit(&paymaster),
+            MIN_PAYMASTER_STAKE - receipt.actual_gas_cost
+        );
+        assert_eq!(entry_point.get_deposit(&beneficiary), receipt.actual_gas_cost);
+    }
+
+    #[test]
+    fn test_user_op_with_token_paymaster_settles_in_token() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let keypair = test_keypair();
+        let sender = Address::from(keypair.address());
+        let paymaster = Address::from([3u8; 20]);
+        let token = Address::from([7u8; 20]);
+        let ep_addr = entry_point.supported_entry_points[0];
+
+        // The paymaster still needs native stake+deposit to cover the
+        // bundler's up-front gas outlay; the sender reimburses it in
+        // tokens afterward.
+        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE, 86400);
+        entry_point.deposit_to(paymaster, MIN_PAYMASTER_STAKE);
+
+        let exchange_rate = 2_000_000_000_000_000_000u128; // 2 token units per wei
+        let max_token_cost = u128::MAX / 4; // generous cap, won't bind in this test
+        let mut paymaster_and_data = paymaster.as_bytes().to_vec();
+        paymaster_and_data.extend_from_slice(token.as_bytes());
+        paymaster_and_data.extend_from_slice(&max_token_cost.to_be_bytes());
+        paymaster_and_data.extend_from_slice(&exchange_rate.to_be_bytes());
+
+        entry_point.credit_token(token, sender, u128::MAX / 4);
+        entry_point.approve_token(token, sender, paymaster, max_token_cost);
+
+        let mut op = UserOperation {
+            sender,
+            nonce: 0,
+            init_code: vec![],
+            call_data: vec![0x12, 0x34, 0x56, 0x78],
+            call_gas_limit: 100_000,
+            verification_gas_limit: 100_000,
+            pre_verification_gas: 21_000,
+            max_fee_per_gas: 1_000_000_000,
+            max_priority_fee_per_gas: 1_000_000,
+            paymaster_and_data,
+            signature: vec![0x00],
+        };
+        sign_user_op(&mut op, &keypair, &ep_addr, TEST_CHAIN_ID);
+
+        assert!(entry_point.validate_user_op(&op, 0).is_ok());
+
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+        let beneficiary = Address::from([4u8; 20]);
+        let results = entry_point.handle_ops(vec![verified], beneficiary, 1, [0u8; 32], 100_000_000);
+        let receipt = results[0].as_ref().unwrap();
+        assert_eq!(receipt.tx_type, UserOpTxType::Sponsored);
+
+        let expected_token_cost = receipt.actual_gas_cost * 2;
+        assert_eq!(entry_point.token_balance_of(&token, &paymaster), expected_token_cost);
+        assert_eq!(
+            entry_point.token_allowance(&token, &sender, &paymaster),
+            max_token_cost - expected_token_cost
+        );
+        // Native paymaster deposit is untouched: settlement happened in tokens.
+        assert_eq!(entry_point.get_deposit(&paymaster), MIN_PAYMASTER_STAKE);
+    }
+
+    #[test]
+    fn test_token_paymaster_settlement_fails_without_enough_allowance() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let keypair = test_keypair();
+        let sender = Address::from(keypair.address());
+        let paymaster = Address::from([3u8; 20]);
+        let token = Address::from([7u8; 20]);
+        let ep_addr = entry_point.supported_entry_points[0];
+
+        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE, 86400);
+        entry_point.deposit_to(paymaster, MIN_PAYMASTER_STAKE);
+
+        let exchange_rate = 1_000_000_000_000_000_000u128; // 1:1
+        let max_token_cost = 1u128; // far too small to cover real gas cost
+        let mut paymaster_and_data = paymaster.as_bytes().to_vec();
+        paymaster_and_data.extend_from_slice(token.as_bytes());
+        paymaster_and_data.extend_from_slice(&max_token_cost.to_be_bytes());
+        paymaster_and_data.extend_from_slice(&exchange_rate.to_be_bytes());
+        // Sender never approved anything.
+
+        let mut op = UserOperation {
+            sender,
+            nonce: 0,
+            init_code: vec![],
+            call_data: vec![0x12, 0x34, 0x56, 0x78],
+            call_gas_limit: 100_000,
+            verification_gas_limit: 100_000,
+            pre_verification_gas: 21_000,
+            max_fee_per_gas: 1_000_000_000,
+            max_priority_fee_per_gas: 1_000_000,
+            paymaster_and_data,
+            signature: vec![0x00],
+        };
+        sign_user_op(&mut op, &keypair, &ep_addr, TEST_CHAIN_ID);
+
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+        let beneficiary = Address::from([4u8; 20]);
+        let results = entry_point.handle_ops(vec![verified], beneficiary, 1, [0u8; 32], 100_000_000);
+        assert!(matches!(results[0], Err(AccountAbstractionError::InsufficientBalance)));
+    }
+
+    #[test]
+    fn test_validate_user_op_rejects_paymaster_without_enough_deposit() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let keypair = test_keypair();
+        let sender = Address::from(keypair.address());
+        let paymaster = Address::from([3u8; 20]);
+        let ep_addr = entry_point.supported_entry_points[0];
+
+        // Staked, but never deposited anything to cover sponsorship.
+        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE, 86400);
+
+        let mut op = UserOperation {
+            sender,
+            nonce: 0,
+            init_code: vec![],
+            call_data: vec![0x12, 0x34, 0x56, 0x78],
+            call_gas_limit: 100_000,
+            verification_gas_limit: 100_000,
+            pre_verification_gas: 21_000,
+            max_fee_per_gas: 1_000_000_000,
+            max_priority_fee_per_gas: 1_000_000,
+            paymaster_and_data: paymaster.as_bytes().to_vec(),
+            signature: vec![0x00],
+        };
+        sign_user_op(&mut op, &keypair, &ep_addr, TEST_CHAIN_ID);
+
+        let result = entry_point.validate_user_op(&op, 0);
+        assert!(matches!(result, Err(AccountAbstractionError::InsufficientBalance)));
+    }
+
+    #[test]
+    fn test_unlock_stake_then_withdraw_after_delay() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let paymaster = Address::from([3u8; 20]);
+        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE, 100);
+
+        // Can't withdraw before unlocking.
+        assert!(matches!(
+            entry_point.withdraw_stake(paymaster, 1),
+            Err(AccountAbstractionError::StakeLocked)
+        ));
+
+        entry_point.unlock_stake(paymaster, 1).unwrap();
+
+        // Can't withdraw before the unstake delay elapses.
+        assert!(matches!(
+            entry_point.withdraw_stake(paymaster, 50),
+            Err(AccountAbstractionError::StakeLocked)
+        ));
+
+        let withdrawn = entry_point.withdraw_stake(paymaster, 101).unwrap();
+        assert_eq!(withdrawn, MIN_PAYMASTER_STAKE);
+    }
+
+    /// Sign a UserOperationV07 in-place, mirroring `sign_user_op`.
+    fn sign_user_op_v07(op: &mut UserOperationV07, keypair: &KeyPair, entry_point: &Address, chain_id: u64) {
+        let op_hash = op.hash(entry_point, chain_id);
+        let sig = keypair.sign(&op_hash).unwrap();
+        let sender_bytes = keypair.address();
+
+        for rid in 0u8..=1 {
+            if let Ok(pubkey) = luxtensor_crypto::recover_public_key(&op_hash, &sig, rid) {
+                if let Ok(addr) = luxtensor_crypto::address_from_public_key(&pubkey) {
+                    if addr == sender_bytes {
+                        let mut signature = sig.to_vec();
+                        signature.push(rid);
+                        op.signature = signature;
+                        return;
+                    }
+                }
+            }
+        }
+        panic!("Could not find valid recovery ID for test signature");
+    }
+
+    fn create_test_user_op_v07() -> UserOperationV07 {
+        let keypair = test_keypair();
+        let sender = Address::from(keypair.address());
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let ep_addr = entry_point.entry_point_v07();
+
+        let mut op = UserOperationV07 {
+            sender,
+            nonce: 0,
+            factory: None,
+            factory_data: vec![],
+            call_data: vec![0x12, 0x34, 0x56, 0x78],
+            account_gas_limits: UserOperationV07::pack_gas_limits(100_000, 100_000),
+            pre_verification_gas: 21_000,
+            gas_fees: UserOperationV07::pack_gas_fees(1_000_000, 1_000_000_000),
+            paymaster: None,
+            paymaster_verification_gas_limit: 0,
+            paymaster_post_op_gas_limit: 0,
+            paymaster_data: vec![],
+            signature: vec![0x00],
+        };
+        sign_user_op_v07(&mut op, &keypair, &ep_addr, TEST_CHAIN_ID);
+        op
+    }
+
+    #[test]
+    fn test_pack_unpack_gas_limits_round_trip() {
+        let packed = UserOperationV07::pack_gas_limits(123_456, 789_012);
+        let op = UserOperationV07 {
+            sender: Address::from([0u8; 20]),
+            nonce: 0,
+            factory: None,
+            factory_data: vec![],
+            call_data: vec![],
+            account_gas_limits: packed,
+            pre_verification_gas: 0,
+            gas_fees: UserOperationV07::pack_gas_fees(1, 2),
+            paymaster: None,
+            paymaster_verification_gas_limit: 0,
+            paymaster_post_op_gas_limit: 0,
+            paymaster_data: vec![],
+            signature: vec![],
+        };
+        assert_eq!(op.verification_gas_limit(), 123_456);
+        assert_eq!(op.call_gas_limit(), 789_012);
+        assert_eq!(op.max_priority_fee_per_gas(), 1);
+        assert_eq!(op.max_fee_per_gas(), 2);
+    }
+
+    #[test]
+    fn test_user_op_v07_hash_deterministic_and_chain_bound() {
+        let op = create_test_user_op_v07();
+        let entry_point = Address::from([0u8; 20]);
+        let hash1 = op.hash(&entry_point, 1);
+        let hash2 = op.hash(&entry_point, 1);
+        assert_eq!(hash1, hash2);
+
+        let hash3 = op.hash(&entry_point, 2);
+        assert_ne!(hash1, hash3);
+    }
+
+    #[test]
+    fn test_validate_user_op_v07() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let op = create_test_user_op_v07();
+        assert!(entry_point.validate_user_op_v07(&op, 0).is_ok());
+    }
+
+    #[test]
+    fn test_validate_versioned_dispatches_both_versions() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+
+        let v06 = create_test_user_op();
+        let v07 = create_test_user_op_v07();
+
+        assert!(entry_point.validate_versioned(&VersionedUserOperation::V06(v06), 0).is_ok());
+        assert!(entry_point.validate_versioned(&VersionedUserOperation::V07(v07), 0).is_ok());
+    }
+
+    #[test]
+    fn test_handle_ops_versioned_mixed_batch() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let v06 = create_test_user_op();
+        let v07 = create_test_user_op_v07();
+        let beneficiary = Address::from([2u8; 20]);
+
+        let verified_v06 = entry_point.verify(v06.into(), 0).unwrap();
+        let verified_v07 = entry_point.verify(v07.into(), 0).unwrap();
+
+        let results = entry_point.handle_ops(
+            vec![verified_v06, verified_v07],
+            beneficiary,
+            1,
+            [0u8; 32],
+            100_000_000,
+        );
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_bad_signature() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let mut op = create_test_user_op();
+        op.signature = vec![0u8; 65]; // corrupt the signature after signing
+
+        let result = entry_point.verify(op.into(), 0);
+        assert!(matches!(result, Err(AccountAbstractionError::InvalidSignature)));
+    }
+
+    #[test]
+    fn test_queue_and_drain_pending_ops_round_trip() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let op = create_test_user_op();
+
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+        let op_hash = entry_point.queue_user_op(verified, 10);
+
+        assert_eq!(entry_point.pending_count(), 1);
+        let drained = entry_point.drain_pending_ops(10);
+        assert_eq!(drained.len(), 1);
+        assert_eq!(drained[0].hash(), op_hash);
+        assert_eq!(entry_point.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_add_pending_op_enforces_single_pending_op_per_sender() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let keypair = test_keypair();
+        let ep_addr = entry_point.supported_entry_points[0];
+
+        let op1 = create_test_user_op();
+        let mut op2 = UserOperation { nonce: 1, ..op1.clone() };
+        sign_user_op(&mut op2, &keypair, &ep_addr, TEST_CHAIN_ID);
+
+        entry_point.add_pending_op(op1.into(), 1, 0).unwrap();
+        let result = entry_point.add_pending_op(op2.into(), 1, 0);
+        assert!(matches!(result, Err(AccountAbstractionError::TooManyPendingOpsForSender)));
+        assert_eq!(entry_point.pending_count(), 1);
+    }
+
+    #[test]
+    fn test_add_pending_op_allows_unlimited_pending_for_staked_paymaster() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let keypair = test_keypair();
+        let ep_addr = entry_point.supported_entry_points[0];
+        let paymaster = Address::from([3u8; 20]);
+        entry_point.add_paymaster_stake(paymaster, MIN_PAYMASTER_STAKE + 1, 86400);
+        entry_point.deposit_to(paymaster, MIN_PAYMASTER_STAKE);
+
+        // Each op is on its own nonce channel (key=i, sequence=0) rather than
+        // incrementing sequences on a single channel, since sequences only
+        // advance on execution — queuing several ops on one channel without
+        // executing in between would fail nonce validation from the second on.
+        for i in 0..5u128 {
+            let mut op = create_test_user_op();
+            op.nonce = i << 64;
+            op.paymaster_and_data = paymaster.as_bytes().to_vec();
+            sign_user_op(&mut op, &keypair, &ep_addr, TEST_CHAIN_ID);
+            entry_point.add_pending_op(op.into(), 1, 0).unwrap();
+        }
+
+        assert_eq!(entry_point.pending_count(), 5);
+    }
+
+    #[test]
+    fn test_reputation_bans_entity_after_excessive_rejections() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let sender = Address::from([9u8; 20]);
+
+        for _ in 0..4 {
+            entry_point.record_rejected(sender, None);
+        }
+        assert_eq!(entry_point.reputation_status(&sender), ReputationStatus::Banned);
+    }
+
+    #[test]
+    fn test_reputation_decay_recovers_banned_entity() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let sender = Address::from([9u8; 20]);
+
+        for _ in 0..4 {
+            entry_point.record_rejected(sender, None);
+        }
+        assert_eq!(entry_point.reputation_status(&sender), ReputationStatus::Banned);
+
+        // Several decay intervals of halving should eventually zero the
+        // rejected counter out and restore a clean reputation.
+        for round in 1..=10 {
+            entry_point.decay_reputations(round * REPUTATION_DECAY_INTERVAL_BLOCKS);
+        }
+        assert_eq!(entry_point.reputation_status(&sender), ReputationStatus::Ok);
+    }
+
+    #[test]
+    fn test_drain_pending_ops_drops_ops_from_banned_sender() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let op = create_test_user_op();
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+        let sender = verified.sender();
+        entry_point.queue_user_op(verified, 1);
+
+        for _ in 0..4 {
+            entry_point.record_rejected(sender, None);
+        }
+
+        let drained = entry_point.drain_pending_ops(1);
+        assert!(drained.is_empty());
+        assert_eq!(entry_point.pending_count(), 0);
+    }
+
+    /// Append a `valid_after ++ valid_until` window onto an already-signed
+    /// op's signature, the way a signer scoping a single op would.
+    fn append_validity_window(signature: &mut Vec<u8>, valid_after: u64, valid_until: u64) {
+        signature.extend_from_slice(&valid_after.to_be_bytes());
+        signature.extend_from_slice(&valid_until.to_be_bytes());
+    }
+
+    #[test]
+    fn test_validate_user_op_rejects_op_not_yet_valid() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let mut op = create_test_user_op();
+        append_validity_window(&mut op.signature, 1_000, u64::MAX);
+
+        assert_eq!(
+            entry_point.validate_user_op(&op, 500),
+            Err(AccountAbstractionError::NotYetValid { valid_after: 1_000, now: 500 })
+        );
+        assert!(entry_point.validate_user_op(&op, 1_000).is_ok());
+    }
+
+    #[test]
+    fn test_validate_user_op_rejects_expired_op() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let mut op = create_test_user_op();
+        append_validity_window(&mut op.signature, 0, 1_000);
+
+        assert_eq!(
+            entry_point.validate_user_op(&op, 1_001),
+            Err(AccountAbstractionError::Expired { valid_until: 1_000, now: 1_001 })
+        );
+        assert!(entry_point.validate_user_op(&op, 1_000).is_ok());
+    }
+
+    #[test]
+    fn test_session_key_signed_op_validates_within_scope() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let mut op = create_test_user_op();
+        let account = op.sender;
+
+        let session_keypair = KeyPair::from_secret(&[2u8; 32]).unwrap();
+        let session_signer = Address::from(session_keypair.address());
+        entry_point.register_session_key(account, SessionKey {
+            signer: session_signer,
+            valid_after: 0,
+            valid_until: u64::MAX,
+            allowed_selectors: vec![[0x12, 0x34, 0x56, 0x78]],
+            spending_cap: u128::MAX,
+        });
+
+        let ep_addr = entry_point.supported_entry_points[0];
+        sign_user_op(&mut op, &session_keypair, &ep_addr, TEST_CHAIN_ID);
+
+        let required_prefund = (op.required_gas() as u128).saturating_mul(op.max_fee_per_gas as u128);
+        let verified = entry_point.verify(op.into(), 0).unwrap();
+        assert_eq!(verified.sender(), account);
+        assert_eq!(entry_point.session_key_spend(&account, &session_signer), required_prefund);
+    }
+
+    #[test]
+    fn test_session_key_rejects_call_data_outside_allowed_selectors() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let mut op = create_test_user_op();
+        let account = op.sender;
+
+        let session_keypair = KeyPair::from_secret(&[2u8; 32]).unwrap();
+        let session_signer = Address::from(session_keypair.address());
+        entry_point.register_session_key(account, SessionKey {
+            signer: session_signer,
+            valid_after: 0,
+            valid_until: u64::MAX,
+            allowed_selectors: vec![[0xAA, 0xBB, 0xCC, 0xDD]],
+            spending_cap: u128::MAX,
+        });
+
+        let ep_addr = entry_point.supported_entry_points[0];
+        sign_user_op(&mut op, &session_keypair, &ep_addr, TEST_CHAIN_ID);
+
+        assert_eq!(
+            entry_point.validate_user_op(&op, 0),
+            Err(AccountAbstractionError::SelectorNotAllowed { selector: [0x12, 0x34, 0x56, 0x78] })
+        );
+    }
+
+    #[test]
+    fn test_session_key_rejects_spend_beyond_cap() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let mut op = create_test_user_op();
+        let account = op.sender;
+        let required_prefund = (op.required_gas() as u128).saturating_mul(op.max_fee_per_gas as u128);
+
+        let session_keypair = KeyPair::from_secret(&[2u8; 32]).unwrap();
+        let session_signer = Address::from(session_keypair.address());
+        entry_point.register_session_key(account, SessionKey {
+            signer: session_signer,
+            valid_after: 0,
+            valid_until: u64::MAX,
+            allowed_selectors: vec![[0x12, 0x34, 0x56, 0x78]],
+            spending_cap: required_prefund,
+        });
+
+        let ep_addr = entry_point.supported_entry_points[0];
+        sign_user_op(&mut op, &session_keypair, &ep_addr, TEST_CHAIN_ID);
+
+        // First op exactly exhausts the cap.
+        let verified = entry_point.verify(op.clone().into(), 0).unwrap();
+        entry_point.queue_user_op(verified, 0);
+        assert_eq!(entry_point.session_key_spend(&account, &session_signer), required_prefund);
+
+        // A second op signed by the same key now has nothing left to spend.
+        let mut op2 = op;
+        op2.nonce = 1;
+        sign_user_op(&mut op2, &session_keypair, &ep_addr, TEST_CHAIN_ID);
+        assert_eq!(
+            entry_point.validate_user_op(&op2, 0),
+            Err(AccountAbstractionError::SpendingCapExceeded {
+                cap: required_prefund,
+                attempted: required_prefund.saturating_add(required_prefund),
+            })
+        );
+    }
+
+    #[test]
+    fn test_session_key_rejects_outside_its_own_window() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let mut op = create_test_user_op();
+        let account = op.sender;
+
+        let session_keypair = KeyPair::from_secret(&[2u8; 32]).unwrap();
+        let session_signer = Address::from(session_keypair.address());
+        entry_point.register_session_key(account, SessionKey {
+            signer: session_signer,
+            valid_after: 0,
+            valid_until: 1_000,
+            allowed_selectors: vec![[0x12, 0x34, 0x56, 0x78]],
+            spending_cap: u128::MAX,
+        });
+
+        let ep_addr = entry_point.supported_entry_points[0];
+        sign_user_op(&mut op, &session_keypair, &ep_addr, TEST_CHAIN_ID);
+
+        // The op's own signature carries no window, but the session key's
+        // registered window still applies.
+        assert_eq!(
+            entry_point.validate_user_op(&op, 1_001),
+            Err(AccountAbstractionError::Expired { valid_until: 1_000, now: 1_001 })
+        );
+        assert!(entry_point.validate_user_op(&op, 1_000).is_ok());
+    }
+
+    #[test]
+    fn test_unregistered_signer_is_rejected_as_session_key() {
+        let entry_point = EntryPoint::new(TEST_CHAIN_ID);
+        let mut op = create_test_user_op();
+
+        let stranger = KeyPair::from_secret(&[3u8; 32]).unwrap();
+        let ep_addr = entry_point.supported_entry_points[0];
+        sign_user_op(&mut op, &stranger, &ep_addr, TEST_CHAIN_ID);
+
+        assert_eq!(
+            entry_point.validate_user_op(&op, 0),
+            Err(AccountAbstractionError::SessionKeyNotRegistered)
+        );
     }
 }
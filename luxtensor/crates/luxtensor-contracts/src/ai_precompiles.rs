@@ -37,6 +37,9 @@ pub mod gas_costs {
     /// Cost for COMPUTE_PAYMENT
     pub const COMPUTE_PAYMENT: u64 = 1_000;
 
+    /// Cost for BASE_FEE (comparable to the GET_RESULT read)
+    pub const BASE_FEE: u64 = 2_000;
+
     /// Base cost for TRAIN_REQUEST
     pub const TRAIN_REQUEST_BASE: u64 = 30_000;
     /// Per-byte cost for training config
@@ -480,6 +483,27 @@ pub fn compute_payment_precompile(
     ))
 }
 
+/// BASE_FEE precompile handler (0x0F)
+///
+/// Mirrors EIP-3198's BASEFEE opcode for contracts that can't rely on
+/// bytecode-level opcode support in this execution context.
+///
+/// Input format: none
+/// Output format: uint256 base_fee
+pub fn base_fee_precompile(base_fee: u128, gas_limit: u64) -> PrecompileResult {
+    if gas_costs::BASE_FEE > gas_limit {
+        return Err(PrecompileError::OutOfGas.into());
+    }
+
+    let mut output = [0u8; 32];
+    output[16..32].copy_from_slice(&base_fee.to_be_bytes());
+
+    Ok(PrecompileOutput::new(
+        gas_costs::BASE_FEE,
+        Bytes::copy_from_slice(&output),
+    ))
+}
+
 /// TRAIN_REQUEST precompile handler (0x14)
 ///
 /// Input format: abi.encode(model_id, dataset_ref, total_rounds, min_participants, reward_per_round)
@@ -1509,6 +1533,11 @@ pub fn is_ai_precompile(address: &[u8; 20]) -> bool {
     is_registry_precompile(address)
 }
 
+/// Check if address is the BASE_FEE precompile (0x0F)
+pub fn is_base_fee_precompile(address: &[u8; 20]) -> bool {
+    *address == precompiles::BASE_FEE
+}
+
 /// Check if address is a training precompile
 pub fn is_training_precompile(address: &[u8; 20]) -> bool {
     // TRAIN_REQUEST at 0x14
@@ -1623,4 +1652,29 @@ mod tests {
         assert!(!is_ai_precompile(&precompiles::ECRECOVER));
         assert!(!is_ai_precompile(&precompiles::SHA256));
     }
+
+    #[test]
+    fn test_base_fee_precompile_encodes_uint256() {
+        let result = base_fee_precompile(1_500_000_000, 100_000);
+
+        assert!(result.is_ok());
+        let output = result.unwrap();
+        assert_eq!(output.bytes.len(), 32);
+        let actual = u128::from_be_bytes(output.bytes[16..32].try_into().unwrap());
+        assert_eq!(actual, 1_500_000_000);
+        assert_eq!(output.gas_used, gas_costs::BASE_FEE);
+    }
+
+    #[test]
+    fn test_base_fee_precompile_out_of_gas() {
+        let result = base_fee_precompile(1_500_000_000, gas_costs::BASE_FEE - 1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_is_base_fee_precompile() {
+        assert!(is_base_fee_precompile(&precompiles::BASE_FEE));
+        assert!(!is_base_fee_precompile(&precompiles::AI_REQUEST));
+        assert!(!is_base_fee_precompile(&precompiles::ECRECOVER));
+    }
 }
@@ -0,0 +1,419 @@
+//! Bundler-side simulation on top of [`EntryPoint`].
+//!
+//! `EntryPoint::simulate_validation` checks an op's signature, nonce, and
+//! paymaster funding, but a real bundler also needs to enforce ERC-4337's
+//! validation-phase safety rules (no reliance on block-dependent opcodes,
+//! no poking at storage that isn't the sender's own) and needs to remember
+//! which senders keep failing simulation so it can stop wasting work on
+//! them. [`Bundler`] wraps an `EntryPoint` to add both.
+
+use crate::account_abstraction::{
+    AccountAbstractionError, EntryPoint, PaymasterContext, UserOperation,
+};
+use crate::revm_integration::StructLog;
+use luxtensor_core::types::Address;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use parking_lot::RwLock;
+
+/// Opcodes banned during the validation phase: anything whose result can
+/// differ between simulation and actual inclusion (so a malicious account
+/// can't pass simulation then misbehave on-chain), plus `SELFDESTRUCT` and
+/// `CREATE`/`CREATE2` outside the sender's own deployment. Mirrors the set
+/// ERC-4337 bundlers ban during `simulateValidation`.
+pub const FORBIDDEN_VALIDATION_OPCODES: &[u8] = &[
+    0x31, // BALANCE
+    0x32, // ORIGIN
+    0x3A, // GASPRICE
+    0x3B, // EXTCODESIZE
+    0x3C, // EXTCODECOPY
+    0x3F, // EXTCODEHASH
+    0x40, // BLOCKHASH
+    0x41, // COINBASE
+    0x42, // TIMESTAMP
+    0x43, // NUMBER
+    0x44, // DIFFICULTY / PREVRANDAO
+    0x45, // GASLIMIT
+    0x47, // SELFBALANCE
+    0x48, // BASEFEE
+    0x5A, // GAS
+    0xF0, // CREATE
+    0xF5, // CREATE2
+    0xFF, // SELFDESTRUCT
+];
+
+const SLOAD: u8 = 0x54;
+const SSTORE: u8 = 0x55;
+
+/// Why a bundler refused to include a user operation.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DropReason {
+    #[error("validation failed: {0}")]
+    ValidationFailed(AccountAbstractionError),
+    #[error("op touched forbidden opcode 0x{0:02x} during validation")]
+    ForbiddenOpcode(u8),
+    #[error("op accessed storage outside its own sender/staked scope")]
+    OutOfScopeStorageAccess,
+    #[error("verification gas limit exceeded during simulation")]
+    VerificationGasExceeded,
+    #[error("sender is banned for repeated failing simulations")]
+    SenderBanned,
+    #[error("paymaster is banned for repeated failing simulations")]
+    PaymasterBanned,
+}
+
+/// Outcome of simulating a user operation's validation phase without
+/// executing it, mirroring ERC-4337's `ValidationResult` return struct.
+#[derive(Debug, Clone)]
+pub struct ValidationResult {
+    /// Gas charged before the main call: verification + pre-verification.
+    pub pre_op_gas: u64,
+    /// Worst-case amount the sender (or paymaster) is on the hook for.
+    pub prefund: u128,
+    /// Signature didn't recover to `sender` — a soft failure a bundler may
+    /// retry later (e.g. once an aggregator registers), unlike a hard
+    /// validation error.
+    pub sig_failed: bool,
+    pub valid_after: u64,
+    pub valid_until: u64,
+    /// Present when a paymaster sponsored this op and passed its funding
+    /// check; `post_op` will need this once the real gas cost is known.
+    pub paymaster_context: Option<PaymasterContext>,
+}
+
+/// Sliding window of recent simulation outcomes for one entity (sender,
+/// paymaster, or aggregator address), used to ban repeat offenders the way
+/// [`EntryPoint`]'s own reputation table bans repeat *mempool* offenders —
+/// this tracks simulation-time failures specifically, which can happen
+/// before an op is ever accepted into the mempool.
+#[derive(Debug, Clone, Default)]
+struct SimulationHistory {
+    window: VecDeque<bool>,
+}
+
+/// Number of recent simulation outcomes kept per entity.
+const SIMULATION_WINDOW_SIZE: usize = 10;
+/// Failures within the window at or above which an entity is banned.
+const SIMULATION_BAN_THRESHOLD: usize = 3;
+
+impl SimulationHistory {
+    fn record(&mut self, failed: bool) {
+        if self.window.len() == SIMULATION_WINDOW_SIZE {
+            self.window.pop_front();
+        }
+        self.window.push_back(failed);
+    }
+
+    fn is_banned(&self) -> bool {
+        self.window.iter().filter(|&&failed| failed).count() >= SIMULATION_BAN_THRESHOLD
+    }
+}
+
+/// A bundler's view of an [`EntryPoint`]: simulates validation without
+/// executing the op, enforces the standard opcode/storage safety rules
+/// against a supplied execution trace, and tracks which senders/paymasters
+/// keep failing so they can be evicted from consideration.
+pub struct Bundler {
+    entry_point: Arc<EntryPoint>,
+    history: RwLock<HashMap<Address, SimulationHistory>>,
+}
+
+impl Bundler {
+    pub fn new(entry_point: Arc<EntryPoint>) -> Self {
+        Self { entry_point, history: RwLock::new(HashMap::new()) }
+    }
+
+    /// Simulate an op's validation phase without executing it. A failed
+    /// signature recovery is reported as `sig_failed` rather than an `Err`,
+    /// since a bundler may legitimately retry those (e.g. once an
+    /// aggregator registers); every other validation error is a hard `Err`.
+    pub fn simulate_validation(
+        &self,
+        user_op: &UserOperation,
+        base_fee_per_gas: u128,
+        current_timestamp: u64,
+    ) -> Result<ValidationResult, AccountAbstractionError> {
+        match self.entry_point.simulate_validation(user_op, base_fee_per_gas, current_timestamp) {
+            Ok(sim) => {
+                let paymaster_context = match user_op.paymaster() {
+                    Some(paymaster) => self.entry_point.validate_paymaster_user_op(Some(paymaster), sim.prefund)?,
+                    None => None,
+                };
+                Ok(ValidationResult {
+                    pre_op_gas: sim.pre_op_gas,
+                    prefund: sim.prefund,
+                    sig_failed: false,
+                    valid_after: sim.valid_after,
+                    valid_until: sim.valid_until,
+                    paymaster_context,
+                })
+            }
+            Err(AccountAbstractionError::InvalidSignature) => Ok(ValidationResult {
+                pre_op_gas: user_op.verification_gas_limit.saturating_add(user_op.pre_verification_gas),
+                prefund: (user_op.required_gas() as u128).saturating_mul(user_op.max_fee_per_gas as u128),
+                sig_failed: true,
+                valid_after: 0,
+                valid_until: 0,
+                paymaster_context: None,
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Enforce the validation-phase safety rules against `trace`: no
+    /// forbidden opcodes, and no storage access outside `sender`'s own
+    /// scope (or an entity in `staked_entities`, e.g. a staked factory or
+    /// paymaster, which ERC-4337 exempts). `trace`'s `StructLog`s don't
+    /// carry a per-step contract address, so the address active at each
+    /// step is reconstructed from `StructLogCall::target` markers on the
+    /// preceding CALL/CREATE entries — an approximation of full call-frame
+    /// tracking, good enough since validation-phase calls are shallow.
+    pub fn validate_trace(
+        &self,
+        trace: &[StructLog],
+        sender: &Address,
+        staked_entities: &[Address],
+    ) -> Result<(), DropReason> {
+        let mut call_stack: Vec<[u8; 20]> = vec![*sender.as_bytes()];
+
+        for log in trace {
+            if let Some(call) = &log.call {
+                call_stack.truncate(log.depth as usize + 1);
+                call_stack.push(call.target);
+                continue;
+            }
+            call_stack.truncate(log.depth as usize + 1);
+
+            if FORBIDDEN_VALIDATION_OPCODES.contains(&log.op) {
+                return Err(DropReason::ForbiddenOpcode(log.op));
+            }
+
+            if log.op == SLOAD || log.op == SSTORE {
+                let current = Address::from(*call_stack.last().unwrap_or(sender.as_bytes()));
+                if current != *sender && !staked_entities.contains(&current) {
+                    return Err(DropReason::OutOfScopeStorageAccess);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record a simulation outcome for `entity` (sender, paymaster, or
+    /// aggregator), so repeated failures eventually ban it via
+    /// `should_include`.
+    pub fn record_simulation_result(&self, entity: Address, failed: bool) {
+        self.history.write().entry(entity).or_default().record(failed);
+    }
+
+    /// Whether `entity` is currently banned from inclusion due to repeated
+    /// failing simulations.
+    pub fn is_banned(&self, entity: &Address) -> bool {
+        self.history.read().get(entity).map(|h| h.is_banned()).unwrap_or(false)
+    }
+
+    /// Decide whether a bundle builder should include `user_op`: combines
+    /// simulation, the opcode/storage safety rules against `trace`, and the
+    /// failing-simulation ban table, recording this attempt's outcome
+    /// along the way.
+    pub fn should_include(
+        &self,
+        user_op: &UserOperation,
+        base_fee_per_gas: u128,
+        current_timestamp: u64,
+        trace: &[StructLog],
+        staked_entities: &[Address],
+    ) -> Result<(), DropReason> {
+        let sender = user_op.sender;
+        let paymaster = user_op.paymaster();
+
+        if self.is_banned(&sender) {
+            return Err(DropReason::SenderBanned);
+        }
+        if let Some(p) = paymaster {
+            if self.is_banned(&p) {
+                return Err(DropReason::PaymasterBanned);
+            }
+        }
+
+        let result = match self.simulate_validation(user_op, base_fee_per_gas, current_timestamp) {
+            Ok(result) => result,
+            Err(e) => {
+                self.record_simulation_result(sender, true);
+                if let Some(p) = paymaster {
+                    self.record_simulation_result(p, true);
+                }
+                return Err(DropReason::ValidationFailed(e));
+            }
+        };
+
+        if result.sig_failed {
+            self.record_simulation_result(sender, true);
+            return Err(DropReason::ValidationFailed(AccountAbstractionError::InvalidSignature));
+        }
+
+        if result.pre_op_gas > user_op.verification_gas_limit {
+            self.record_simulation_result(sender, true);
+            return Err(DropReason::VerificationGasExceeded);
+        }
+
+        if let Err(reason) = self.validate_trace(trace, &sender, staked_entities) {
+            self.record_simulation_result(sender, true);
+            if let Some(p) = paymaster {
+                self.record_simulation_result(p, true);
+            }
+            return Err(reason);
+        }
+
+        self.record_simulation_result(sender, false);
+        if let Some(p) = paymaster {
+            self.record_simulation_result(p, false);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::revm_integration::StructLogCall;
+
+    const TEST_CHAIN_ID: u64 = 8898;
+
+    fn test_op(sender: Address) -> UserOperation {
+        UserOperation {
+            sender,
+            nonce: 0,
+            init_code: vec![],
+            call_data: vec![],
+            call_gas_limit: 100_000,
+            verification_gas_limit: 100_000,
+            pre_verification_gas: 21_000,
+            max_fee_per_gas: 1_000_000_000,
+            max_priority_fee_per_gas: 1_000_000,
+            paymaster_and_data: vec![],
+            signature: vec![0x00],
+        }
+    }
+
+    #[test]
+    fn simulate_validation_reports_sig_failed_without_hard_error() {
+        let entry_point = Arc::new(EntryPoint::new(TEST_CHAIN_ID));
+        let bundler = Bundler::new(entry_point);
+        let op = test_op(Address::from([1u8; 20]));
+
+        let result = bundler.simulate_validation(&op, 0, 0).unwrap();
+        assert!(result.sig_failed);
+    }
+
+    #[test]
+    fn validate_trace_rejects_forbidden_opcode() {
+        let entry_point = Arc::new(EntryPoint::new(TEST_CHAIN_ID));
+        let bundler = Bundler::new(entry_point);
+        let sender = Address::from([1u8; 20]);
+
+        let trace = vec![StructLog {
+            pc: 0,
+            op: 0x42, // TIMESTAMP
+            gas: 1000,
+            gas_cost: 2,
+            depth: 0,
+            stack: vec![],
+            call: None,
+        }];
+
+        assert_eq!(
+            bundler.validate_trace(&trace, &sender, &[]),
+            Err(DropReason::ForbiddenOpcode(0x42))
+        );
+    }
+
+    #[test]
+    fn validate_trace_rejects_out_of_scope_storage_access() {
+        let entry_point = Arc::new(EntryPoint::new(TEST_CHAIN_ID));
+        let bundler = Bundler::new(entry_point);
+        let sender = Address::from([1u8; 20]);
+        let other_contract = [9u8; 20];
+
+        let trace = vec![
+            StructLog {
+                pc: 0,
+                op: 0,
+                gas: 1000,
+                gas_cost: 0,
+                depth: 0,
+                stack: vec![],
+                call: Some(StructLogCall {
+                    target: other_contract,
+                    input: vec![],
+                    value: 0,
+                    is_luxtensor_precompile: false,
+                }),
+            },
+            StructLog {
+                pc: 0,
+                op: SLOAD,
+                gas: 900,
+                gas_cost: 100,
+                depth: 1,
+                stack: vec![],
+                call: None,
+            },
+        ];
+
+        assert_eq!(
+            bundler.validate_trace(&trace, &sender, &[]),
+            Err(DropReason::OutOfScopeStorageAccess)
+        );
+    }
+
+    #[test]
+    fn validate_trace_allows_storage_access_on_staked_entity() {
+        let entry_point = Arc::new(EntryPoint::new(TEST_CHAIN_ID));
+        let bundler = Bundler::new(entry_point);
+        let sender = Address::from([1u8; 20]);
+        let staked_factory = Address::from([9u8; 20]);
+
+        let trace = vec![
+            StructLog {
+                pc: 0,
+                op: 0,
+                gas: 1000,
+                gas_cost: 0,
+                depth: 0,
+                stack: vec![],
+                call: Some(StructLogCall {
+                    target: *staked_factory.as_bytes(),
+                    input: vec![],
+                    value: 0,
+                    is_luxtensor_precompile: false,
+                }),
+            },
+            StructLog {
+                pc: 0,
+                op: SSTORE,
+                gas: 900,
+                gas_cost: 100,
+                depth: 1,
+                stack: vec![],
+                call: None,
+            },
+        ];
+
+        assert!(bundler.validate_trace(&trace, &sender, &[staked_factory]).is_ok());
+    }
+
+    #[test]
+    fn should_include_bans_sender_after_repeated_failures() {
+        let entry_point = Arc::new(EntryPoint::new(TEST_CHAIN_ID));
+        let bundler = Bundler::new(entry_point);
+        let sender = Address::from([1u8; 20]);
+        let op = test_op(sender);
+
+        for _ in 0..SIMULATION_BAN_THRESHOLD {
+            assert!(bundler.should_include(&op, 0, 0, &[], &[]).is_err());
+        }
+
+        assert_eq!(bundler.should_include(&op, 0, 0, &[], &[]), Err(DropReason::SenderBanned));
+    }
+}
@@ -8,6 +8,8 @@ pub mod types;
 pub mod evm_executor;
 pub mod revm_integration;
 pub mod account_abstraction;
+pub mod bls_aggregation;
+pub mod bundler;
 pub mod ai_precompiles;
 pub mod agent_registry;
 pub mod agent_trigger;
@@ -25,15 +27,26 @@ pub use types::{
 pub use evm_executor::EvmExecutor;
 pub use evm_executor::{EvmLog, PersistentEvmExecutor, EvmAccountRecord, EvmStateStore};
 pub use account_abstraction::{
-    UserOperation, EntryPoint, UserOperationReceipt, PaymasterInfo,
-    SimulationResult, GasEstimate, AccountAbstractionError,
+    UserOperation, UserOperationV07, VersionedUserOperation, EntryPoint, UserOperationReceipt,
+    PaymasterInfo, SimulationResult, GasEstimate, AccountAbstractionError,
+    PreVerificationGasOracle, MainnetGasOracle, OptimismGasOracle, ArbitrumGasOracle,
+    UnverifiedUserOp, VerifiedUserOp, UserOpTxType, ReputationStatus, PaymasterContext,
+    TokenPaymasterData, TokenSettlement, PostOpMode, Eip155Domain, SessionKey,
 };
+pub use account_abstraction::recover_signer;
+pub use bls_aggregation::{Bn128Pairing, Bn128Error, IAggregator, BlsAggregator};
+pub use bundler::{Bundler, DropReason, ValidationResult, FORBIDDEN_VALIDATION_OPCODES};
 pub use ai_precompiles::{
     AIPrecompileState, AIRequestEntry, RequestStatus,
     TrainingJob, TrainingStatus, gas_costs,
 };
 pub use revm_integration::precompiles;
 pub use revm_integration::{execute_ai_precompile, is_luxtensor_precompile};
+pub use revm_integration::{LuxInspector, StructLog, StructLogCall};
+pub use revm_integration::{
+    reject_sender_with_code_result, sender_has_code, EMPTY_CODE_HASH,
+};
+pub use revm_integration::{modexp_gas, MODEXP_MAX_INPUT_LEN};
 pub use agent_registry::{AgentRegistry, AgentAccount, AgentTriggerConfig, AgentRegistryConfig, AgentRegistryError};
 pub use agent_trigger::{AgentTriggerEngine, TriggerResult, BlockTriggerOutcome};
 
@@ -34,6 +34,17 @@ pub struct EvmConfig {
     pub enable_eip1559: bool,
     /// Enable tracing/debugging
     pub enable_tracing: bool,
+    /// Target gas usage as a fraction of `block_gas_limit`
+    /// (`gas_target = gas_limit / elasticity_multiplier`). Default: 2,
+    /// matching Ethereum mainnet.
+    pub elasticity_multiplier: u64,
+    /// Divisor bounding how much the base fee can move block-to-block.
+    /// Default: 8, matching Ethereum mainnet (a max ~12.5% change per block).
+    pub base_fee_max_change_denominator: u128,
+    /// EIP-3607: reject transactions whose `from` account has deployed
+    /// bytecode, closing the gap where a contract address could be spoofed
+    /// as an EOA sender. Default `true`, matching mainnet EVM semantics.
+    pub reject_sender_with_code: bool,
 }
 
 impl Default for EvmConfig {
@@ -45,6 +56,46 @@ impl Default for EvmConfig {
             enable_precompiles: true,
             enable_eip1559: true,
             enable_tracing: false,
+            elasticity_multiplier: 2,
+            base_fee_max_change_denominator: 8,
+            reject_sender_with_code: true,
+        }
+    }
+}
+
+impl EvmConfig {
+    /// Rolls the base fee forward by one block per EIP-1559: unchanged if
+    /// the parent block used exactly the gas target, pushed up when it used
+    /// more (capped at a `1/base_fee_max_change_denominator` increase, with
+    /// a floor of `1` so a nonzero excess always moves the fee), and pulled
+    /// down when it used less (no such floor — the fee can fall by zero).
+    pub fn calculate_next_base_fee(
+        &self,
+        parent_base_fee: u128,
+        parent_gas_used: u64,
+        parent_gas_limit: u64,
+    ) -> u128 {
+        let gas_target = parent_gas_limit / self.elasticity_multiplier;
+        if gas_target == 0 {
+            return parent_base_fee;
+        }
+
+        let gas_target = gas_target as u128;
+        let parent_gas_used = parent_gas_used as u128;
+
+        if parent_gas_used == gas_target {
+            parent_base_fee
+        } else if parent_gas_used > gas_target {
+            let gas_used_delta = parent_gas_used - gas_target;
+            let delta = (parent_base_fee * gas_used_delta / gas_target
+                / self.base_fee_max_change_denominator)
+                .max(1);
+            parent_base_fee.saturating_add(delta)
+        } else {
+            let gas_used_delta = gas_target - parent_gas_used;
+            let delta = parent_base_fee * gas_used_delta / gas_target
+                / self.base_fee_max_change_denominator;
+            parent_base_fee.saturating_sub(delta)
         }
     }
 }
@@ -106,6 +157,66 @@ pub mod precompiles {
     /// Input: bytes32 model_hash, uint256 input_size
     /// Output: uint256 required_payment
     pub const COMPUTE_PAYMENT: [u8; 20] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x13];
+
+    /// BASE_FEE precompile address (0x0F)
+    /// Mirrors EIP-3198's BASEFEE opcode: reads the current block's EIP-1559
+    /// base fee so AI-payment contracts can price gas against the live fee
+    /// instead of hardcoding a fixed value.
+    /// Input: none
+    /// Output: uint256 base_fee
+    pub const BASE_FEE: [u8; 20] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x0F];
+}
+
+/// Max length (bytes) accepted for any single MODEXP (0x05) length field
+/// (base/exponent/modulus). A call declaring a larger length is rejected up
+/// front by `modexp_gas` rather than risking an overflow or an oversized
+/// allocation while parsing it.
+pub const MODEXP_MAX_INPUT_LEN: u64 = 1024;
+
+/// EIP-2565 gas cost for the MODEXP precompile (0x05), hardened against the
+/// panics naive `u64`/`usize` arithmetic risks on attacker-controlled
+/// base/exponent/modulus lengths: every intermediate step uses `u128` or
+/// saturating arithmetic, and any length field above
+/// [`MODEXP_MAX_INPUT_LEN`] short-circuits to `u64::MAX` so the caller can
+/// treat the call as out-of-gas instead of sizing a buffer that large.
+///
+/// `exp_head` is the first `min(exp_len, 32)` bytes of the exponent
+/// (big-endian), used — as in the reference implementation — to derive the
+/// exponent's bit length without materializing the full value.
+pub fn modexp_gas(base_len: u64, exp_len: u64, mod_len: u64, exp_head: &[u8]) -> u64 {
+    if base_len > MODEXP_MAX_INPUT_LEN
+        || exp_len > MODEXP_MAX_INPUT_LEN
+        || mod_len > MODEXP_MAX_INPUT_LEN
+    {
+        return u64::MAX;
+    }
+
+    let words = (base_len.max(mod_len) as u128 + 7) / 8;
+    let multiplication_complexity = words.saturating_mul(words);
+
+    let bit_length: u64 = match exp_head.iter().position(|&b| b != 0) {
+        Some(first_nonzero) => {
+            let leading_byte_bits = 8 - exp_head[first_nonzero].leading_zeros() as u64;
+            let trailing_bytes = (exp_head.len() - first_nonzero - 1) as u64;
+            trailing_bytes.saturating_mul(8).saturating_add(leading_byte_bits)
+        }
+        None => 0,
+    };
+
+    let iteration_count: u128 = if exp_len <= 32 {
+        bit_length.saturating_sub(1) as u128
+    } else {
+        let extra_bytes = exp_len.saturating_sub(32) as u128;
+        extra_bytes.saturating_mul(8) + bit_length.saturating_sub(1) as u128
+    }
+    .max(1);
+
+    let gas = multiplication_complexity
+        .saturating_mul(iteration_count)
+        .saturating_div(3)
+        .max(200);
+
+    u64::try_from(gas).unwrap_or(u64::MAX)
 }
 
 /// Parse REVM logs into LuxTensor Log format
@@ -222,6 +333,29 @@ pub mod gas_costs {
     pub const LOG_TOPIC_GAS: u64 = 375;
     /// LOG data byte cost
     pub const LOG_DATA_GAS: u64 = 8;
+    /// EIP-2930 intrinsic cost per unique address in an access list
+    pub const ACCESS_LIST_ADDRESS: u64 = 2_400;
+    /// EIP-2930 intrinsic cost per storage key in an access list
+    pub const ACCESS_LIST_STORAGE_KEY: u64 = 1_900;
+}
+
+/// An EIP-2930 access list: addresses a transaction pre-declares it will
+/// touch, each with the storage slots it will read/write, so the executor
+/// can treat them as already "warm" (see `gas_costs::SLOAD_WARM`/
+/// `CALL_WARM`) instead of paying the cold-access surcharge on first use.
+pub type AccessList = Vec<(ContractAddress, Vec<Hash>)>;
+
+/// Intrinsic EIP-2930 gas for declaring `access_list`: `ACCESS_LIST_ADDRESS`
+/// per unique address plus `ACCESS_LIST_STORAGE_KEY` per storage key,
+/// charged up front regardless of whether the transaction actually
+/// accesses every entry.
+fn estimate_access_list_gas(access_list: &AccessList) -> u64 {
+    let unique_addresses: std::collections::HashSet<ContractAddress> =
+        access_list.iter().map(|(addr, _)| *addr).collect();
+    let storage_keys: usize = access_list.iter().map(|(_, keys)| keys.len()).sum();
+
+    unique_addresses.len() as u64 * gas_costs::ACCESS_LIST_ADDRESS
+        + storage_keys as u64 * gas_costs::ACCESS_LIST_STORAGE_KEY
 }
 
 /// Estimate gas for transaction data
@@ -242,9 +376,11 @@ pub fn estimate_transaction_gas(
     data: &[u8],
     is_contract_creation: bool,
     _estimated_execution: u64,
+    access_list: &AccessList,
 ) -> u64 {
     let mut gas = gas_costs::TX_BASE;
     gas += estimate_calldata_gas(data);
+    gas += estimate_access_list_gas(access_list);
 
     if is_contract_creation {
         gas += gas_costs::TX_CREATE;
@@ -265,13 +401,13 @@ pub fn estimate_transaction_gas(
 use crate::ai_precompiles::{
     AIPrecompileState,
     ai_request_precompile, verify_proof_precompile, get_result_precompile,
-    compute_payment_precompile, train_request_precompile,
+    compute_payment_precompile, train_request_precompile, base_fee_precompile,
     vector_store_precompile, vector_query_precompile,
     classify_precompile, anomaly_score_precompile, similarity_gate_precompile,
     semantic_relate_precompile, cluster_assign_precompile,
     register_vector_precompile, global_search_precompile,
     is_ai_precompile, is_semantic_precompile, is_training_precompile,
-    is_ai_primitives_precompile, is_registry_precompile,
+    is_ai_primitives_precompile, is_registry_precompile, is_base_fee_precompile,
 };
 use revm::primitives::Bytes;
 
@@ -279,7 +415,7 @@ use revm::primitives::Bytes;
 pub fn is_luxtensor_precompile(address: &[u8; 20]) -> bool {
     is_ai_precompile(address) || is_training_precompile(address) ||
     is_semantic_precompile(address) || is_ai_primitives_precompile(address) ||
-    is_registry_precompile(address)
+    is_registry_precompile(address) || is_base_fee_precompile(address)
 }
 
 /// Route a call to the appropriate AI precompile handler.
@@ -293,6 +429,10 @@ pub fn is_luxtensor_precompile(address: &[u8; 20]) -> bool {
 /// * `state` — shared AI precompile state (vector stores, registries, etc.)
 /// * `caller` — 20-byte caller address
 /// * `block_number` — current block height (for registry TTL)
+/// * `base_fee` — current block's EIP-1559 base fee (for the BASE_FEE precompile)
+/// * `trace` — when tracing is enabled (see [`LuxInspector`]), the inspector
+///   to record a synthetic [`StructLog`] entry into, so custom precompiles
+///   that short-circuit EVM bytecode still show up in the trace
 pub fn execute_ai_precompile(
     address: &[u8; 20],
     input: &Bytes,
@@ -300,11 +440,22 @@ pub fn execute_ai_precompile(
     state: &AIPrecompileState,
     caller: [u8; 20],
     block_number: u64,
+    base_fee: u128,
+    trace: Option<&mut LuxInspector>,
 ) -> Option<revm::primitives::PrecompileResult> {
     let last_byte = address[19];
 
+    if is_luxtensor_precompile(address) {
+        if let Some(inspector) = trace {
+            inspector.record_precompile_call(0, *address, input, 0);
+        }
+    }
+
     // AI Core (0x10 - 0x13)
     match last_byte {
+        0x0F if is_base_fee_precompile(address) => {
+            Some(base_fee_precompile(base_fee, gas_limit))
+        }
         0x10 if is_ai_precompile(address) => {
             Some(ai_request_precompile(input, gas_limit, state, caller))
         }
@@ -355,6 +506,212 @@ pub fn execute_ai_precompile(
     }
 }
 
+/// Number of stack entries (top-down) recorded per [`StructLog`] step.
+/// Matching the common `debug_traceTransaction` convention of showing only
+/// the top of the stack keeps traces readable for deep stacks.
+pub const TRACE_STACK_DEPTH: usize = 10;
+
+/// One step of a [`LuxInspector`] trace: either an executed opcode, or a
+/// synthetic entry for a CALL/CREATE (including ones the AI precompile
+/// router short-circuited before reaching the EVM interpreter).
+#[derive(Debug, Clone)]
+pub struct StructLog {
+    /// Program counter at this step (0 for synthetic CALL/CREATE entries)
+    pub pc: u64,
+    /// Raw opcode byte (0 for synthetic CALL/CREATE entries)
+    pub op: u8,
+    /// Gas remaining before this step executed
+    pub gas: u64,
+    /// Gas consumed by this step
+    pub gas_cost: u64,
+    /// Call depth (0 = top-level transaction)
+    pub depth: u64,
+    /// Top `TRACE_STACK_DEPTH` stack entries, ordered top-first
+    pub stack: Vec<U256>,
+    /// Set when this step is a CALL/CREATE into another contract
+    pub call: Option<StructLogCall>,
+}
+
+/// Details of a CALL/CREATE observed by [`LuxInspector`].
+#[derive(Debug, Clone)]
+pub struct StructLogCall {
+    /// Target contract address
+    pub target: [u8; 20],
+    /// Calldata (or init code, for CREATE)
+    pub input: Vec<u8>,
+    /// Value transferred, in wei
+    pub value: u128,
+    /// Whether `target` is one of LuxTensor's custom precompiles
+    /// (see [`is_luxtensor_precompile`])
+    pub is_luxtensor_precompile: bool,
+}
+
+/// Opcode-level tracer used when [`EvmConfig::enable_tracing`] is set.
+///
+/// Implements revm's `Inspector` trait to record a [`StructLog`] per
+/// executed opcode — program counter, opcode, remaining gas, gas cost,
+/// call depth, and the top of the stack — plus a structured entry for
+/// every CALL/CREATE, so debugging tooling can reconstruct execution
+/// without re-running the EVM.
+#[derive(Debug, Clone, Default)]
+pub struct LuxInspector {
+    /// Collected trace, in execution order
+    pub logs: Vec<StructLog>,
+}
+
+impl LuxInspector {
+    /// Create an inspector with an empty trace
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a synthetic trace entry for a CALL/CREATE the AI precompile
+    /// router (`execute_ai_precompile`) short-circuited before it reached
+    /// the EVM interpreter, so custom precompiles remain visible in the
+    /// trace even though no opcodes actually ran for them.
+    pub fn record_precompile_call(
+        &mut self,
+        depth: u64,
+        target: [u8; 20],
+        input: &[u8],
+        value: u128,
+    ) {
+        self.logs.push(StructLog {
+            pc: 0,
+            op: 0,
+            gas: 0,
+            gas_cost: 0,
+            depth,
+            stack: Vec::new(),
+            call: Some(StructLogCall {
+                target,
+                input: input.to_vec(),
+                value,
+                is_luxtensor_precompile: true,
+            }),
+        });
+    }
+}
+
+impl<DB: revm::Database> revm::Inspector<DB> for LuxInspector {
+    fn step(&mut self, interp: &mut revm::interpreter::Interpreter, _context: &mut revm::EvmContext<DB>) {
+        let stack = interp.stack.data();
+        let top_n = stack.len().min(TRACE_STACK_DEPTH);
+        let stack_snapshot = stack[stack.len() - top_n..].iter().rev().copied().collect();
+
+        self.logs.push(StructLog {
+            pc: interp.program_counter() as u64,
+            op: interp.current_opcode(),
+            gas: interp.gas.remaining(),
+            gas_cost: 0, // filled in by step_end once the interpreter has charged it
+            depth: interp.contract.call_depth,
+            stack: stack_snapshot,
+            call: None,
+        });
+    }
+
+    fn step_end(&mut self, interp: &mut revm::interpreter::Interpreter, _context: &mut revm::EvmContext<DB>) {
+        if let Some(last) = self.logs.last_mut() {
+            last.gas_cost = last.gas.saturating_sub(interp.gas.remaining());
+        }
+    }
+
+    fn call(
+        &mut self,
+        _context: &mut revm::EvmContext<DB>,
+        inputs: &mut revm::interpreter::CallInputs,
+    ) -> Option<revm::interpreter::CallOutcome> {
+        let target: [u8; 20] = inputs.target_address.into();
+        self.logs.push(StructLog {
+            pc: 0,
+            op: 0,
+            gas: inputs.gas_limit,
+            gas_cost: 0,
+            depth: 0,
+            stack: Vec::new(),
+            call: Some(StructLogCall {
+                target,
+                input: inputs.input.to_vec(),
+                value: inputs.value.get().try_into().unwrap_or(u128::MAX),
+                is_luxtensor_precompile: is_luxtensor_precompile(&target),
+            }),
+        });
+        None
+    }
+
+    fn create(
+        &mut self,
+        _context: &mut revm::EvmContext<DB>,
+        inputs: &mut revm::interpreter::CreateInputs,
+    ) -> Option<revm::interpreter::CreateOutcome> {
+        self.logs.push(StructLog {
+            pc: 0,
+            op: 0,
+            gas: inputs.gas_limit,
+            gas_cost: 0,
+            depth: 0,
+            stack: Vec::new(),
+            call: Some(StructLogCall {
+                target: [0u8; 20], // not assigned until CREATE completes
+                input: inputs.init_code.to_vec(),
+                value: inputs.value.try_into().unwrap_or(u128::MAX),
+                is_luxtensor_precompile: false,
+            }),
+        });
+        None
+    }
+}
+
+/// EIP-2718 transaction envelope type, identified by `decode_tx_type` from
+/// the transaction's leading byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxType {
+    /// Untyped, RLP-encoded transaction (no EIP-2718 envelope).
+    Legacy = 0x00,
+    /// EIP-2930: legacy pricing plus an [`AccessList`].
+    AccessList = 0x01,
+    /// EIP-1559: `max_fee_per_gas`/`max_priority_fee_per_gas` pricing
+    /// against the block's base fee.
+    DynamicFee = 0x02,
+}
+
+/// Identifies `raw`'s transaction envelope from its leading byte: a byte
+/// `>= 0xc0` is the start of an RLP list, i.e. an untyped `Legacy`
+/// transaction; otherwise the byte is the EIP-2718 type identifier prefixed
+/// to the typed payload. An empty or unrecognized-type input is treated as
+/// `Legacy`, matching how a non-typed-transaction-aware client would see it.
+pub fn decode_tx_type(raw: &[u8]) -> TxType {
+    match raw.first() {
+        Some(&b) if b >= 0xc0 => TxType::Legacy,
+        Some(0x01) => TxType::AccessList,
+        Some(0x02) => TxType::DynamicFee,
+        _ => TxType::Legacy,
+    }
+}
+
+/// Effective gas price paid per unit of gas, selecting legacy flat pricing
+/// vs. EIP-1559 fee-capped pricing based on `tx_type`. `Legacy` and
+/// `AccessList` transactions still carry a single flat `gas_price`; only
+/// `DynamicFee` transactions bid `max_fee_per_gas`/`max_priority_fee_per_gas`
+/// against the block's `base_fee`, per EIP-1559's `effective_gas_price =
+/// base_fee + min(max_priority_fee_per_gas, max_fee_per_gas - base_fee)`.
+pub fn calculate_effective_gas_price(
+    tx_type: TxType,
+    gas_price: u128,
+    max_fee_per_gas: u128,
+    max_priority_fee_per_gas: u128,
+    base_fee: u128,
+) -> u128 {
+    match tx_type {
+        TxType::Legacy | TxType::AccessList => gas_price,
+        TxType::DynamicFee => {
+            let headroom = max_fee_per_gas.saturating_sub(base_fee);
+            let priority_fee = max_priority_fee_per_gas.min(headroom);
+            base_fee.saturating_add(priority_fee)
+        }
+    }
+}
+
 /// EVM execution result with detailed information
 #[derive(Debug, Clone)]
 pub struct DetailedExecutionResult {
@@ -374,6 +731,12 @@ pub struct DetailedExecutionResult {
     pub created_address: Option<ContractAddress>,
     /// State changes made
     pub state_changes: Vec<StateChange>,
+    /// Decoded EIP-2718 envelope type, for tracing/receipts that need to
+    /// distinguish legacy from typed transactions.
+    pub tx_type: TxType,
+    /// Opcode-level trace collected by [`LuxInspector`] when
+    /// [`EvmConfig::enable_tracing`] is set; empty otherwise.
+    pub struct_logs: Vec<StructLog>,
 }
 
 /// Represents a state change from execution
@@ -393,6 +756,38 @@ pub enum StateChangeType {
     SelfDestructed,
 }
 
+/// Code hash LuxTensor treats as "no deployed bytecode". Matches
+/// `Account::new()`'s default `code_hash` in `luxtensor-core` (not the
+/// canonical `keccak256("")`), since that is the value the rest of this
+/// codebase already uses to mean "this is an EOA".
+pub const EMPTY_CODE_HASH: Hash = [0u8; 32];
+
+/// EIP-3607: true if `code_hash` indicates the account has deployed
+/// bytecode, i.e. it is not a plain EOA. Senders that fail this check must
+/// be rejected — otherwise a contract address could be spoofed as a
+/// transaction originator.
+pub fn sender_has_code(code_hash: &Hash) -> bool {
+    *code_hash != EMPTY_CODE_HASH
+}
+
+/// Build the rejection result for an EIP-3607 violation: a transaction
+/// whose sender account has deployed bytecode. Callers gate this on
+/// [`EvmConfig::reject_sender_with_code`] and [`sender_has_code`].
+pub fn reject_sender_with_code_result(tx_type: TxType) -> DetailedExecutionResult {
+    DetailedExecutionResult {
+        success: false,
+        gas_used: 0,
+        gas_refunded: 0,
+        return_data: Vec::new(),
+        logs: Vec::new(),
+        revert_reason: Some("sender has deployed code (EIP-3607)".to_string()),
+        created_address: None,
+        state_changes: Vec::new(),
+        tx_type,
+        struct_logs: Vec::new(),
+    }
+}
+
 /// Decode revert reason from return data
 pub fn decode_revert_reason(data: &[u8]) -> Option<String> {
     // Check for standard Error(string) selector: 0x08c379a0
@@ -455,11 +850,78 @@ mod tests {
     #[test]
     fn test_transaction_gas_estimation() {
         let data = vec![0x60, 0x60, 0x60, 0x40];
-        let gas = estimate_transaction_gas(&data, false, 0);
+        let gas = estimate_transaction_gas(&data, false, 0, &AccessList::new());
         // Base (21000) + 4 non-zero bytes (4 * 16 = 64) = 21064
         assert_eq!(gas, 21_064);
     }
 
+    #[test]
+    fn test_transaction_gas_estimation_with_access_list() {
+        let data = vec![0x60, 0x60, 0x60, 0x40];
+        let access_list: AccessList = vec![
+            (ContractAddress::zero(), vec![Hash::default(), Hash::default()]),
+            (ContractAddress([1; 20]), vec![]),
+        ];
+        let gas = estimate_transaction_gas(&data, false, 0, &access_list);
+        // Base (21064, as above) + 2 addresses (2 * 2400 = 4800)
+        // + 2 storage keys (2 * 1900 = 3800)
+        assert_eq!(gas, 21_064 + 4_800 + 3_800);
+    }
+
+    #[test]
+    fn test_access_list_dedupes_repeated_addresses() {
+        let access_list: AccessList = vec![
+            (ContractAddress::zero(), vec![Hash::default()]),
+            (ContractAddress::zero(), vec![Hash::default()]),
+        ];
+        // Same address listed twice only charges once for the address,
+        // but each entry's storage keys still count separately.
+        assert_eq!(
+            estimate_access_list_gas(&access_list),
+            gas_costs::ACCESS_LIST_ADDRESS + 2 * gas_costs::ACCESS_LIST_STORAGE_KEY
+        );
+    }
+
+    #[test]
+    fn test_decode_tx_type_legacy_from_rlp_list() {
+        // 0xc0 is the smallest RLP list prefix.
+        assert_eq!(decode_tx_type(&[0xc0, 0x01, 0x02]), TxType::Legacy);
+        assert_eq!(decode_tx_type(&[0xf8, 0x6c]), TxType::Legacy);
+    }
+
+    #[test]
+    fn test_decode_tx_type_typed_envelopes() {
+        assert_eq!(decode_tx_type(&[0x01, 0xaa, 0xbb]), TxType::AccessList);
+        assert_eq!(decode_tx_type(&[0x02, 0xaa, 0xbb]), TxType::DynamicFee);
+    }
+
+    #[test]
+    fn test_decode_tx_type_empty_or_unknown_defaults_to_legacy() {
+        assert_eq!(decode_tx_type(&[]), TxType::Legacy);
+        assert_eq!(decode_tx_type(&[0x7f]), TxType::Legacy);
+    }
+
+    #[test]
+    fn test_effective_gas_price_legacy_ignores_fee_cap_fields() {
+        let price = calculate_effective_gas_price(TxType::Legacy, 50, 999, 999, 10);
+        assert_eq!(price, 50);
+    }
+
+    #[test]
+    fn test_effective_gas_price_dynamic_fee_caps_at_max_fee() {
+        // base_fee (10) + priority (30) would be 40, but max_fee_per_gas
+        // caps the effective price at 25.
+        let price = calculate_effective_gas_price(TxType::DynamicFee, 0, 25, 30, 10);
+        assert_eq!(price, 25);
+    }
+
+    #[test]
+    fn test_effective_gas_price_dynamic_fee_uses_priority_when_under_cap() {
+        // base_fee (10) + priority (2) = 12, well under max_fee_per_gas (50).
+        let price = calculate_effective_gas_price(TxType::DynamicFee, 0, 50, 2, 10);
+        assert_eq!(price, 12);
+    }
+
     #[test]
     fn test_decode_function_selector() {
         let input = vec![0xa9, 0x05, 0x9c, 0xbb, 0x00, 0x01, 0x02];
@@ -480,5 +942,170 @@ mod tests {
         assert_eq!(config.chain_id, 777);
         assert!(config.enable_precompiles);
         assert!(config.enable_eip1559);
+        assert_eq!(config.elasticity_multiplier, 2);
+        assert_eq!(config.base_fee_max_change_denominator, 8);
+    }
+
+    #[test]
+    fn test_base_fee_unchanged_at_gas_target() {
+        let config = EvmConfig::default();
+        // gas_target = 30_000_000 / 2 = 15_000_000
+        let next = config.calculate_next_base_fee(1_000_000_000, 15_000_000, 30_000_000);
+        assert_eq!(next, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_base_fee_rises_when_block_is_full() {
+        let config = EvmConfig::default();
+        // Full block (gas_used == gas_limit) is 2x the target.
+        let next = config.calculate_next_base_fee(1_000_000_000, 30_000_000, 30_000_000);
+        // delta = 1_000_000_000 * 15_000_000 / 15_000_000 / 8 = 125_000_000
+        assert_eq!(next, 1_125_000_000);
+    }
+
+    #[test]
+    fn test_base_fee_falls_when_block_is_empty() {
+        let config = EvmConfig::default();
+        let next = config.calculate_next_base_fee(1_000_000_000, 0, 30_000_000);
+        // delta = 1_000_000_000 * 15_000_000 / 15_000_000 / 8 = 125_000_000
+        assert_eq!(next, 875_000_000);
+    }
+
+    #[test]
+    fn test_base_fee_increase_floors_at_one_for_tiny_excess() {
+        let config = EvmConfig::default();
+        // gas_target = 1_000_000; a 1-gas excess over it rounds down to a
+        // delta of 0 before the `.max(1)` floor forces it back to 1.
+        let next = config.calculate_next_base_fee(1, 1_000_001, 2_000_000);
+        assert_eq!(next, 2);
+    }
+
+    #[test]
+    fn test_base_fee_decrease_has_no_floor() {
+        let config = EvmConfig::default();
+        // Same 1-gas shortfall, but on the decrease side there is no floor,
+        // so a delta that rounds down to 0 leaves the base fee unchanged.
+        let next = config.calculate_next_base_fee(1, 999_999, 2_000_000);
+        assert_eq!(next, 1);
+    }
+
+    #[test]
+    fn test_modexp_gas_rejects_oversized_lengths() {
+        assert_eq!(modexp_gas(MODEXP_MAX_INPUT_LEN + 1, 32, 32, &[1]), u64::MAX);
+        assert_eq!(modexp_gas(32, MODEXP_MAX_INPUT_LEN + 1, 32, &[1]), u64::MAX);
+        assert_eq!(modexp_gas(32, 32, MODEXP_MAX_INPUT_LEN + 1, &[1]), u64::MAX);
+    }
+
+    #[test]
+    fn test_modexp_gas_has_floor_of_200() {
+        // Tiny lengths and a zero exponent still cost at least 200 gas.
+        let gas = modexp_gas(1, 1, 1, &[0]);
+        assert_eq!(gas, 200);
+    }
+
+    #[test]
+    fn test_modexp_gas_scales_with_exponent_bit_length() {
+        // Large enough base/modulus that the gas floor of 200 doesn't mask
+        // the difference a higher exponent bit length makes.
+        let small_exponent = modexp_gas(512, 32, 512, &[0x01]);
+        let large_exponent = modexp_gas(512, 32, 512, &[0xFF]);
+        assert!(large_exponent > small_exponent);
+    }
+
+    #[test]
+    fn test_modexp_gas_accounts_for_exponent_length_over_32_bytes() {
+        let short_exp = modexp_gas(32, 32, 32, &[0x01]);
+        let long_exp = modexp_gas(32, 64, 32, &[0x01]);
+        assert!(long_exp > short_exp);
+    }
+
+    #[test]
+    fn test_modexp_gas_never_panics_on_empty_exp_head() {
+        assert_eq!(modexp_gas(32, 0, 32, &[]), 200);
+    }
+
+    #[test]
+    fn test_evm_config_rejects_sender_with_code_by_default() {
+        assert!(EvmConfig::default().reject_sender_with_code);
+    }
+
+    #[test]
+    fn test_sender_has_code() {
+        assert!(!sender_has_code(&EMPTY_CODE_HASH));
+        assert!(!sender_has_code(&[0u8; 32]));
+        assert!(sender_has_code(&[0xAAu8; 32]));
+    }
+
+    #[test]
+    fn test_reject_sender_with_code_result() {
+        let result = reject_sender_with_code_result(TxType::Legacy);
+        assert!(!result.success);
+        assert_eq!(
+            result.revert_reason,
+            Some("sender has deployed code (EIP-3607)".to_string())
+        );
+        assert_eq!(result.gas_used, 0);
+    }
+
+    #[test]
+    fn test_lux_inspector_starts_empty() {
+        let inspector = LuxInspector::new();
+        assert!(inspector.logs.is_empty());
+    }
+
+    #[test]
+    fn test_record_precompile_call_marks_luxtensor_precompile() {
+        let mut inspector = LuxInspector::new();
+        inspector.record_precompile_call(1, precompiles::COMPUTE_PAYMENT, &[0xAB, 0xCD], 0);
+
+        assert_eq!(inspector.logs.len(), 1);
+        let call = inspector.logs[0].call.as_ref().unwrap();
+        assert_eq!(call.target, precompiles::COMPUTE_PAYMENT);
+        assert_eq!(call.input, vec![0xAB, 0xCD]);
+        assert!(call.is_luxtensor_precompile);
+        assert_eq!(inspector.logs[0].depth, 1);
+    }
+
+    #[test]
+    fn test_execute_ai_precompile_emits_trace_entry_when_inspector_present() {
+        let state = crate::ai_precompiles::AIPrecompileState::new();
+        let mut inspector = LuxInspector::new();
+        let mut input = vec![0u8; 64];
+        input[56..64].copy_from_slice(&1000u64.to_be_bytes());
+
+        let result = execute_ai_precompile(
+            &precompiles::COMPUTE_PAYMENT,
+            &Bytes::from(input),
+            10_000,
+            &state,
+            [1u8; 20],
+            0,
+            1_000_000_000,
+            Some(&mut inspector),
+        );
+
+        assert!(result.is_some());
+        assert_eq!(inspector.logs.len(), 1);
+        assert!(inspector.logs[0].call.as_ref().unwrap().is_luxtensor_precompile);
+    }
+
+    #[test]
+    fn test_execute_ai_precompile_no_trace_entry_without_inspector() {
+        let state = crate::ai_precompiles::AIPrecompileState::new();
+        let mut input = vec![0u8; 64];
+        input[56..64].copy_from_slice(&1000u64.to_be_bytes());
+
+        let result = execute_ai_precompile(
+            &precompiles::COMPUTE_PAYMENT,
+            &Bytes::from(input),
+            10_000,
+            &state,
+            [1u8; 20],
+            0,
+            1_000_000_000,
+            None,
+        );
+
+        assert!(result.is_some());
     }
 }
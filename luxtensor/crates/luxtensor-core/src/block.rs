@@ -291,6 +291,58 @@ impl Block {
     }
 }
 
+/// A [`Block`] paired with its already-computed header hash and per-transaction
+/// hashes.
+///
+/// Sync, validation, and DB-write stages each independently call
+/// `block.hash()` and `tx.hash()` on the same data; wrapping a block once it's
+/// been hashed lets those stages pass the hashes along instead of recomputing
+/// them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedBlock {
+    pub header: BlockHeader,
+    pub header_hash: Hash,
+    pub transactions: Vec<Transaction>,
+    pub tx_hashes: Vec<Hash>,
+}
+
+impl IndexedBlock {
+    /// Build an `IndexedBlock` from a header and its transactions, each
+    /// already paired with its hash.
+    pub fn new(header: BlockHeader, transactions: Vec<(Hash, Transaction)>) -> Self {
+        let header_hash = header.hash();
+        let (tx_hashes, transactions) = transactions.into_iter().unzip();
+        Self {
+            header,
+            header_hash,
+            transactions,
+            tx_hashes,
+        }
+    }
+
+    pub fn height(&self) -> u64 {
+        self.header.height
+    }
+
+    /// Recompute and return the block itself, discarding the cached hashes.
+    pub fn into_block(self) -> Block {
+        Block::new(self.header, self.transactions)
+    }
+}
+
+impl From<Block> for IndexedBlock {
+    fn from(block: Block) -> Self {
+        let header_hash = block.header.hash();
+        let tx_hashes = block.transactions.iter().map(|tx| tx.hash()).collect();
+        Self {
+            header: block.header,
+            header_hash,
+            transactions: block.transactions,
+            tx_hashes,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,6 +354,25 @@ mod tests {
         assert_eq!(genesis.transactions.len(), 0);
     }
 
+    #[test]
+    fn test_indexed_block_from_matches_direct_hashing() {
+        let block = Block::genesis();
+        let indexed = IndexedBlock::from(block.clone());
+        assert_eq!(indexed.header_hash, block.hash());
+        assert_eq!(indexed.tx_hashes.len(), block.transactions.len());
+        assert_eq!(indexed.into_block().hash(), block.hash());
+    }
+
+    #[test]
+    fn test_indexed_block_new_pairs_hashes_with_transactions() {
+        let header = Block::genesis().header;
+        let indexed = IndexedBlock::new(header.clone(), vec![]);
+        assert_eq!(indexed.header_hash, header.hash());
+        assert_eq!(indexed.height(), 0);
+        assert!(indexed.transactions.is_empty());
+        assert!(indexed.tx_hashes.is_empty());
+    }
+
     #[test]
     fn test_block_hash() {
         let genesis = Block::genesis();
@@ -25,6 +25,65 @@ pub struct HnswConfig {
     pub ml: f64,
     /// Maximum layer height (caps random_layer output)
     pub max_layer: usize,
+    /// If the diversity heuristic in `select_neighbors` discards candidates
+    /// and still has fewer than `m`/`m0` results, backfill from the
+    /// discarded pile (nearest-first) instead of leaving the node
+    /// under-connected. Mirrors the HNSW paper's `keepPrunedConnections`
+    /// flag. Defaults to `true`: a sparser-but-diverse neighbor set is
+    /// only useful if it doesn't leave nodes poorly connected.
+    pub extend_candidates: bool,
+    /// Distance metric used for both graph construction and search.
+    /// Fixed for the lifetime of an index — changing it after vectors have
+    /// been inserted would invalidate the existing graph's edges, so it is
+    /// persisted through `to_bytes`/`from_bytes` rather than re-derived.
+    pub metric: HnswDistance,
+    /// Opt-in int8 scalar quantization. When set, `insert` stores each
+    /// node's per-component min/max alongside u8 codes and replaces the
+    /// stored vector with its dequantized approximation, so graph
+    /// construction and search pay a small, bounded recall cost in
+    /// exchange for a ~4x smaller serialized form (see `to_bytes`). Set via
+    /// `HnswIndex::new_quantized` rather than toggled on an existing index,
+    /// for the same reason `metric` is fixed at construction.
+    pub quantize: bool,
+}
+
+/// Distance metric dispatched by `HnswIndex::distance`.
+///
+/// `L2` (squared Euclidean) is the default and matches the index's original
+/// behavior. The others serve embedding workloads where raw magnitude either
+/// doesn't matter (`Cosine`, `InnerProduct`) or where an L1-style metric is
+/// more robust to outlier dimensions (`Manhattan`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HnswDistance {
+    /// Sum of squared component differences. Smaller = more similar.
+    L2,
+    /// `1 - cosine_similarity`, bounded to `[0, 2]`. Ignores vector magnitude.
+    Cosine,
+    /// Negated dot product, so smaller (more negative) = more similar,
+    /// consistent with every other metric's "smaller is closer" convention.
+    InnerProduct,
+    /// Sum of absolute component differences (L1 / taxicab distance).
+    Manhattan,
+}
+
+impl HnswDistance {
+    fn to_byte(self) -> u8 {
+        match self {
+            HnswDistance::L2 => 0,
+            HnswDistance::Cosine => 1,
+            HnswDistance::InnerProduct => 2,
+            HnswDistance::Manhattan => 3,
+        }
+    }
+
+    fn from_byte(b: u8) -> Self {
+        match b {
+            1 => HnswDistance::Cosine,
+            2 => HnswDistance::InnerProduct,
+            3 => HnswDistance::Manhattan,
+            _ => HnswDistance::L2,
+        }
+    }
 }
 
 /// Default HNSW connections per layer (M parameter)
@@ -44,6 +103,9 @@ impl Default for HnswConfig {
             dimension: 768,      // Standard embedding dimension
             ml: 1.0 / (DEFAULT_M as f64).ln(),
             max_layer: DEFAULT_MAX_LAYER,
+            extend_candidates: true,
+            metric: HnswDistance::L2,
+            quantize: false,
         }
     }
 }
@@ -52,9 +114,74 @@ impl Default for HnswConfig {
 #[derive(Clone, Debug)]
 pub struct HnswNode {
     pub id: u64,
+    /// For a `config.quantize` index, this is the *dequantized
+    /// approximation* of the inserted vector (see `quantization` below),
+    /// not the raw input — graph construction and search operate on it
+    /// directly so no other code path needs to know quantization happened.
     pub vector: Vec<f32>,
     pub connections: Vec<Vec<u64>>,
     pub max_layer: usize,
+    /// Precomputed L2 norm of `vector`, cached at insert time so cosine
+    /// distance doesn't re-derive it from scratch on every query.
+    pub norm: f32,
+    /// Per-component min/max and u8 codes used to reconstruct `vector`,
+    /// present only when the owning index has `config.quantize` set. Kept
+    /// so `to_bytes` can serialize one u8 per component instead of one
+    /// f32, a ~4x reduction in the node's on-disk footprint.
+    pub quantization: Option<ScalarQuantization>,
+}
+
+/// Per-node int8 scalar quantization state: `dequantize(code) = min +
+/// code as f32 / 255.0 * (max - min)`.
+#[derive(Clone, Debug)]
+pub struct ScalarQuantization {
+    pub codes: Vec<u8>,
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Quantize `vector` to u8 codes over its own min/max range, returning the
+/// codes alongside the range needed to dequantize them.
+fn quantize_vector(vector: &[f32]) -> ScalarQuantization {
+    let min = vector.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = vector.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+
+    let codes = vector
+        .iter()
+        .map(|&x| if range > 0.0 { (((x - min) / range) * 255.0).round() as u8 } else { 0u8 })
+        .collect();
+
+    ScalarQuantization { codes, min, max }
+}
+
+/// Reconstruct the approximate f32 vector `quantize_vector` encoded.
+fn dequantize_vector(q: &ScalarQuantization) -> Vec<f32> {
+    let range = q.max - q.min;
+    q.codes.iter().map(|&c| q.min + (c as f32 / 255.0) * range).collect()
+}
+
+/// Metric dispatch shared by `HnswIndex::distance_core` and
+/// `ArchivedHnswIndex`'s read-only traversal, so the two never drift apart
+/// on how a distance is actually computed.
+fn distance_with_metric(metric: HnswDistance, a: &[f32], norm_a: f32, b: &[f32], norm_b: f32) -> f32 {
+    match metric {
+        HnswDistance::L2 => a.iter().zip(b.iter()).map(|(x, y)| (x - y).powi(2)).sum(),
+        HnswDistance::Manhattan => a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()).sum(),
+        HnswDistance::InnerProduct => {
+            let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+            -dot
+        }
+        HnswDistance::Cosine => {
+            let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+            let denom = norm_a * norm_b;
+            if denom <= f32::EPSILON {
+                1.0
+            } else {
+                1.0 - (dot / denom).clamp(-1.0, 1.0)
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -117,6 +244,11 @@ pub struct HnswIndex {
     entry_point: Option<u64>,
     max_layer: usize,
     count: usize,
+    /// Ids marked by `soft_delete`: still present in `nodes` and still
+    /// usable as routing hops, but excluded from `search`/`live_count`.
+    /// Distinct from `remove`, which drops a node from the graph entirely
+    /// — see `soft_delete`'s doc comment for when to use which.
+    deleted: HashSet<u64>,
 }
 
 impl HnswIndex {
@@ -126,6 +258,32 @@ impl HnswIndex {
         Self::with_config(config)
     }
 
+    /// Create an index using a specific distance metric instead of the
+    /// default `HnswDistance::L2`. For `Cosine`, distance is computed from
+    /// each node's cached L2 norm rather than by normalizing the stored
+    /// vector in place (see `HnswNode::norm`), so `get_vector` always
+    /// returns what was inserted.
+    pub fn new_with_metric(dimension: usize, metric: HnswDistance) -> Self {
+        let mut config = HnswConfig::default();
+        config.dimension = dimension;
+        config.metric = metric;
+        Self::with_config(config)
+    }
+
+    /// Create an index with int8 scalar quantization enabled. Each
+    /// inserted vector is quantized to a per-node `[u8; dimension]` code
+    /// book (global min/max, not per-dimension) and the dequantized
+    /// approximation is what's actually stored and searched — this keeps
+    /// `distance_core`/`distance_between` untouched at the cost of not
+    /// shrinking the in-memory `Vec<f32>`, while `to_bytes` still only
+    /// serializes the compact `u8` codes plus the two `f32` bounds.
+    pub fn new_quantized(dimension: usize) -> Self {
+        let mut config = HnswConfig::default();
+        config.dimension = dimension;
+        config.quantize = true;
+        Self::with_config(config)
+    }
+
     pub fn with_config(config: HnswConfig) -> Self {
         Self {
             config,
@@ -133,6 +291,7 @@ impl HnswIndex {
             entry_point: None,
             max_layer: 0,
             count: 0,
+            deleted: HashSet::new(),
         }
     }
 
@@ -163,11 +322,20 @@ impl HnswIndex {
 
         let node_layer = self.random_layer(id);
 
+        let (stored_vector, quantization) = if self.config.quantize {
+            let q = quantize_vector(&vector);
+            (dequantize_vector(&q), Some(q))
+        } else {
+            (vector.clone(), None)
+        };
+
         let mut new_node = HnswNode {
             id,
-            vector: vector.clone(),
+            norm: Self::vector_norm(&stored_vector),
+            vector: stored_vector,
             connections: vec![Vec::new(); node_layer + 1],
             max_layer: node_layer,
+            quantization,
         };
 
         if self.entry_point.is_none() {
@@ -178,6 +346,11 @@ impl HnswIndex {
             return Ok(());
         }
 
+        // From here on, use the stored (possibly dequantized) vector as the
+        // search query — graph construction must be consistent with what
+        // every other node's distance is actually computed against.
+        let vector = new_node.vector.clone();
+
         let entry_id = self.entry_point.unwrap();
         let mut current_id = entry_id;
 
@@ -239,13 +412,15 @@ impl HnswIndex {
                 let candidates: Vec<(u64, f32)> = conn_ids
                     .iter()
                     .filter_map(|&cid| {
-                        self.nodes.get(&cid).map(|n| {
-                            (cid, self.distance(&neighbor_vec, &n.vector))
-                        })
+                        if self.nodes.contains_key(&cid) {
+                            Some((cid, self.distance_between(neighbor_id, cid)))
+                        } else {
+                            None
+                        }
                     })
                     .collect();
 
-                let pruned = self.select_neighbors_simple(&candidates, max_m);
+                let pruned = self.select_neighbors_simple(&neighbor_vec, &candidates, max_m);
 
                 if let Some(neighbor) = self.nodes.get_mut(&neighbor_id) {
                     if layer < neighbor.connections.len() {
@@ -266,6 +441,361 @@ impl HnswIndex {
         Ok(())
     }
 
+    /// Remove a vector from the index.
+    ///
+    /// Unlike the soft-delete tombstone model used elsewhere in this
+    /// workspace (`luxtensor-hnsw::HnswGraph`), this is a hard removal: the
+    /// node is dropped entirely and every neighbor that referenced it is
+    /// repaired so the graph stays navigable. A repaired neighbor whose
+    /// remaining connection count falls below its layer's target re-runs the
+    /// diversity heuristic over its surviving connections plus their
+    /// second-degree neighbors (candidates two hops away), mirroring how a
+    /// fresh `insert` selects neighbors.
+    ///
+    /// # Errors
+    /// Returns `NodeNotFound` if `id` isn't in the index.
+    pub fn remove(&mut self, id: u64) -> Result<(), HnswError> {
+        let node = self.nodes.remove(&id).ok_or(HnswError::NodeNotFound(id))?;
+
+        for (layer, neighbor_ids) in node.connections.iter().enumerate() {
+            for &neighbor_id in neighbor_ids {
+                if neighbor_id == id {
+                    continue;
+                }
+                self.repair_orphaned_neighbor(neighbor_id, id, layer);
+            }
+        }
+
+        self.count = self.count.saturating_sub(1);
+
+        if self.entry_point == Some(id) {
+            self.entry_point = self.find_new_entry_point();
+            self.max_layer = self
+                .entry_point
+                .and_then(|ep| self.nodes.get(&ep))
+                .map(|n| n.max_layer)
+                .unwrap_or(0);
+        }
+
+        Ok(())
+    }
+
+    /// Replace the vector stored for `id`.
+    ///
+    /// `random_layer` is a pure function of `id`, so in practice the layer
+    /// never actually changes across an update — but the check is kept so
+    /// this stays correct if that ever stops being true. When the layer is
+    /// unchanged (the common case), the vector is replaced in place and the
+    /// node's neighbor lists are repaired via `reconnect_node`, since the old
+    /// connections were chosen for the old vector and may no longer be the
+    /// best choices. When the layer would change, a full `remove` + `insert`
+    /// is simpler and safer than growing/shrinking the connections vector.
+    ///
+    /// # Errors
+    /// Returns `DimensionMismatch` if `new_vector`'s length doesn't match the
+    /// index's configured dimension, or `NodeNotFound` if `id` isn't present.
+    pub fn update(&mut self, id: u64, new_vector: Vec<f32>) -> Result<(), HnswError> {
+        if new_vector.len() != self.config.dimension {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.config.dimension,
+                got: new_vector.len(),
+            });
+        }
+
+        let existing_layer = self
+            .nodes
+            .get(&id)
+            .map(|n| n.max_layer)
+            .ok_or(HnswError::NodeNotFound(id))?;
+
+        let new_layer = self.random_layer(id);
+
+        if new_layer != existing_layer {
+            self.remove(id)?;
+            return self.insert(id, new_vector);
+        }
+
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.vector = new_vector;
+            node.norm = Self::vector_norm(&node.vector);
+        }
+
+        self.reconnect_node(id);
+
+        Ok(())
+    }
+
+    /// Tombstone `id` instead of hard-removing it: the node stays in the
+    /// graph and keeps serving as a routing hop for other nodes'
+    /// searches, but `search`/`get_vector` (via `HnswVectorStore`) and
+    /// `live_count` all treat it as gone. Cheap — unlike `remove`, no
+    /// neighbor repair is needed, since the node's edges are untouched.
+    ///
+    /// Prefer this over `remove` when deletions are frequent relative to
+    /// inserts and repair cost matters; call `compact` periodically to
+    /// actually reclaim the tombstoned nodes once they pile up.
+    ///
+    /// # Errors
+    /// Returns `NodeNotFound` if `id` isn't in the index, or is already
+    /// tombstoned.
+    pub fn soft_delete(&mut self, id: u64) -> Result<(), HnswError> {
+        if !self.nodes.contains_key(&id) || self.deleted.contains(&id) {
+            return Err(HnswError::NodeNotFound(id));
+        }
+        self.deleted.insert(id);
+        Ok(())
+    }
+
+    /// Whether `id` has been `soft_delete`d. Ids that were hard-`remove`d
+    /// (or never inserted) also report `false` here — this only tracks
+    /// tombstones, not graph membership.
+    pub fn is_deleted(&self, id: u64) -> bool {
+        self.deleted.contains(&id)
+    }
+
+    /// Node count excluding tombstoned ids. Unlike `len()` (which counts
+    /// every node still in the graph, tombstoned or not, so routing-hop
+    /// bookkeeping stays accurate), this is what a caller should compare
+    /// against capacity or report as "how many vectors are actually
+    /// live".
+    pub fn live_count(&self) -> usize {
+        self.count.saturating_sub(self.deleted.len())
+    }
+
+    /// Fraction of nodes that are tombstoned, in `[0.0, 1.0]`.
+    pub fn deleted_ratio(&self) -> f32 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.deleted.len() as f32 / self.count as f32
+        }
+    }
+
+    /// Rebuild the graph from surviving (non-tombstoned) vectors if the
+    /// tombstoned fraction exceeds `max_deleted_ratio`, reclaiming their
+    /// memory and the routing overhead of dead-end hops. Returns whether a
+    /// rebuild actually happened.
+    ///
+    /// Rebuilding re-inserts every live vector in ascending id order into a
+    /// fresh index with the same config, which is simpler and safer than
+    /// trying to patch the existing graph's connections in place — the
+    /// usual reasoning for this tradeoff applies here too (see `update`'s
+    /// remove-then-insert fallback above).
+    pub fn compact(&mut self, max_deleted_ratio: f32) -> bool {
+        if self.deleted_ratio() <= max_deleted_ratio {
+            return false;
+        }
+
+        let mut survivors: Vec<(u64, Vec<f32>)> = self
+            .nodes
+            .iter()
+            .filter(|(id, _)| !self.deleted.contains(id))
+            .map(|(&id, node)| (id, node.vector.clone()))
+            .collect();
+        survivors.sort_by_key(|(id, _)| *id);
+
+        let mut rebuilt = Self::with_config(self.config.clone());
+        for (id, vector) in survivors {
+            // Survivors were already valid under this config's dimension,
+            // and ids are unique by construction — neither error path is
+            // reachable here.
+            rebuilt.insert(id, vector).expect("surviving vector must re-insert cleanly");
+        }
+
+        *self = rebuilt;
+        true
+    }
+
+    /// Find the best surviving entry point (highest layer, lowest ID for
+    /// determinism) after the current one is removed.
+    fn find_new_entry_point(&self) -> Option<u64> {
+        self.nodes
+            .values()
+            .max_by_key(|n| (n.max_layer, std::cmp::Reverse(n.id)))
+            .map(|n| n.id)
+    }
+
+    /// After `removed_id` is dropped, strip it from `neighbor_id`'s
+    /// connection list at `layer`. If that leaves the neighbor under its
+    /// layer's target connection count, repair by re-running the diversity
+    /// heuristic over its surviving connections plus their second-degree
+    /// neighbors, adding reciprocal edges for any newly pulled-in candidate
+    /// so the repaired graph stays bidirectional like a fresh insert.
+    fn repair_orphaned_neighbor(&mut self, neighbor_id: u64, removed_id: u64, layer: usize) {
+        let (neighbor_vec, mut remaining) = match self.nodes.get(&neighbor_id) {
+            Some(n) if layer < n.connections.len() => {
+                let mut conns = n.connections[layer].clone();
+                conns.retain(|&c| c != removed_id);
+                (n.vector.clone(), conns)
+            }
+            _ => return,
+        };
+
+        let max_conn = if layer == 0 { self.config.m0 } else { self.config.m };
+
+        if remaining.len() < max_conn {
+            let mut candidate_ids: HashSet<u64> = HashSet::new();
+            for &rid in &remaining {
+                if let Some(rn) = self.nodes.get(&rid) {
+                    if layer < rn.connections.len() {
+                        for &c in &rn.connections[layer] {
+                            if c != neighbor_id && c != removed_id && !remaining.contains(&c) {
+                                candidate_ids.insert(c);
+                            }
+                        }
+                    }
+                }
+            }
+
+            let mut pool: Vec<(u64, f32)> = remaining
+                .iter()
+                .filter_map(|&rid| {
+                    self.nodes.get(&rid).map(|n| (rid, self.distance(&neighbor_vec, &n.vector)))
+                })
+                .collect();
+            pool.extend(candidate_ids.iter().filter_map(|&cid| {
+                self.nodes.get(&cid).map(|n| (cid, self.distance(&neighbor_vec, &n.vector)))
+            }));
+
+            let newly_selected = self.select_neighbors(&neighbor_vec, &pool, max_conn);
+
+            for &sel_id in &newly_selected {
+                if !remaining.contains(&sel_id) {
+                    if let Some(n) = self.nodes.get_mut(&sel_id) {
+                        if layer < n.connections.len() && !n.connections[layer].contains(&neighbor_id) {
+                            n.connections[layer].push(neighbor_id);
+                        }
+                    }
+                    let max_m = if layer == 0 { self.config.m0 } else { self.config.m };
+                    self.prune_if_overloaded(sel_id, layer, max_m, neighbor_id);
+                }
+            }
+
+            remaining = newly_selected;
+        }
+
+        if let Some(n) = self.nodes.get_mut(&neighbor_id) {
+            if layer < n.connections.len() {
+                n.connections[layer] = remaining;
+            }
+        }
+    }
+
+    /// Re-run the neighbor-selection beam search for an *existing* node at
+    /// every layer it participates in, rewiring both its own connections
+    /// and reciprocal links from its new neighbors. Used by `update` for
+    /// in-place vector replacement.
+    fn reconnect_node(&mut self, id: u64) {
+        let (vector, node_layer) = match self.nodes.get(&id) {
+            Some(n) => (n.vector.clone(), n.max_layer),
+            None => return,
+        };
+
+        let seed = match self.entry_point {
+            Some(e) if e != id => Some(e),
+            Some(_) => self.nodes.keys().find(|&&k| k != id).copied(),
+            None => None,
+        };
+        let mut current_id = match seed {
+            Some(s) => s,
+            None => return, // only node in the graph; nothing to reconnect to
+        };
+
+        for layer in (node_layer + 1..=self.max_layer).rev() {
+            current_id = self.search_layer_greedy(&vector, current_id, layer);
+        }
+
+        let mut own_connections = vec![Vec::new(); node_layer + 1];
+        let mut neighbor_updates: Vec<(u64, usize)> = Vec::new();
+
+        for layer in (0..=node_layer.min(self.max_layer)).rev() {
+            let ef = self.config.ef_construction;
+            let candidates: Vec<(u64, f32)> = self
+                .search_layer(&vector, current_id, ef, layer)
+                .into_iter()
+                .filter(|(cid, _)| *cid != id)
+                .collect();
+
+            let m = if layer == 0 { self.config.m0 } else { self.config.m };
+            let neighbors = self.select_neighbors(&vector, &candidates, m);
+
+            own_connections[layer] = neighbors.clone();
+            for &neighbor_id in &neighbors {
+                neighbor_updates.push((neighbor_id, layer));
+            }
+
+            if !candidates.is_empty() {
+                current_id = candidates[0].0;
+            }
+        }
+
+        // Drop `id` from every old neighbor list at every layer it used to
+        // occupy — some may not be re-selected above.
+        if let Some(node) = self.nodes.get(&id) {
+            let old_connections = node.connections.clone();
+            for (layer, conns) in old_connections.iter().enumerate() {
+                for &old_neighbor in conns {
+                    if let Some(n) = self.nodes.get_mut(&old_neighbor) {
+                        if layer < n.connections.len() {
+                            n.connections[layer].retain(|&c| c != id);
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.connections = own_connections;
+        }
+
+        for (neighbor_id, layer) in &neighbor_updates {
+            if let Some(neighbor) = self.nodes.get_mut(neighbor_id) {
+                if *layer < neighbor.connections.len() && !neighbor.connections[*layer].contains(&id) {
+                    neighbor.connections[*layer].push(id);
+                }
+            }
+        }
+
+        for (neighbor_id, layer) in neighbor_updates {
+            let max_m = if layer == 0 { self.config.m0 } else { self.config.m };
+            self.prune_if_overloaded(neighbor_id, layer, max_m, id);
+        }
+    }
+
+    /// Shared prune step: if `neighbor_id`'s connection list at `layer`
+    /// exceeds `max_m`, keep the closest `max_m - 1` (by the diversity
+    /// heuristic) plus always retain `keep_id`, the edge that triggered
+    /// the overload.
+    fn prune_if_overloaded(&mut self, neighbor_id: u64, layer: usize, max_m: usize, keep_id: u64) {
+        let prune_data = match self.nodes.get(&neighbor_id) {
+            Some(neighbor)
+                if layer < neighbor.connections.len() && neighbor.connections[layer].len() > max_m =>
+            {
+                Some((neighbor.vector.clone(), neighbor.connections[layer].clone()))
+            }
+            _ => None,
+        };
+
+        if let Some((neighbor_vec, conn_ids)) = prune_data {
+            let candidates: Vec<(u64, f32)> = conn_ids
+                .iter()
+                .filter(|&&cid| cid != keep_id)
+                .filter_map(|&cid| {
+                    self.nodes.get(&cid).map(|n| (cid, self.distance(&neighbor_vec, &n.vector)))
+                })
+                .collect();
+
+            let mut pruned = self.select_neighbors(&neighbor_vec, &candidates, max_m.saturating_sub(1));
+            pruned.push(keep_id);
+
+            if let Some(neighbor) = self.nodes.get_mut(&neighbor_id) {
+                if layer < neighbor.connections.len() {
+                    neighbor.connections[layer] = pruned;
+                }
+            }
+        }
+    }
+
     pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(u64, f32)>, HnswError> {
         if query.len() != self.config.dimension {
             return Err(HnswError::DimensionMismatch {
@@ -278,6 +808,54 @@ impl HnswIndex {
             return Ok(Vec::new());
         }
 
+        // A tombstoned node must still serve as a routing hop (its edges
+        // are untouched by `soft_delete`), so the exclusion is applied via
+        // `search_filtered`'s predicate rather than by skipping it during
+        // traversal — that also gets `search` the same ef-widening a
+        // heavily-tombstoned region needs to still surface `k` live hits.
+        if self.deleted.is_empty() {
+            let entry_id = self.entry_point.unwrap();
+            let mut current_id = entry_id;
+
+            for layer in (1..=self.max_layer).rev() {
+                current_id = self.search_layer_greedy(query, current_id, layer);
+            }
+
+            let ef = self.config.ef_search.max(k);
+            let candidates = self.search_layer(query, current_id, ef, 0);
+
+            let results: Vec<(u64, f32)> = candidates.into_iter().take(k).collect();
+            return Ok(results);
+        }
+
+        self.search_filtered(query, k, &|id| !self.deleted.contains(&id))
+    }
+
+    /// Nearest-neighbor search restricted to ids matching `predicate`.
+    ///
+    /// The graph is still traversed through *all* nodes for connectivity —
+    /// a node failing `predicate` can still serve as a routing hop — but only
+    /// predicate-passing ids are admitted into the result set. Since a
+    /// restrictive predicate can filter out most of a single `ef_search`
+    /// pass, `ef` is widened and the layer-0 search retried until either `k`
+    /// matches are found or the whole index has been explored.
+    pub fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        predicate: &dyn Fn(u64) -> bool,
+    ) -> Result<Vec<(u64, f32)>, HnswError> {
+        if query.len() != self.config.dimension {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.config.dimension,
+                got: query.len(),
+            });
+        }
+
+        if self.entry_point.is_none() {
+            return Ok(Vec::new());
+        }
+
         let entry_id = self.entry_point.unwrap();
         let mut current_id = entry_id;
 
@@ -285,10 +863,19 @@ impl HnswIndex {
             current_id = self.search_layer_greedy(query, current_id, layer);
         }
 
-        let ef = self.config.ef_search.max(k);
-        let candidates = self.search_layer(query, current_id, ef, 0);
+        let mut ef = self.config.ef_search.max(k);
+        let mut results;
+        loop {
+            results = self.search_layer_filtered(query, current_id, ef, 0, predicate);
+            if results.len() >= k || ef >= self.count {
+                break;
+            }
+            // Widen aggressively: a selective predicate can reject most of a
+            // normal-sized ef pass, so doubling would take many retries.
+            ef = (ef * 4).min(self.count.max(1));
+        }
 
-        let results: Vec<(u64, f32)> = candidates.into_iter().take(k).collect();
+        results.truncate(k);
         Ok(results)
     }
 
@@ -364,22 +951,181 @@ impl HnswIndex {
         result_vec
     }
 
-    fn select_neighbors(&self, _query: &[f32], candidates: &[(u64, f32)], m: usize) -> Vec<u64> {
-        candidates.iter().take(m).map(|(id, _)| *id).collect()
+    /// Same beam search as `search_layer`, but only ids passing `predicate`
+    /// are admitted into the result heap. Admission into the *traversal*
+    /// frontier (`candidates`) is unaffected by `predicate` — a node must
+    /// remain usable as a routing hop even if it doesn't itself match.
+    fn search_layer_filtered(
+        &self,
+        query: &[f32],
+        entry_id: u64,
+        ef: usize,
+        layer: usize,
+        predicate: &dyn Fn(u64) -> bool,
+    ) -> Vec<(u64, f32)> {
+        let mut visited = HashSet::new();
+        let mut candidates: BinaryHeap<Candidate> = BinaryHeap::new();
+        let mut results: BinaryHeap<MaxCandidate> = BinaryHeap::new();
+
+        let entry_dist = self.distance_to_node(query, entry_id);
+
+        visited.insert(entry_id);
+        candidates.push(Candidate { id: entry_id, distance: entry_dist });
+        if predicate(entry_id) {
+            results.push(MaxCandidate { id: entry_id, distance: entry_dist });
+        }
+
+        while let Some(current) = candidates.pop() {
+            let furthest_dist = results.peek().map(|c| c.distance).unwrap_or(f32::MAX);
+
+            if current.distance > furthest_dist {
+                break;
+            }
+
+            if let Some(node) = self.nodes.get(&current.id) {
+                if layer < node.connections.len() {
+                    for &neighbor_id in &node.connections[layer] {
+                        if visited.insert(neighbor_id) {
+                            let dist = self.distance_to_node(query, neighbor_id);
+                            let furthest = results.peek().map(|c| c.distance).unwrap_or(f32::MAX);
+
+                            if dist < furthest || results.len() < ef {
+                                candidates.push(Candidate { id: neighbor_id, distance: dist });
+
+                                if predicate(neighbor_id) {
+                                    results.push(MaxCandidate { id: neighbor_id, distance: dist });
+
+                                    if results.len() > ef {
+                                        results.pop();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut result_vec: Vec<(u64, f32)> = results.into_iter().map(|c| (c.id, c.distance)).collect();
+        result_vec.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+        result_vec
     }
 
-    fn select_neighbors_simple(&self, candidates: &[(u64, f32)], m: usize) -> Vec<u64> {
+    /// Select up to `m` neighbors for `query` using the HNSW paper's diversity
+    /// heuristic (Malkov & Yashunin, Algorithm 4) rather than naive "m closest".
+    ///
+    /// Candidates are considered nearest-first. A candidate `e` is kept only if
+    /// it is closer to `query` than to every neighbor already selected — i.e.
+    /// `dist(e, query) < min(dist(e, r) for r in result)`. This spreads
+    /// connections across directions instead of clustering them all on one
+    /// side of `query`, which keeps the graph navigable at lower degree.
+    /// Discarded candidates are kept so that, if `extend_candidates` is set
+    /// and the diverse set still falls short of `m`, we backfill nearest-first
+    /// rather than under-connecting the node.
+    fn select_neighbors(&self, query: &[f32], candidates: &[(u64, f32)], m: usize) -> Vec<u64> {
         let mut sorted = candidates.to_vec();
         sorted.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
-        sorted.into_iter().take(m).map(|(id, _)| id).collect()
+
+        let mut result: Vec<u64> = Vec::with_capacity(m.min(sorted.len()));
+        let mut discarded: Vec<u64> = Vec::new();
+
+        for (id, dist_to_query) in sorted {
+            if result.len() >= m {
+                break;
+            }
+
+            let dominated = result
+                .iter()
+                .any(|&r| self.distance_between(id, r) < dist_to_query);
+
+            if dominated {
+                discarded.push(id);
+            } else {
+                result.push(id);
+            }
+        }
+
+        if self.config.extend_candidates {
+            for id in discarded {
+                if result.len() >= m {
+                    break;
+                }
+                result.push(id);
+            }
+        }
+
+        result
+    }
+
+    /// Same diversity heuristic as `select_neighbors`, for the prune step
+    /// where an overloaded neighbor's own vector (`query`) is re-evaluated
+    /// against its current connections.
+    fn select_neighbors_simple(&self, query: &[f32], candidates: &[(u64, f32)], m: usize) -> Vec<u64> {
+        self.select_neighbors(query, candidates, m)
+    }
+
+    /// Distance between two already-inserted nodes, looked up by ID.
+    /// Returns `f32::MAX` if either node is missing (cannot happen for IDs
+    /// drawn from the graph itself, but keeps this total rather than panicking).
+    /// Uses both nodes' precomputed `norm` — cheaper than `distance()` for
+    /// metrics like `Cosine` that would otherwise re-derive it.
+    fn distance_between(&self, a: u64, b: u64) -> f32 {
+        match (self.nodes.get(&a), self.nodes.get(&b)) {
+            (Some(node_a), Some(node_b)) => self.distance_core(
+                &node_a.vector,
+                node_a.norm,
+                &node_b.vector,
+                node_b.norm,
+            ),
+            _ => f32::MAX,
+        }
     }
 
+    /// Distance from a query vector (not yet a node, so its norm is computed
+    /// on the fly) to an existing node (whose norm is cached).
     fn distance_to_node(&self, query: &[f32], node_id: u64) -> f32 {
-        self.nodes.get(&node_id).map(|node| self.distance(query, &node.vector)).unwrap_or(f32::MAX)
+        self.nodes
+            .get(&node_id)
+            .map(|node| self.distance_core(query, Self::vector_norm(query), &node.vector, node.norm))
+            .unwrap_or(f32::MAX)
     }
 
+    /// Distance between two arbitrary vectors, dispatched through the
+    /// configured `HnswDistance` metric. Neither side is assumed to be a
+    /// stored node, so both norms are computed fresh; prefer
+    /// `distance_between`/`distance_to_node` when one or both sides are
+    /// already-inserted nodes.
     fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
-        a.iter().zip(b.iter()).map(|(x, y)| (x - y).powi(2)).sum()
+        self.distance_core(a, Self::vector_norm(a), b, Self::vector_norm(b))
+    }
+
+    /// Core metric dispatch, taking precomputed norms so callers can reuse
+    /// cached per-node values instead of recomputing them every call.
+    fn distance_core(&self, a: &[f32], norm_a: f32, b: &[f32], norm_b: f32) -> f32 {
+        distance_with_metric(self.config.metric, a, norm_a, b, norm_b)
+    }
+
+    /// L2 norm of a vector, cached per-node as `HnswNode::norm` at insert
+    /// time so `Cosine` distance doesn't redo this for the stored side.
+    fn vector_norm(v: &[f32]) -> f32 {
+        v.iter().map(|x| x * x).sum::<f32>().sqrt()
+    }
+
+    /// Map a raw distance (in this index's configured metric) to a
+    /// `[0, 1]` confidence/similarity score, used by `classify`,
+    /// `anomaly_score`, and `similarity_check`. Each metric has a different
+    /// native range, so the mapping differs: `L2`/`Manhattan` are unbounded
+    /// above, so an exponential decay is used; `Cosine` is already bounded
+    /// to `[0, 2]`, so a linear map is correct and an `exp(-sqrt(d))` curve
+    /// would badly distort it; `InnerProduct` is unbounded in both
+    /// directions, so a sigmoid centers "no correlation" at 0.5.
+    fn distance_to_confidence(&self, distance: f32) -> f32 {
+        match self.config.metric {
+            HnswDistance::L2 => (-distance.sqrt()).exp().clamp(0.0, 1.0),
+            HnswDistance::Manhattan => (-distance).exp().clamp(0.0, 1.0),
+            HnswDistance::InnerProduct => (1.0 / (1.0 + distance.exp())).clamp(0.0, 1.0),
+            HnswDistance::Cosine => (1.0 - distance / 2.0).clamp(0.0, 1.0),
+        }
     }
 
     fn random_layer(&self, id: u64) -> usize {
@@ -409,13 +1155,21 @@ impl HnswIndex {
         bytes.extend_from_slice(&(self.max_layer as u32).to_le_bytes());
         bytes.extend_from_slice(&self.entry_point.unwrap_or(0).to_le_bytes());
         bytes.push(if self.entry_point.is_some() { 1 } else { 0 });
+        bytes.push(self.config.metric.to_byte());
+        bytes.push(if self.config.quantize { 1 } else { 0 });
 
         for (id, node) in &self.nodes {
             bytes.extend_from_slice(&id.to_le_bytes());
             bytes.extend_from_slice(&(node.max_layer as u32).to_le_bytes());
 
-            for &v in &node.vector {
-                bytes.extend_from_slice(&v.to_le_bytes());
+            if let Some(q) = &node.quantization {
+                bytes.extend_from_slice(&q.min.to_le_bytes());
+                bytes.extend_from_slice(&q.max.to_le_bytes());
+                bytes.extend_from_slice(&q.codes);
+            } else {
+                for &v in &node.vector {
+                    bytes.extend_from_slice(&v.to_le_bytes());
+                }
             }
 
             for layer_conns in &node.connections {
@@ -426,11 +1180,16 @@ impl HnswIndex {
             }
         }
 
-        bytes
-    }
+        bytes.extend_from_slice(&(self.deleted.len() as u32).to_le_bytes());
+        for &id in &self.deleted {
+            bytes.extend_from_slice(&id.to_le_bytes());
+        }
+
+        bytes
+    }
 
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, HnswError> {
-        if bytes.len() < 37 {
+        if bytes.len() < 39 {
             return Err(HnswError::InvalidData);
         }
 
@@ -457,6 +1216,10 @@ impl HnswIndex {
         pos += 8;
         let has_entry = bytes[pos] == 1;
         pos += 1;
+        config.metric = HnswDistance::from_byte(bytes[pos]);
+        pos += 1;
+        config.quantize = bytes[pos] == 1;
+        pos += 1;
 
         let entry_point = if has_entry { Some(entry_id) } else { None };
 
@@ -471,15 +1234,32 @@ impl HnswIndex {
             let node_max_layer = u32::from_le_bytes(bytes[pos..pos+4].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
             pos += 4;
 
-            let mut vector = Vec::with_capacity(dimension);
-            for _ in 0..dimension {
-                if pos + 4 > bytes.len() {
+            let (vector, quantization) = if config.quantize {
+                if pos + 8 + dimension > bytes.len() {
                     return Err(HnswError::InvalidData);
                 }
-                let v = f32::from_le_bytes(bytes[pos..pos+4].try_into().map_err(|_| HnswError::InvalidData)?);
+                let min = f32::from_le_bytes(bytes[pos..pos+4].try_into().map_err(|_| HnswError::InvalidData)?);
                 pos += 4;
-                vector.push(v);
-            }
+                let max = f32::from_le_bytes(bytes[pos..pos+4].try_into().map_err(|_| HnswError::InvalidData)?);
+                pos += 4;
+                let codes = bytes[pos..pos + dimension].to_vec();
+                pos += dimension;
+
+                let q = ScalarQuantization { codes, min, max };
+                let vector = dequantize_vector(&q);
+                (vector, Some(q))
+            } else {
+                let mut vector = Vec::with_capacity(dimension);
+                for _ in 0..dimension {
+                    if pos + 4 > bytes.len() {
+                        return Err(HnswError::InvalidData);
+                    }
+                    let v = f32::from_le_bytes(bytes[pos..pos+4].try_into().map_err(|_| HnswError::InvalidData)?);
+                    pos += 4;
+                    vector.push(v);
+                }
+                (vector, None)
+            };
 
             let mut connections = Vec::with_capacity(node_max_layer + 1);
             for _ in 0..=node_max_layer {
@@ -501,365 +1281,1978 @@ impl HnswIndex {
                 connections.push(layer_conns);
             }
 
-            nodes.insert(id, HnswNode { id, vector, connections, max_layer: node_max_layer });
+            let norm = Self::vector_norm(&vector);
+            nodes.insert(id, HnswNode { id, vector, connections, max_layer: node_max_layer, norm, quantization });
         }
 
-        Ok(Self { config, nodes, entry_point, max_layer, count })
-    }
-}
-
-/// HNSW-backed vector store for precompile integration
-pub struct HnswVectorStore {
-    index: HnswIndex,
-}
-
-impl HnswVectorStore {
-    pub fn new(dimension: usize) -> Self {
-        Self { index: HnswIndex::new(dimension) }
-    }
+        if pos + 4 > bytes.len() {
+            return Err(HnswError::InvalidData);
+        }
+        let deleted_count = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
+        pos += 4;
+        let mut deleted = HashSet::with_capacity(deleted_count);
+        for _ in 0..deleted_count {
+            if pos + 8 > bytes.len() {
+                return Err(HnswError::InvalidData);
+            }
+            deleted.insert(u64::from_le_bytes(bytes[pos..pos + 8].try_into().map_err(|_| HnswError::InvalidData)?));
+            pos += 8;
+        }
 
-    pub fn insert(&mut self, id: u64, vector: Vec<f32>) -> Result<(), HnswError> {
-        self.index.insert(id, vector)
+        Ok(Self { config, nodes, entry_point, max_layer, count, deleted })
     }
 
-    pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(u64, f32)>, HnswError> {
-        self.index.search(query, k)
+    /// Encode in the same flat, offset-addressable layout as `to_bytes`.
+    ///
+    /// The request this was added for asks for an `rkyv`-archived format,
+    /// but this workspace has no `Cargo.toml` to add `rkyv` as a
+    /// dependency to, so there's no archival framework to generate an
+    /// archived type from. `to_bytes`'s wire format is already a flat,
+    /// sequentially-addressable buffer (no pointers to fix up), which is
+    /// the property `load_mmap` actually needs — it builds an id→offset
+    /// index over this buffer once and then decodes individual nodes
+    /// directly from caller-owned bytes on demand, which is what a real
+    /// `rkyv` archive would buy here. Kept as a distinctly-named method
+    /// (not just an alias) so a future switch to real `rkyv` only has to
+    /// change this one function and `load_mmap`.
+    pub fn to_archived_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
     }
 
-    #[allow(dead_code)]
-    pub fn len(&self) -> usize {
-        self.index.len()
+    /// Build a read-only, lazily-decoded view over an `to_archived_bytes`
+    /// buffer without materializing the `HashMap<u64, HnswNode>` graph.
+    ///
+    /// Only a small `id -> offset` index is allocated up front; node
+    /// vectors and connection lists are decoded from `bytes` on demand by
+    /// `ArchivedHnswIndex::search`, so faulting in a memory-mapped file's
+    /// pages (the caller's responsibility — this takes a plain `&[u8]`)
+    /// only touches the nodes actually visited by a query instead of the
+    /// whole index.
+    pub fn load_mmap(bytes: &[u8]) -> Result<ArchivedHnswIndex<'_>, HnswError> {
+        ArchivedHnswIndex::parse(bytes)
     }
 
-    #[allow(dead_code)]
-    pub fn is_empty(&self) -> bool {
-        self.index.is_empty()
+    /// Merkle root over this index's `(id, vector)` pairs in sorted-id
+    /// order. See `HnswVectorStore::root_hash` for the consensus-facing
+    /// entry point, and `to_bytes_delta` for why this is also computed
+    /// internally as a delta-header base-check.
+    fn root_hash(&self) -> [u8; 32] {
+        merkle_levels(self.sorted_leaf_hashes()).last().unwrap()[0]
     }
 
-    #[allow(dead_code)]
-    pub fn to_bytes(&self) -> Vec<u8> {
-        self.index.to_bytes()
+    /// Leaf hashes in sorted-id order — the Merkle tree's deterministic
+    /// leaf ordering.
+    fn sorted_leaf_hashes(&self) -> Vec<[u8; 32]> {
+        let mut ids: Vec<u64> = self.nodes.keys().copied().collect();
+        ids.sort();
+        ids.iter().map(|id| merkle_leaf_hash(*id, &self.nodes[id].vector)).collect()
     }
 
-    #[allow(dead_code)]
-    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HnswError> {
-        Ok(Self { index: HnswIndex::from_bytes(bytes)? })
-    }
+    /// Diff-encode this index against `prev`, producing a delta that
+    /// `prev.apply_delta` can replay to reconstruct this index — without
+    /// re-transmitting nodes whose vector and adjacency are unchanged.
+    /// Inspired by LZ77-style back-referencing: each node becomes either a
+    /// cheap COPY record (id only, meaning "unchanged from `prev`") or an
+    /// INSERT/MODIFY record carrying the full node body, and nodes present
+    /// in `prev` but absent here become DELETE records.
+    ///
+    /// The delta is only valid against the exact `prev` it was built from —
+    /// its header carries `prev`'s root hash so `apply_delta` can refuse to
+    /// replay it against a different base state.
+    pub fn to_bytes_delta(&self, prev: &HnswIndex) -> Vec<u8> {
+        let mut bytes = Vec::new();
 
-    /// Calculate Merkle root hash for consensus verification
-    /// Produces a deterministic 32-byte hash from all stored vectors
-    pub fn root_hash(&self) -> [u8; 32] {
-        if self.index.is_empty() {
-            return [0u8; 32];
-        }
+        // Header: base root hash, then the metadata this delta updates to.
+        bytes.extend_from_slice(&prev.root_hash());
+        bytes.extend_from_slice(&(self.count as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.max_layer as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.entry_point.unwrap_or(0).to_le_bytes());
+        bytes.push(if self.entry_point.is_some() { 1 } else { 0 });
+        bytes.push(self.config.metric.to_byte());
+        bytes.push(if self.config.quantize { 1 } else { 0 });
 
-        // Collect and sort IDs for deterministic ordering
-        let mut ids: Vec<u64> = self.index.nodes.keys().copied().collect();
+        let mut ids: Vec<u64> = self.nodes.keys().copied().collect();
         ids.sort();
 
-        // Hash all vector data in sorted order
-        let mut data = Vec::new();
+        let mut deleted_ids: Vec<u64> = prev
+            .nodes
+            .keys()
+            .copied()
+            .filter(|id| !self.nodes.contains_key(id))
+            .collect();
+        deleted_ids.sort();
+
+        bytes.extend_from_slice(&(deleted_ids.len() as u32).to_le_bytes());
+        for id in deleted_ids {
+            bytes.extend_from_slice(&id.to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&(ids.len() as u32).to_le_bytes());
         for id in ids {
-            data.extend_from_slice(&id.to_le_bytes());
-            if let Some(node) = self.index.nodes.get(&id) {
-                for val in &node.vector {
-                    data.extend_from_slice(&val.to_le_bytes());
+            let node = &self.nodes[&id];
+            let unchanged = prev
+                .nodes
+                .get(&id)
+                .is_some_and(|p| p.vector == node.vector && p.connections == node.connections);
+
+            bytes.extend_from_slice(&id.to_le_bytes());
+            bytes.push(if unchanged { DELTA_RECORD_COPY } else { DELTA_RECORD_WRITE });
+
+            if !unchanged {
+                bytes.extend_from_slice(&(node.max_layer as u32).to_le_bytes());
+                if let Some(q) = &node.quantization {
+                    bytes.extend_from_slice(&q.min.to_le_bytes());
+                    bytes.extend_from_slice(&q.max.to_le_bytes());
+                    bytes.extend_from_slice(&q.codes);
+                } else {
+                    for &v in &node.vector {
+                        bytes.extend_from_slice(&v.to_le_bytes());
+                    }
+                }
+                for layer_conns in &node.connections {
+                    bytes.extend_from_slice(&(layer_conns.len() as u32).to_le_bytes());
+                    for &conn_id in layer_conns {
+                        bytes.extend_from_slice(&conn_id.to_le_bytes());
+                    }
                 }
             }
         }
 
-        luxtensor_crypto::keccak256(&data)
+        bytes
     }
 
-    // ==================== AI Primitives ====================
-
-    /// Classify a vector against labeled reference vectors.
-    /// Returns the label of the closest match and confidence score.
-    ///
-    /// # Arguments
-    /// * `query` - The vector to classify
-    /// * `labels` - List of (vector_id, label) pairs representing categories
-    ///
-    /// # Returns
-    /// * `(label, confidence)` where confidence is 1.0 - normalized_distance
-    pub fn classify(&self, query: &[f32], labels: &[(u64, u32)]) -> Result<(u32, f32), HnswError> {
-        if labels.is_empty() {
+    /// Apply a delta produced by some later index's
+    /// `to_bytes_delta(&self)` (with `self` as the base), returning the
+    /// reconstructed new index. Errors with `InvalidData` if the delta's
+    /// stated base root hash doesn't match `self` — it was built against a
+    /// different base state than this one.
+    pub fn apply_delta(&self, delta: &[u8]) -> Result<HnswIndex, HnswError> {
+        if delta.len() < 32 + 8 + 4 + 8 + 1 + 1 + 1 + 4 + 4 {
             return Err(HnswError::InvalidData);
         }
 
-        // Search for nearest neighbor among all stored vectors
-        let results = self.search(query, 1)?;
+        let mut pos = 0;
 
-        if results.is_empty() {
+        let base_root: [u8; 32] =
+            delta[pos..pos + 32].try_into().map_err(|_| HnswError::InvalidData)?;
+        pos += 32;
+        if base_root != self.root_hash() {
             return Err(HnswError::InvalidData);
         }
 
-        let (nearest_id, distance) = results[0];
-
-        // Find label for nearest vector
-        let label = labels.iter()
-            .find(|(id, _)| *id == nearest_id)
-            .map(|(_, l)| *l)
-            .unwrap_or(0);
-
-        // Convert distance to confidence (1.0 = exact match, 0.0 = very far)
-        // Using exponential decay: confidence = e^(-distance)
-        let confidence = (-distance.sqrt()).exp().clamp(0.0, 1.0);
+        let count = u64::from_le_bytes(
+            delta[pos..pos + 8].try_into().map_err(|_| HnswError::InvalidData)?,
+        ) as usize;
+        pos += 8;
+        let max_layer = u32::from_le_bytes(
+            delta[pos..pos + 4].try_into().map_err(|_| HnswError::InvalidData)?,
+        ) as usize;
+        pos += 4;
+        let entry_id = u64::from_le_bytes(
+            delta[pos..pos + 8].try_into().map_err(|_| HnswError::InvalidData)?,
+        );
+        pos += 8;
+        let has_entry = delta[pos] == 1;
+        pos += 1;
+        let metric = HnswDistance::from_byte(delta[pos]);
+        pos += 1;
+        let quantize = *delta.get(pos).ok_or(HnswError::InvalidData)? == 1;
+        pos += 1;
 
-        Ok((label, confidence))
-    }
+        let mut nodes = self.nodes.clone();
 
-    /// Calculate anomaly score for a vector relative to the stored vectors.
-    /// Higher score means more anomalous (further from all stored vectors).
-    ///
-    /// # Returns
-    /// * Score in range [0.0, 1.0] where 1.0 = highly anomalous
-    pub fn anomaly_score(&self, query: &[f32]) -> Result<f32, HnswError> {
-        if self.index.is_empty() {
-            return Ok(1.0); // No data = everything is anomalous
+        let deleted_count = u32::from_le_bytes(
+            delta
+                .get(pos..pos + 4)
+                .ok_or(HnswError::InvalidData)?
+                .try_into()
+                .map_err(|_| HnswError::InvalidData)?,
+        ) as usize;
+        pos += 4;
+        for _ in 0..deleted_count {
+            let id = u64::from_le_bytes(
+                delta
+                    .get(pos..pos + 8)
+                    .ok_or(HnswError::InvalidData)?
+                    .try_into()
+                    .map_err(|_| HnswError::InvalidData)?,
+            );
+            pos += 8;
+            nodes.remove(&id);
         }
 
-        // Get k nearest neighbors to calculate average distance
-        let k = 5.min(self.index.len());
-        let results = self.search(query, k)?;
-
-        if results.is_empty() {
-            return Ok(1.0);
-        }
+        let record_count = u32::from_le_bytes(
+            delta
+                .get(pos..pos + 4)
+                .ok_or(HnswError::InvalidData)?
+                .try_into()
+                .map_err(|_| HnswError::InvalidData)?,
+        ) as usize;
+        pos += 4;
 
-        // Calculate average distance to nearest neighbors
-        let avg_distance: f32 = results.iter().map(|(_, d)| d).sum::<f32>() / results.len() as f32;
+        for _ in 0..record_count {
+            let id = u64::from_le_bytes(
+                delta
+                    .get(pos..pos + 8)
+                    .ok_or(HnswError::InvalidData)?
+                    .try_into()
+                    .map_err(|_| HnswError::InvalidData)?,
+            );
+            pos += 8;
+            let record_kind = *delta.get(pos).ok_or(HnswError::InvalidData)?;
+            pos += 1;
 
-        // Normalize to [0, 1] using sigmoid-like function
-        // threshold = 2.0 is "normal" distance, higher = more anomalous
-        let threshold = 2.0;
-        let score = 1.0 / (1.0 + (-((avg_distance / threshold) - 1.0)).exp());
+            if record_kind == DELTA_RECORD_COPY {
+                if !nodes.contains_key(&id) {
+                    return Err(HnswError::InvalidData);
+                }
+                continue;
+            }
 
-        Ok(score.clamp(0.0, 1.0))
-    }
+            let node_max_layer = u32::from_le_bytes(
+                delta
+                    .get(pos..pos + 4)
+                    .ok_or(HnswError::InvalidData)?
+                    .try_into()
+                    .map_err(|_| HnswError::InvalidData)?,
+            ) as usize;
+            pos += 4;
 
-    /// Check if two vectors are semantically similar above a threshold.
-    ///
-    /// # Arguments
-    /// * `vector_a` - First vector
-    /// * `vector_b` - Second vector
-    /// * `threshold` - Similarity threshold (0.0 to 1.0)
-    ///
-    /// # Returns
-    /// * `(is_similar, similarity_score)`
-    pub fn similarity_check(&self, vector_a: &[f32], vector_b: &[f32], threshold: f32) -> Result<(bool, f32), HnswError> {
-        if vector_a.len() != self.index.config.dimension || vector_b.len() != self.index.config.dimension {
-            return Err(HnswError::DimensionMismatch {
-                expected: self.index.config.dimension,
-                got: vector_a.len().min(vector_b.len()),
-            });
-        }
+            let (vector, quantization) = if quantize {
+                let min = f32::from_le_bytes(
+                    delta
+                        .get(pos..pos + 4)
+                        .ok_or(HnswError::InvalidData)?
+                        .try_into()
+                        .map_err(|_| HnswError::InvalidData)?,
+                );
+                pos += 4;
+                let max = f32::from_le_bytes(
+                    delta
+                        .get(pos..pos + 4)
+                        .ok_or(HnswError::InvalidData)?
+                        .try_into()
+                        .map_err(|_| HnswError::InvalidData)?,
+                );
+                pos += 4;
+                let codes = delta
+                    .get(pos..pos + self.config.dimension)
+                    .ok_or(HnswError::InvalidData)?
+                    .to_vec();
+                pos += self.config.dimension;
+
+                let q = ScalarQuantization { codes, min, max };
+                let vector = dequantize_vector(&q);
+                (vector, Some(q))
+            } else {
+                let mut vector = Vec::with_capacity(self.config.dimension);
+                for _ in 0..self.config.dimension {
+                    let v = f32::from_le_bytes(
+                        delta
+                            .get(pos..pos + 4)
+                            .ok_or(HnswError::InvalidData)?
+                            .try_into()
+                            .map_err(|_| HnswError::InvalidData)?,
+                    );
+                    pos += 4;
+                    vector.push(v);
+                }
+                (vector, None)
+            };
 
-        // Calculate Euclidean distance
-        let distance: f32 = vector_a.iter()
-            .zip(vector_b.iter())
-            .map(|(a, b)| (a - b).powi(2))
-            .sum();
+            let mut connections = Vec::with_capacity(node_max_layer + 1);
+            for _ in 0..=node_max_layer {
+                let conn_len = u32::from_le_bytes(
+                    delta
+                        .get(pos..pos + 4)
+                        .ok_or(HnswError::InvalidData)?
+                        .try_into()
+                        .map_err(|_| HnswError::InvalidData)?,
+                ) as usize;
+                pos += 4;
 
-        // Convert distance to similarity (1.0 = identical, 0.0 = very different)
-        let similarity = (-distance.sqrt() / 2.0).exp();
-        let is_similar = similarity >= threshold;
+                let mut layer_conns = Vec::with_capacity(conn_len);
+                for _ in 0..conn_len {
+                    let conn_id = u64::from_le_bytes(
+                        delta
+                            .get(pos..pos + 8)
+                            .ok_or(HnswError::InvalidData)?
+                            .try_into()
+                            .map_err(|_| HnswError::InvalidData)?,
+                    );
+                    pos += 8;
+                    layer_conns.push(conn_id);
+                }
+                connections.push(layer_conns);
+            }
 
-        Ok((is_similar, similarity))
-    }
+            let norm = Self::vector_norm(&vector);
+            nodes.insert(
+                id,
+                HnswNode { id, vector, connections, max_layer: node_max_layer, norm, quantization },
+            );
+        }
 
-    /// Get a vector by ID for cross-contract composability.
-    pub fn get_vector(&self, id: u64) -> Option<Vec<f32>> {
-        self.index.nodes.get(&id).map(|node| node.vector.clone())
-    }
+        if nodes.len() != count {
+            return Err(HnswError::InvalidData);
+        }
 
-    /// Get the dimension of vectors in this store.
-    pub fn dimension(&self) -> usize {
-        self.index.config.dimension
-    }
-}
+        let mut config = self.config.clone();
+        config.metric = metric;
+        config.quantize = quantize;
 
+        // Tombstones aren't part of the delta wire format; carry forward
+        // whichever of the base's survive onto nodes still present.
+        let deleted: HashSet<u64> = self.deleted.iter().copied().filter(|id| nodes.contains_key(id)).collect();
 
-impl Default for HnswVectorStore {
-    fn default() -> Self {
-        Self::new(768) // Default to 768 dimensions (standard embedding size)
+        Ok(HnswIndex {
+            config,
+            nodes,
+            entry_point: if has_entry { Some(entry_id) } else { None },
+            max_layer,
+            count,
+            deleted,
+        })
     }
 }
 
-/// HNSW errors
-#[derive(Debug, Clone)]
-pub enum HnswError {
-    DimensionMismatch { expected: usize, got: usize },
-    CapacityExceeded,
-    DuplicateId(u64),
-    InvalidData,
+/// Read-only view over a `to_archived_bytes` buffer, produced by
+/// `HnswIndex::load_mmap`. Holds only a header and an `id -> offset`
+/// index into `bytes`; see `HnswIndex::to_archived_bytes` for why this
+/// hand-rolled layout stands in for an `rkyv` archive.
+pub struct ArchivedHnswIndex<'a> {
+    bytes: &'a [u8],
+    config: HnswConfig,
+    entry_point: Option<u64>,
+    max_layer: usize,
+    count: usize,
+    offsets: HashMap<u64, usize>,
 }
 
-impl std::fmt::Display for HnswError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            HnswError::DimensionMismatch { expected, got } => {
-                write!(f, "Dimension mismatch: expected {}, got {}", expected, got)
-            }
-            HnswError::CapacityExceeded => write!(f, "Index capacity exceeded"),
-            HnswError::DuplicateId(id) => write!(f, "Duplicate ID: {}", id),
-            HnswError::InvalidData => write!(f, "Invalid serialized data"),
+impl<'a> ArchivedHnswIndex<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<Self, HnswError> {
+        if bytes.len() < 39 {
+            return Err(HnswError::InvalidData);
         }
-    }
-}
 
-impl std::error::Error for HnswError {}
+        let mut pos = 0;
+        let dimension = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
+        pos += 4;
+        let m = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
+        pos += 4;
+        let ef_search = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
+        pos += 4;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut config = HnswConfig::default();
+        config.dimension = dimension;
+        config.m = m;
+        config.m0 = m * 2;
+        config.ef_search = ef_search;
 
-    #[test]
-    fn test_hnsw_insert_and_search() {
-        let mut index = HnswIndex::new(4);
+        let count = u64::from_le_bytes(bytes[pos..pos + 8].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
+        pos += 8;
+        let max_layer = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
+        pos += 4;
+        let entry_id = u64::from_le_bytes(bytes[pos..pos + 8].try_into().map_err(|_| HnswError::InvalidData)?);
+        pos += 8;
+        let has_entry = bytes[pos] == 1;
+        pos += 1;
+        config.metric = HnswDistance::from_byte(bytes[pos]);
+        pos += 1;
+        config.quantize = bytes[pos] == 1;
+        pos += 1;
 
-        index.insert(1, vec![1.0, 0.0, 0.0, 0.0]).unwrap();
-        index.insert(2, vec![0.0, 1.0, 0.0, 0.0]).unwrap();
-        index.insert(3, vec![0.0, 0.0, 1.0, 0.0]).unwrap();
-        index.insert(4, vec![0.5, 0.5, 0.0, 0.0]).unwrap();
+        let mut offsets = HashMap::with_capacity(count);
+        for _ in 0..count {
+            if pos + 12 > bytes.len() {
+                return Err(HnswError::InvalidData);
+            }
+            let id = u64::from_le_bytes(bytes[pos..pos + 8].try_into().map_err(|_| HnswError::InvalidData)?);
+            let node_start = pos;
+            pos += 8;
+            let node_max_layer = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
+            pos += 4;
 
-        assert_eq!(index.len(), 4);
+            pos += if config.quantize { 8 + dimension } else { dimension * 4 };
+            if pos > bytes.len() {
+                return Err(HnswError::InvalidData);
+            }
 
-        let results = index.search(&[1.0, 0.0, 0.0, 0.0], 2).unwrap();
+            for _ in 0..=node_max_layer {
+                if pos + 4 > bytes.len() {
+                    return Err(HnswError::InvalidData);
+                }
+                let conn_len = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| HnswError::InvalidData)?) as usize;
+                pos += 4 + conn_len * 8;
+                if pos > bytes.len() {
+                    return Err(HnswError::InvalidData);
+                }
+            }
 
-        assert!(!results.is_empty());
-        assert_eq!(results[0].0, 1);
-        assert!(results[0].1 < 0.01);
+            offsets.insert(id, node_start);
+        }
+
+        Ok(Self {
+            bytes,
+            config,
+            entry_point: if has_entry { Some(entry_id) } else { None },
+            max_layer,
+            count,
+            offsets,
+        })
     }
 
-    #[test]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Decode one node's `(vector, connections)` directly from `self.bytes`
+    /// at `offset` — the only per-query heap allocation this view does, and
+    /// only for nodes actually visited by a traversal.
+    fn decode_node(&self, offset: usize) -> Option<(Vec<f32>, Vec<Vec<u64>>)> {
+        let bytes = self.bytes;
+        let mut pos = offset + 8; // skip id, already known by the caller
+        let node_max_layer = u32::from_le_bytes(bytes.get(pos..pos + 4)?.try_into().ok()?) as usize;
+        pos += 4;
+
+        let vector = if self.config.quantize {
+            let min = f32::from_le_bytes(bytes.get(pos..pos + 4)?.try_into().ok()?);
+            pos += 4;
+            let max = f32::from_le_bytes(bytes.get(pos..pos + 4)?.try_into().ok()?);
+            pos += 4;
+            let codes = bytes.get(pos..pos + self.config.dimension)?.to_vec();
+            pos += self.config.dimension;
+            dequantize_vector(&ScalarQuantization { codes, min, max })
+        } else {
+            let mut vector = Vec::with_capacity(self.config.dimension);
+            for _ in 0..self.config.dimension {
+                vector.push(f32::from_le_bytes(bytes.get(pos..pos + 4)?.try_into().ok()?));
+                pos += 4;
+            }
+            vector
+        };
+
+        let mut connections = Vec::with_capacity(node_max_layer + 1);
+        for _ in 0..=node_max_layer {
+            let conn_len = u32::from_le_bytes(bytes.get(pos..pos + 4)?.try_into().ok()?) as usize;
+            pos += 4;
+            let mut layer_conns = Vec::with_capacity(conn_len);
+            for _ in 0..conn_len {
+                layer_conns.push(u64::from_le_bytes(bytes.get(pos..pos + 8)?.try_into().ok()?));
+                pos += 8;
+            }
+            connections.push(layer_conns);
+        }
+
+        Some((vector, connections))
+    }
+
+    fn distance_to_node(&self, query: &[f32], query_norm: f32, id: u64) -> f32 {
+        let Some(&offset) = self.offsets.get(&id) else { return f32::MAX };
+        let Some((vector, _)) = self.decode_node(offset) else { return f32::MAX };
+        let norm = HnswIndex::vector_norm(&vector);
+        distance_with_metric(self.config.metric, query, query_norm, &vector, norm)
+    }
+
+    fn search_layer_greedy(&self, query: &[f32], query_norm: f32, entry_id: u64, layer: usize) -> u64 {
+        let mut current_id = entry_id;
+        let mut current_dist = self.distance_to_node(query, query_norm, current_id);
+
+        loop {
+            let Some(&offset) = self.offsets.get(&current_id) else { break };
+            let Some((_, connections)) = self.decode_node(offset) else { break };
+
+            let mut changed = false;
+            if layer < connections.len() {
+                for &neighbor_id in &connections[layer] {
+                    let dist = self.distance_to_node(query, query_norm, neighbor_id);
+                    if dist < current_dist {
+                        current_id = neighbor_id;
+                        current_dist = dist;
+                        changed = true;
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        current_id
+    }
+
+    /// Read-only nearest-neighbor search, decoding only the nodes visited
+    /// during traversal rather than the whole archived graph.
+    pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(u64, f32)>, HnswError> {
+        if query.len() != self.config.dimension {
+            return Err(HnswError::DimensionMismatch { expected: self.config.dimension, got: query.len() });
+        }
+
+        let Some(entry_id) = self.entry_point else { return Ok(Vec::new()) };
+        let query_norm = HnswIndex::vector_norm(query);
+        let mut current_id = entry_id;
+
+        for layer in (1..=self.max_layer).rev() {
+            current_id = self.search_layer_greedy(query, query_norm, current_id, layer);
+        }
+
+        let ef = self.config.ef_search.max(k);
+        let mut visited = HashSet::new();
+        let mut candidates: BinaryHeap<Candidate> = BinaryHeap::new();
+        let mut results: BinaryHeap<MaxCandidate> = BinaryHeap::new();
+
+        let entry_dist = self.distance_to_node(query, query_norm, current_id);
+        visited.insert(current_id);
+        candidates.push(Candidate { id: current_id, distance: entry_dist });
+        results.push(MaxCandidate { id: current_id, distance: entry_dist });
+
+        while let Some(current) = candidates.pop() {
+            let furthest_dist = results.peek().map(|c| c.distance).unwrap_or(f32::MAX);
+            if current.distance > furthest_dist {
+                break;
+            }
+
+            let Some(&offset) = self.offsets.get(&current.id) else { continue };
+            let Some((_, connections)) = self.decode_node(offset) else { continue };
+            if connections.is_empty() {
+                continue;
+            }
+            for &neighbor_id in &connections[0] {
+                if !visited.insert(neighbor_id) {
+                    continue;
+                }
+                let dist = self.distance_to_node(query, query_norm, neighbor_id);
+                let furthest_dist = results.peek().map(|c| c.distance).unwrap_or(f32::MAX);
+                if dist < furthest_dist || results.len() < ef {
+                    candidates.push(Candidate { id: neighbor_id, distance: dist });
+                    results.push(MaxCandidate { id: neighbor_id, distance: dist });
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+
+        let mut sorted: Vec<(u64, f32)> = results.into_iter().map(|c| (c.id, c.distance)).collect();
+        sorted.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+        sorted.truncate(k);
+        Ok(sorted)
+    }
+}
+
+/// Delta record tag: this node is unchanged from the base index; only its
+/// id is carried so the decoder can keep the existing entry as-is.
+const DELTA_RECORD_COPY: u8 = 0;
+/// Delta record tag: this node is new or modified; its full body follows.
+const DELTA_RECORD_WRITE: u8 = 1;
+
+/// Domain separator for Merkle leaf hashes, mirroring the second-preimage
+/// protection in `luxtensor_crypto::merkle::MerkleTree`: without distinct
+/// prefixes, a crafted leaf could collide with an internal node hash.
+const MERKLE_LEAF_PREFIX: u8 = 0x00;
+/// Domain separator for Merkle internal-node hashes.
+const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+/// Hash a single `(id, vector)` pair into a Merkle leaf.
+fn merkle_leaf_hash(id: u64, vector: &[f32]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(1 + 8 + vector.len() * 4);
+    data.push(MERKLE_LEAF_PREFIX);
+    data.extend_from_slice(&id.to_le_bytes());
+    for v in vector {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    luxtensor_crypto::keccak256(&data)
+}
+
+/// Combine two child hashes into a parent hash. The pair is sorted before
+/// hashing so a proof doesn't need to carry left/right position bits —
+/// `verify_proof` can replay it with nothing but the sibling hashes.
+fn merkle_hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(1 + 64);
+    data.push(MERKLE_NODE_PREFIX);
+    if a <= b {
+        data.extend_from_slice(a);
+        data.extend_from_slice(b);
+    } else {
+        data.extend_from_slice(b);
+        data.extend_from_slice(a);
+    }
+    luxtensor_crypto::keccak256(&data)
+}
+
+/// Build every level of a binary Merkle tree bottom-up from `leaves`.
+/// `levels[0]` is the leaves themselves and `levels.last()` is the
+/// single-element root level. An odd node at a level is paired with
+/// itself rather than carried up unhashed, so it can't be mistaken for an
+/// already-combined node one level up.
+fn merkle_levels(leaves: Vec<[u8; 32]>) -> Vec<Vec<[u8; 32]>> {
+    if leaves.is_empty() {
+        return vec![vec![[0u8; 32]]];
+    }
+
+    let mut levels = vec![leaves];
+    while levels.last().unwrap().len() > 1 {
+        let current = levels.last().unwrap();
+        let mut next = Vec::with_capacity(current.len().div_ceil(2));
+        for chunk in current.chunks(2) {
+            let hash = if chunk.len() == 2 {
+                merkle_hash_pair(&chunk[0], &chunk[1])
+            } else {
+                merkle_hash_pair(&chunk[0], &chunk[0])
+            };
+            next.push(hash);
+        }
+        levels.push(next);
+    }
+    levels
+}
+
+/// Walk `levels` bottom-up from `index`, collecting the sibling hash at
+/// each level — the inclusion proof for the leaf at `index`.
+fn merkle_proof_path(levels: &[Vec<[u8; 32]>], mut index: usize) -> Vec<[u8; 32]> {
+    let mut proof = Vec::with_capacity(levels.len().saturating_sub(1));
+    for level in &levels[..levels.len() - 1] {
+        let sibling_index = if index % 2 == 0 { index + 1 } else { index - 1 };
+        let sibling = *level.get(sibling_index).unwrap_or(&level[index]);
+        proof.push(sibling);
+        index /= 2;
+    }
+    proof
+}
+
+/// Verify a Merkle inclusion proof produced by `HnswVectorStore::proof`
+/// against a previously committed `root_hash()`. A light client or
+/// cross-contract caller needs only `root`, `id`, `vector`, and `proof` —
+/// not the rest of the index — to check membership in O(log N).
+pub fn verify_proof(root: [u8; 32], id: u64, vector: &[f32], proof: &[[u8; 32]]) -> bool {
+    let mut hash = merkle_leaf_hash(id, vector);
+    for sibling in proof {
+        hash = merkle_hash_pair(&hash, sibling);
+    }
+    hash == root
+}
+
+/// A single attribute value in a vector's metadata map. Kept as a small
+/// closed enum (rather than an arbitrary JSON value) so metadata stays
+/// cheap to store and compare on-chain.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetaValue {
+    Text(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+/// Per-vector attribute map, evaluated against a predicate during
+/// `search_filtered_meta` without pulling the rest of the index's metadata.
+pub type Meta = HashMap<String, MetaValue>;
+
+/// HNSW-backed vector store for precompile integration
+pub struct HnswVectorStore {
+    index: HnswIndex,
+    /// Attribute maps for ids inserted via `insert_with_meta`. Kept
+    /// alongside the index rather than inside `HnswIndex` itself — the
+    /// graph stays metadata-agnostic, and filtering just closes over this
+    /// map on top of the existing id-based `search_filtered`.
+    meta: HashMap<u64, Meta>,
+}
+
+impl HnswVectorStore {
+    pub fn new(dimension: usize) -> Self {
+        Self { index: HnswIndex::new(dimension), meta: HashMap::new() }
+    }
+
+    /// Create a store with a custom `HnswConfig`, e.g. to select a
+    /// non-default `HnswDistance` metric.
+    pub fn with_config(config: HnswConfig) -> Self {
+        Self { index: HnswIndex::with_config(config), meta: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, id: u64, vector: Vec<f32>) -> Result<(), HnswError> {
+        self.index.insert(id, vector)
+    }
+
+    /// Insert a vector along with an attribute map that `search_filtered_meta`
+    /// can later filter on.
+    pub fn insert_with_meta(&mut self, id: u64, vector: Vec<f32>, meta: Meta) -> Result<(), HnswError> {
+        self.index.insert(id, vector)?;
+        self.meta.insert(id, meta);
+        Ok(())
+    }
+
+    /// The attribute map stored for `id` via `insert_with_meta`, if any.
+    pub fn get_meta(&self, id: u64) -> Option<&Meta> {
+        self.meta.get(&id)
+    }
+
+    pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(u64, f32)>, HnswError> {
+        self.index.search(query, k)
+    }
+
+    /// Nearest-neighbor search restricted to ids whose attribute map passes
+    /// `predicate`. Built on the same ef-widening traversal as
+    /// `search_filtered`: filtered-out nodes still act as routing hops, only
+    /// the result heap is restricted, and `ef` widens until `k` passing
+    /// results are found or the graph is exhausted. An id with no stored
+    /// metadata never passes the filter.
+    pub fn search_filtered_meta(
+        &self,
+        query: &[f32],
+        k: usize,
+        predicate: impl Fn(&Meta) -> bool,
+    ) -> Result<Vec<(u64, f32)>, HnswError> {
+        let id_predicate = |id: u64| self.meta.get(&id).is_some_and(&predicate);
+        self.index.search_filtered(query, k, &id_predicate)
+    }
+
+    /// Nearest-neighbor search restricted to ids matching `predicate`, e.g.
+    /// "nearest vector owned by address X" without returning (or a caller
+    /// having to pull and re-filter) the unrestricted neighbor set.
+    pub fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        predicate: impl Fn(u64) -> bool,
+    ) -> Result<Vec<(u64, f32)>, HnswError> {
+        self.index.search_filtered(query, k, &predicate)
+    }
+
+    /// Fuse the vector index's own ranking with an externally supplied
+    /// lexical/metadata ranking via Reciprocal Rank Fusion, so callers can
+    /// combine ANN search with any keyword/attribute signal without this
+    /// store needing to know how `lexical_scores` was produced.
+    ///
+    /// `semantic_ratio` (0.0-1.0) weights the vector ranking against the
+    /// lexical one; `RRF(id) = semantic_ratio / (c + rank_vec(id)) +
+    /// (1 - semantic_ratio) / (c + rank_lex(id))`, with `c = 60` (the
+    /// standard RRF constant) and a missing rank in either list treated as
+    /// contributing 0.
+    pub fn hybrid_search(
+        &self,
+        query_vec: &[f32],
+        lexical_scores: &[(u64, f32)],
+        k: usize,
+        semantic_ratio: f32,
+    ) -> Result<Vec<(u64, f32)>, HnswError> {
+        const RRF_CONSTANT: f32 = 60.0;
+
+        // Over-fetch from the vector ranking so fusion has a wide enough
+        // pool to work with beyond just the final top-k.
+        let pool_size = (k.max(lexical_scores.len())).saturating_mul(4).max(k);
+        let vector_ranked = self.index.search(query_vec, pool_size)?;
+
+        let vector_rank: HashMap<u64, usize> =
+            vector_ranked.iter().enumerate().map(|(rank, (id, _))| (*id, rank)).collect();
+
+        let mut lexical_sorted: Vec<(u64, f32)> = lexical_scores.to_vec();
+        lexical_sorted.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        let lexical_rank: HashMap<u64, usize> =
+            lexical_sorted.iter().enumerate().map(|(rank, (id, _))| (*id, rank)).collect();
+
+        let mut candidate_ids: HashSet<u64> = vector_rank.keys().copied().collect();
+        candidate_ids.extend(lexical_rank.keys().copied());
+
+        let mut fused: Vec<(u64, f32)> = candidate_ids
+            .into_iter()
+            .map(|id| {
+                let vec_term = vector_rank
+                    .get(&id)
+                    .map(|&rank| semantic_ratio / (RRF_CONSTANT + rank as f32))
+                    .unwrap_or(0.0);
+                let lex_term = lexical_rank
+                    .get(&id)
+                    .map(|&rank| (1.0 - semantic_ratio) / (RRF_CONSTANT + rank as f32))
+                    .unwrap_or(0.0);
+                (id, vec_term + lex_term)
+            })
+            .collect();
+
+        fused.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        fused.truncate(k);
+        Ok(fused)
+    }
+
+    /// Remove a stored vector. See `HnswIndex::remove` for the neighbor
+    /// repair semantics.
+    pub fn remove(&mut self, id: u64) -> Result<(), HnswError> {
+        self.index.remove(id)?;
+        self.meta.remove(&id);
+        Ok(())
+    }
+
+    /// Replace a stored vector's embedding in place. See
+    /// `HnswIndex::update` for how connectivity is repaired.
+    pub fn update(&mut self, id: u64, new_vector: Vec<f32>) -> Result<(), HnswError> {
+        self.index.update(id, new_vector)
+    }
+
+    /// Tombstone a stored vector without repairing its neighbors. See
+    /// `HnswIndex::soft_delete`.
+    pub fn soft_delete(&mut self, id: u64) -> Result<(), HnswError> {
+        self.index.soft_delete(id)
+    }
+
+    pub fn is_deleted(&self, id: u64) -> bool {
+        self.index.is_deleted(id)
+    }
+
+    /// Live (non-tombstoned) vector count. See `HnswIndex::live_count`.
+    pub fn live_count(&self) -> usize {
+        self.index.live_count()
+    }
+
+    /// Rebuild from surviving vectors if tombstones exceed
+    /// `max_deleted_ratio`. See `HnswIndex::compact`.
+    pub fn compact(&mut self, max_deleted_ratio: f32) -> bool {
+        let rebuilt = self.index.compact(max_deleted_ratio);
+        if rebuilt {
+            let live_ids: HashSet<u64> = self.index.nodes.keys().copied().collect();
+            self.meta.retain(|id, _| live_ids.contains(id));
+        }
+        rebuilt
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    #[allow(dead_code)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.index.to_bytes()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HnswError> {
+        Ok(Self { index: HnswIndex::from_bytes(bytes)?, meta: HashMap::new() })
+    }
+
+    /// Calculate Merkle root hash for consensus verification
+    /// Produces a deterministic 32-byte hash from all stored vectors
+    pub fn root_hash(&self) -> [u8; 32] {
+        self.index.root_hash()
+    }
+
+    /// Merkle inclusion proof that `id`'s stored vector is part of the
+    /// dataset committed to by `root_hash()`. `None` if `id` isn't present.
+    /// Verify with the free `verify_proof` function, which needs only the
+    /// root, the id, the vector, and this proof — not the rest of the index.
+    pub fn proof(&self, id: u64) -> Option<Vec<[u8; 32]>> {
+        let mut ids: Vec<u64> = self.index.nodes.keys().copied().collect();
+        ids.sort();
+        let index = ids.binary_search(&id).ok()?;
+
+        let leaves: Vec<[u8; 32]> = ids
+            .iter()
+            .map(|i| merkle_leaf_hash(*i, &self.index.nodes[i].vector))
+            .collect();
+
+        Some(merkle_proof_path(&merkle_levels(leaves), index))
+    }
+
+    /// Diff-encode this store against `prev` — see `HnswIndex::to_bytes_delta`.
+    /// Intended for incremental on-chain commits: only nodes whose vector or
+    /// adjacency changed since `prev` are written out in full.
+    pub fn to_bytes_delta(&self, prev: &HnswVectorStore) -> Vec<u8> {
+        self.index.to_bytes_delta(&prev.index)
+    }
+
+    /// Reconstruct the store a delta was diffed against `self` into — see
+    /// `HnswIndex::apply_delta`. Metadata isn't part of the delta encoding
+    /// (it diffs the vector graph only), so surviving ids keep `self`'s
+    /// metadata and ids removed by the delta drop theirs with them.
+    pub fn apply_delta(&self, delta: &[u8]) -> Result<HnswVectorStore, HnswError> {
+        let index = self.index.apply_delta(delta)?;
+        let meta = self.meta.iter().filter(|(id, _)| index.nodes.contains_key(id)).map(|(k, v)| (*k, v.clone())).collect();
+        Ok(HnswVectorStore { index, meta })
+    }
+
+    // ==================== AI Primitives ====================
+
+    /// Classify a vector against labeled reference vectors.
+    /// Returns the label of the closest match and confidence score.
+    ///
+    /// # Arguments
+    /// * `query` - The vector to classify
+    /// * `labels` - List of (vector_id, label) pairs representing categories
+    ///
+    /// # Returns
+    /// * `(label, confidence)` where confidence is 1.0 - normalized_distance
+    pub fn classify(&self, query: &[f32], labels: &[(u64, u32)]) -> Result<(u32, f32), HnswError> {
+        if labels.is_empty() {
+            return Err(HnswError::InvalidData);
+        }
+
+        // Search for nearest neighbor among all stored vectors
+        let results = self.search(query, 1)?;
+
+        if results.is_empty() {
+            return Err(HnswError::InvalidData);
+        }
+
+        let (nearest_id, distance) = results[0];
+
+        // Find label for nearest vector
+        let label = labels.iter()
+            .find(|(id, _)| *id == nearest_id)
+            .map(|(_, l)| *l)
+            .unwrap_or(0);
+
+        // Convert distance to confidence (1.0 = exact match, 0.0 = very far),
+        // using the mapping appropriate for this index's configured metric.
+        let confidence = self.index.distance_to_confidence(distance);
+
+        Ok((label, confidence))
+    }
+
+    /// Calculate anomaly score for a vector relative to the stored vectors.
+    /// Higher score means more anomalous (further from all stored vectors).
+    ///
+    /// # Returns
+    /// * Score in range [0.0, 1.0] where 1.0 = highly anomalous
+    pub fn anomaly_score(&self, query: &[f32]) -> Result<f32, HnswError> {
+        if self.index.is_empty() {
+            return Ok(1.0); // No data = everything is anomalous
+        }
+
+        // Get k nearest neighbors to calculate average distance
+        let k = 5.min(self.index.len());
+        let results = self.search(query, k)?;
+
+        if results.is_empty() {
+            return Ok(1.0);
+        }
+
+        // Calculate average distance to nearest neighbors
+        let avg_distance: f32 = results.iter().map(|(_, d)| d).sum::<f32>() / results.len() as f32;
+
+        // Anomaly is the inverse of confidence: a query far from its nearest
+        // neighbors (low confidence under the configured metric) is anomalous.
+        let confidence = self.index.distance_to_confidence(avg_distance);
+
+        Ok((1.0 - confidence).clamp(0.0, 1.0))
+    }
+
+    /// Check if two vectors are semantically similar above a threshold.
+    ///
+    /// # Arguments
+    /// * `vector_a` - First vector
+    /// * `vector_b` - Second vector
+    /// * `threshold` - Similarity threshold (0.0 to 1.0)
+    ///
+    /// # Returns
+    /// * `(is_similar, similarity_score)`
+    pub fn similarity_check(&self, vector_a: &[f32], vector_b: &[f32], threshold: f32) -> Result<(bool, f32), HnswError> {
+        if vector_a.len() != self.index.config.dimension || vector_b.len() != self.index.config.dimension {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.index.config.dimension,
+                got: vector_a.len().min(vector_b.len()),
+            });
+        }
+
+        // Distance under the index's configured metric, converted to a
+        // [0, 1] similarity score (1.0 = identical, 0.0 = very different).
+        let distance = self.index.distance(vector_a, vector_b);
+        let similarity = self.index.distance_to_confidence(distance);
+        let is_similar = similarity >= threshold;
+
+        Ok((is_similar, similarity))
+    }
+
+    /// Get a vector by ID for cross-contract composability.
+    pub fn get_vector(&self, id: u64) -> Option<Vec<f32>> {
+        if self.index.deleted.contains(&id) {
+            return None;
+        }
+        self.index.nodes.get(&id).map(|node| node.vector.clone())
+    }
+
+    /// Get the dimension of vectors in this store.
+    pub fn dimension(&self) -> usize {
+        self.index.config.dimension
+    }
+}
+
+
+impl Default for HnswVectorStore {
+    fn default() -> Self {
+        Self::new(768) // Default to 768 dimensions (standard embedding size)
+    }
+}
+
+/// HNSW errors
+#[derive(Debug, Clone)]
+pub enum HnswError {
+    DimensionMismatch { expected: usize, got: usize },
+    CapacityExceeded,
+    DuplicateId(u64),
+    NodeNotFound(u64),
+    InvalidData,
+}
+
+impl std::fmt::Display for HnswError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HnswError::DimensionMismatch { expected, got } => {
+                write!(f, "Dimension mismatch: expected {}, got {}", expected, got)
+            }
+            HnswError::CapacityExceeded => write!(f, "Index capacity exceeded"),
+            HnswError::DuplicateId(id) => write!(f, "Duplicate ID: {}", id),
+            HnswError::NodeNotFound(id) => write!(f, "Node not found: {}", id),
+            HnswError::InvalidData => write!(f, "Invalid serialized data"),
+        }
+    }
+}
+
+impl std::error::Error for HnswError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hnsw_insert_and_search() {
+        let mut index = HnswIndex::new(4);
+
+        index.insert(1, vec![1.0, 0.0, 0.0, 0.0]).unwrap();
+        index.insert(2, vec![0.0, 1.0, 0.0, 0.0]).unwrap();
+        index.insert(3, vec![0.0, 0.0, 1.0, 0.0]).unwrap();
+        index.insert(4, vec![0.5, 0.5, 0.0, 0.0]).unwrap();
+
+        assert_eq!(index.len(), 4);
+
+        let results = index.search(&[1.0, 0.0, 0.0, 0.0], 2).unwrap();
+
+        assert!(!results.is_empty());
+        assert_eq!(results[0].0, 1);
+        assert!(results[0].1 < 0.01);
+    }
+
+    #[test]
     fn test_hnsw_serialization() {
         let mut index = HnswIndex::new(3);
 
-        index.insert(10, vec![1.0, 2.0, 3.0]).unwrap();
-        index.insert(20, vec![4.0, 5.0, 6.0]).unwrap();
+        index.insert(10, vec![1.0, 2.0, 3.0]).unwrap();
+        index.insert(20, vec![4.0, 5.0, 6.0]).unwrap();
+
+        let bytes = index.to_bytes();
+        let restored = HnswIndex::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.len(), 2);
+
+        let results = restored.search(&[1.0, 2.0, 3.0], 1).unwrap();
+        assert_eq!(results[0].0, 10);
+    }
+
+    #[test]
+    fn test_hnsw_duplicate_rejection() {
+        let mut index = HnswIndex::new(2);
+
+        index.insert(1, vec![1.0, 0.0]).unwrap();
+        let result = index.insert(1, vec![0.0, 1.0]);
+
+        assert!(matches!(result, Err(HnswError::DuplicateId(1))));
+    }
+
+    #[test]
+    fn test_hnsw_dimension_check() {
+        let mut index = HnswIndex::new(3);
+
+        let result = index.insert(1, vec![1.0, 2.0]);
+
+        assert!(matches!(result, Err(HnswError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_hnsw_vector_store() {
+        let mut store = HnswVectorStore::new(4);
+
+        store.insert(1, vec![1.0, 0.0, 0.0, 0.0]).unwrap();
+        store.insert(2, vec![0.9, 0.1, 0.0, 0.0]).unwrap();
+        store.insert(3, vec![0.0, 1.0, 0.0, 0.0]).unwrap();
+
+        let results = store.search(&[1.0, 0.0, 0.0, 0.0], 2).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, 1);
+    }
+
+    // ==================== AI Primitives Tests ====================
+
+    #[test]
+    fn test_classify() {
+        let mut store = HnswVectorStore::new(4);
+
+        // Create labeled categories
+        store.insert(1, vec![1.0, 0.0, 0.0, 0.0]).unwrap(); // Category A
+        store.insert(2, vec![0.0, 1.0, 0.0, 0.0]).unwrap(); // Category B
+        store.insert(3, vec![0.0, 0.0, 1.0, 0.0]).unwrap(); // Category C
+
+        let labels = vec![(1, 100), (2, 200), (3, 300)];
+
+        // Classify a vector close to category A
+        let query = vec![0.95, 0.05, 0.0, 0.0];
+        let (label, confidence) = store.classify(&query, &labels).unwrap();
+
+        assert_eq!(label, 100); // Should match category A
+        assert!(confidence > 0.5); // High confidence
+    }
+
+    #[test]
+    fn test_anomaly_score() {
+        let mut store = HnswVectorStore::new(4);
+
+        // Create a cluster of normal vectors
+        store.insert(1, vec![1.0, 0.0, 0.0, 0.0]).unwrap();
+        store.insert(2, vec![0.9, 0.1, 0.0, 0.0]).unwrap();
+        store.insert(3, vec![0.95, 0.05, 0.0, 0.0]).unwrap();
+
+        // Normal query (close to cluster)
+        let normal_score = store.anomaly_score(&[1.0, 0.0, 0.0, 0.0]).unwrap();
+
+        // Anomalous query (far from cluster)
+        let anomaly_score = store.anomaly_score(&[0.0, 0.0, 0.0, 1.0]).unwrap();
+
+        // Anomalous should have higher score
+        assert!(anomaly_score > normal_score);
+    }
+
+    #[test]
+    fn test_similarity_check() {
+        let store = HnswVectorStore::new(4);
+
+        // Similar vectors
+        let vec_a = vec![1.0, 0.0, 0.0, 0.0];
+        let vec_b = vec![0.95, 0.05, 0.0, 0.0];
+
+        let (is_similar, similarity) = store.similarity_check(&vec_a, &vec_b, 0.5).unwrap();
+        assert!(is_similar);
+        assert!(similarity > 0.5);
+
+        // Different vectors
+        let vec_c = vec![0.0, 0.0, 0.0, 1.0];
+        let (is_similar, _) = store.similarity_check(&vec_a, &vec_c, 0.9).unwrap();
+        assert!(!is_similar);
+    }
+
+    #[test]
+    fn test_get_vector() {
+        let mut store = HnswVectorStore::new(4);
+
+        let vector = vec![1.0, 2.0, 3.0, 4.0];
+        store.insert(42, vector.clone()).unwrap();
+
+        // Should find stored vector
+        let retrieved = store.get_vector(42);
+        assert!(retrieved.is_some());
+        assert_eq!(retrieved.unwrap(), vector);
+
+        // Should return None for unknown ID
+        let missing = store.get_vector(999);
+        assert!(missing.is_none());
+    }
+
+    #[test]
+    fn test_dimension() {
+        let store = HnswVectorStore::new(768);
+        assert_eq!(store.dimension(), 768);
+    }
+
+    #[test]
+    fn test_select_neighbors_diversity_heuristic_spreads_directions() {
+        let mut index = HnswIndex::new(2);
+
+        // Two candidates clustered together near the query, one further away
+        // but in an unrepresented direction. Naive "closest M" would pick
+        // both clustered candidates; the diversity heuristic should prefer
+        // spreading across directions once the first is selected.
+        let candidates = vec![
+            (1u64, 1.0f32), // close to query
+            (2u64, 1.1f32), // close to query AND close to candidate 1
+            (3u64, 4.0f32), // further from query, but a distinct direction
+        ];
+
+        index.nodes.insert(1, HnswNode { id: 1, norm: 1.0, vector: vec![1.0, 0.0], connections: vec![Vec::new()], max_layer: 0, quantization: None });
+        index.nodes.insert(2, HnswNode { id: 2, norm: (1.05f32 * 1.05 + 0.05 * 0.05).sqrt(), vector: vec![1.05, 0.05], connections: vec![Vec::new()], max_layer: 0, quantization: None });
+        index.nodes.insert(3, HnswNode { id: 3, norm: 2.0, vector: vec![-2.0, 0.0], connections: vec![Vec::new()], max_layer: 0, quantization: None });
+
+        let query = vec![0.0, 0.0];
+        let selected = index.select_neighbors(&query, &candidates, 2);
+
+        assert_eq!(selected.len(), 2);
+        assert!(selected.contains(&1), "closest candidate should always be selected");
+        assert!(
+            selected.contains(&3),
+            "heuristic should prefer the distinct direction over the redundant close neighbor"
+        );
+        assert!(!selected.contains(&2), "candidate 2 is dominated by candidate 1");
+    }
+
+    #[test]
+    fn test_select_neighbors_backfills_when_extend_candidates_set() {
+        let mut config = HnswConfig::default();
+        config.dimension = 2;
+        config.extend_candidates = true;
+        let mut index = HnswIndex::with_config(config);
+
+        index.nodes.insert(1, HnswNode { id: 1, norm: 1.0, vector: vec![1.0, 0.0], connections: vec![Vec::new()], max_layer: 0, quantization: None });
+        index.nodes.insert(2, HnswNode { id: 2, norm: (1.05f32 * 1.05 + 0.05 * 0.05).sqrt(), vector: vec![1.05, 0.05], connections: vec![Vec::new()], max_layer: 0, quantization: None });
+
+        // Both candidates are mutually redundant (no distinct direction), so
+        // the pure diversity pass would only keep one. With extend_candidates
+        // on, the second should be backfilled to satisfy m=2.
+        let candidates = vec![(1u64, 1.0f32), (2u64, 1.1f32)];
+        let query = vec![0.0, 0.0];
+        let selected = index.select_neighbors(&query, &candidates, 2);
+
+        assert_eq!(selected.len(), 2);
+    }
+
+    #[test]
+    fn test_insert_still_connects_nodes_with_diversity_heuristic() {
+        // Regression check: the heuristic must not break ordinary insert/search
+        // behavior for well-separated points.
+        let mut index = HnswIndex::new(2);
+
+        for i in 0..20 {
+            index.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        assert_eq!(index.len(), 20);
+        let results = index.search(&[0.0, 0.0], 3).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, 0);
+    }
+
+    // ==================== Pluggable Distance Metrics Tests ====================
+
+    #[test]
+    fn test_new_with_metric_matches_with_config() {
+        let via_constructor = HnswIndex::new_with_metric(2, HnswDistance::Cosine);
+
+        let mut config = HnswConfig::default();
+        config.dimension = 2;
+        config.metric = HnswDistance::Cosine;
+        let via_config = HnswIndex::with_config(config);
+
+        assert_eq!(via_constructor.config.metric.to_byte(), via_config.config.metric.to_byte());
+        assert_eq!(via_constructor.config.dimension, via_config.config.dimension);
+    }
+
+    #[test]
+    fn test_cosine_distance_ignores_magnitude() {
+        let mut config = HnswConfig::default();
+        config.dimension = 2;
+        config.metric = HnswDistance::Cosine;
+        let mut index = HnswIndex::with_config(config);
+
+        index.insert(1, vec![1.0, 0.0]).unwrap();
+        index.insert(2, vec![5.0, 0.0]).unwrap(); // same direction, larger magnitude
+        index.insert(3, vec![0.0, 1.0]).unwrap(); // orthogonal
+
+        let results = index.search(&[2.0, 0.0], 3).unwrap();
+
+        // Node 2 has a much larger L2 distance than node 3 to [2,0], but under
+        // cosine both node 1 and node 2 are a perfect direction match (distance
+        // ~0), so they must rank ahead of the orthogonal node 3.
+        assert_eq!(results[0].1, results[1].1, "nodes 1 and 2 share the same cosine distance");
+        assert!(results[2].0 == 3, "orthogonal node should rank last under cosine");
+    }
+
+    #[test]
+    fn test_inner_product_distance_prefers_aligned_large_vectors() {
+        let mut config = HnswConfig::default();
+        config.dimension = 2;
+        config.metric = HnswDistance::InnerProduct;
+        let mut index = HnswIndex::with_config(config);
+
+        index.insert(1, vec![1.0, 0.0]).unwrap();
+        index.insert(2, vec![5.0, 0.0]).unwrap();
+
+        let results = index.search(&[1.0, 0.0], 2).unwrap();
+
+        // Negated dot product: node 2 has the most negative (best) distance.
+        assert_eq!(results[0].0, 2);
+    }
+
+    #[test]
+    fn test_manhattan_distance_matches_l1_sum() {
+        let mut config = HnswConfig::default();
+        config.dimension = 3;
+        config.metric = HnswDistance::Manhattan;
+        let index = HnswIndex::with_config(config);
+
+        let d = index.distance(&[0.0, 0.0, 0.0], &[1.0, -2.0, 3.0]);
+        assert!((d - 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_metric_round_trips_through_serialization() {
+        let mut config = HnswConfig::default();
+        config.dimension = 2;
+        config.metric = HnswDistance::Cosine;
+        let mut index = HnswIndex::with_config(config);
+
+        index.insert(1, vec![1.0, 0.0]).unwrap();
+        index.insert(2, vec![0.0, 1.0]).unwrap();
+
+        let bytes = index.to_bytes();
+        let restored = HnswIndex::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.config.metric, HnswDistance::Cosine);
+        // Searching the restored index should reproduce the same ordering.
+        assert_eq!(
+            index.search(&[1.0, 0.1], 2).unwrap(),
+            restored.search(&[1.0, 0.1], 2).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_similarity_check_uses_configured_metric() {
+        let mut config = HnswConfig::default();
+        config.dimension = 2;
+        config.metric = HnswDistance::Cosine;
+        let store = HnswVectorStore::with_config(config);
+
+        // Same direction, different magnitude: cosine similarity is ~1.0
+        // even though Euclidean distance between them is large.
+        let (is_similar, similarity) = store
+            .similarity_check(&[1.0, 0.0], &[10.0, 0.0], 0.99)
+            .unwrap();
+        assert!(is_similar);
+        assert!(similarity > 0.99);
+    }
+
+    // ==================== Filtered Search Tests ====================
+
+    #[test]
+    fn test_search_filtered_only_returns_matching_ids() {
+        let mut store = HnswVectorStore::new(2);
+
+        for i in 0..30u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        // Only even ids are "owned by address X"
+        let results = store.search_filtered(&[0.0, 0.0], 5, |id| id % 2 == 0).unwrap();
+
+        assert_eq!(results.len(), 5);
+        assert!(results.iter().all(|(id, _)| id % 2 == 0));
+        // Nearest even ids to the origin are 0, 2, 4, 6, 8.
+        let ids: Vec<u64> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_search_filtered_widens_ef_for_selective_predicate() {
+        let mut store = HnswVectorStore::new(2);
+
+        for i in 0..200u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        // Only one id in the whole index matches — well outside a default
+        // ef_search window from the origin — but it must still be found.
+        let results = store.search_filtered(&[0.0, 0.0], 3, |id| id == 150).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, 150);
+    }
+
+    #[test]
+    fn test_search_filtered_returns_empty_when_nothing_matches() {
+        let mut store = HnswVectorStore::new(2);
+
+        for i in 0..10u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let results = store.search_filtered(&[0.0, 0.0], 5, |_| false).unwrap();
+        assert!(results.is_empty());
+    }
+
+    // ==================== Deletion / Update Tests ====================
+
+    #[test]
+    fn test_remove_drops_node_and_updates_count() {
+        let mut store = HnswVectorStore::new(2);
+
+        for i in 0..20u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        assert_eq!(store.len(), 20);
+        store.remove(5).unwrap();
+        assert_eq!(store.len(), 19);
+
+        let results = store.search(&[5.0, 0.0], 20).unwrap();
+        assert!(results.iter().all(|(id, _)| *id != 5));
+    }
+
+    #[test]
+    fn test_remove_unknown_id_errors() {
+        let mut store = HnswVectorStore::new(2);
+        store.insert(1, vec![1.0, 0.0]).unwrap();
+
+        let err = store.remove(999).unwrap_err();
+        assert!(matches!(err, HnswError::NodeNotFound(999)));
+    }
+
+    #[test]
+    fn test_remove_preserves_connectivity_for_remaining_nodes() {
+        let mut store = HnswVectorStore::new(2);
+
+        for i in 0..50u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        // Remove a run of nodes clustered around the query point and make
+        // sure the survivors are still reachable via search.
+        for i in 20..30u64 {
+            store.remove(i).unwrap();
+        }
 
-        let bytes = index.to_bytes();
-        let restored = HnswIndex::from_bytes(&bytes).unwrap();
+        let results = store.search(&[25.0, 0.0], 5).unwrap();
+        assert_eq!(results.len(), 5);
+        assert!(results.iter().all(|(id, _)| !(20..30).contains(id)));
+    }
 
-        assert_eq!(restored.len(), 2);
+    #[test]
+    fn test_remove_entry_point_promotes_new_one() {
+        let mut store = HnswVectorStore::new(2);
 
-        let results = restored.search(&[1.0, 2.0, 3.0], 1).unwrap();
-        assert_eq!(results[0].0, 10);
+        for i in 0..30u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let entry_before = store.index.entry_point.unwrap();
+        store.remove(entry_before).unwrap();
+
+        let entry_after = store.index.entry_point.unwrap();
+        assert_ne!(entry_after, entry_before);
+        assert!(store.index.nodes.contains_key(&entry_after));
+
+        // Index stays fully searchable after the entry point moves.
+        let results = store.search(&[0.0, 0.0], 5).unwrap();
+        assert_eq!(results.len(), 5);
     }
 
     #[test]
-    fn test_hnsw_duplicate_rejection() {
-        let mut index = HnswIndex::new(2);
+    fn test_update_replaces_vector_and_is_found_at_new_location() {
+        let mut store = HnswVectorStore::new(2);
 
-        index.insert(1, vec![1.0, 0.0]).unwrap();
-        let result = index.insert(1, vec![0.0, 1.0]);
+        for i in 0..20u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
 
-        assert!(matches!(result, Err(HnswError::DuplicateId(1))));
+        store.update(0, vec![100.0, 0.0]).unwrap();
+
+        // id 0 should no longer be the nearest neighbor to the origin...
+        let near_origin = store.search(&[0.0, 0.0], 1).unwrap();
+        assert_ne!(near_origin[0].0, 0);
+
+        // ...but should be found near its new location instead.
+        let near_new = store.search(&[100.0, 0.0], 1).unwrap();
+        assert_eq!(near_new[0].0, 0);
     }
 
     #[test]
-    fn test_hnsw_dimension_check() {
-        let mut index = HnswIndex::new(3);
+    fn test_update_unknown_id_errors() {
+        let mut store = HnswVectorStore::new(2);
+        store.insert(1, vec![1.0, 0.0]).unwrap();
 
-        let result = index.insert(1, vec![1.0, 2.0]);
+        let err = store.update(999, vec![0.0, 0.0]).unwrap_err();
+        assert!(matches!(err, HnswError::NodeNotFound(999)));
+    }
 
-        assert!(matches!(result, Err(HnswError::DimensionMismatch { .. })));
+    #[test]
+    fn test_update_rejects_dimension_mismatch() {
+        let mut store = HnswVectorStore::new(2);
+        store.insert(1, vec![1.0, 0.0]).unwrap();
+
+        let err = store.update(1, vec![0.0, 0.0, 0.0]).unwrap_err();
+        assert!(matches!(err, HnswError::DimensionMismatch { expected: 2, got: 3 }));
     }
 
     #[test]
-    fn test_hnsw_vector_store() {
-        let mut store = HnswVectorStore::new(4);
+    fn test_index_stays_consistent_after_interleaved_remove_and_update() {
+        let mut store = HnswVectorStore::new(2);
 
-        store.insert(1, vec![1.0, 0.0, 0.0, 0.0]).unwrap();
-        store.insert(2, vec![0.9, 0.1, 0.0, 0.0]).unwrap();
-        store.insert(3, vec![0.0, 1.0, 0.0, 0.0]).unwrap();
+        for i in 0..40u64 {
+            store.insert(i, vec![i as f32, i as f32 * 0.5]).unwrap();
+        }
 
-        let results = store.search(&[1.0, 0.0, 0.0, 0.0], 2).unwrap();
+        for i in (0..40u64).step_by(3) {
+            store.remove(i).unwrap();
+        }
+        for i in (1..40u64).step_by(4) {
+            if store.index.nodes.contains_key(&i) {
+                store.update(i, vec![i as f32 + 0.5, 0.0]).unwrap();
+            }
+        }
 
-        assert_eq!(results.len(), 2);
-        assert_eq!(results[0].0, 1);
+        assert_eq!(store.len(), store.index.nodes.len());
+
+        // Round-tripping through the serialized form must still work.
+        let bytes = store.to_bytes();
+        let restored = HnswVectorStore::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.len(), store.len());
     }
 
-    // ==================== AI Primitives Tests ====================
+    // ==================== Merkle Proof Tests ====================
 
     #[test]
-    fn test_classify() {
-        let mut store = HnswVectorStore::new(4);
+    fn test_root_hash_empty_store_is_zero() {
+        let store = HnswVectorStore::new(2);
+        assert_eq!(store.root_hash(), [0u8; 32]);
+    }
 
-        // Create labeled categories
-        store.insert(1, vec![1.0, 0.0, 0.0, 0.0]).unwrap(); // Category A
-        store.insert(2, vec![0.0, 1.0, 0.0, 0.0]).unwrap(); // Category B
-        store.insert(3, vec![0.0, 0.0, 1.0, 0.0]).unwrap(); // Category C
+    #[test]
+    fn test_root_hash_changes_with_content() {
+        let mut store = HnswVectorStore::new(2);
+        store.insert(1, vec![1.0, 2.0]).unwrap();
+        let root_a = store.root_hash();
 
-        let labels = vec![(1, 100), (2, 200), (3, 300)];
+        store.insert(2, vec![3.0, 4.0]).unwrap();
+        let root_b = store.root_hash();
 
-        // Classify a vector close to category A
-        let query = vec![0.95, 0.05, 0.0, 0.0];
-        let (label, confidence) = store.classify(&query, &labels).unwrap();
+        assert_ne!(root_a, root_b);
+    }
 
-        assert_eq!(label, 100); // Should match category A
-        assert!(confidence > 0.5); // High confidence
+    #[test]
+    fn test_root_hash_independent_of_insertion_order() {
+        let mut store_a = HnswVectorStore::new(2);
+        store_a.insert(1, vec![1.0, 2.0]).unwrap();
+        store_a.insert(2, vec![3.0, 4.0]).unwrap();
+        store_a.insert(3, vec![5.0, 6.0]).unwrap();
+
+        let mut store_b = HnswVectorStore::new(2);
+        store_b.insert(3, vec![5.0, 6.0]).unwrap();
+        store_b.insert(1, vec![1.0, 2.0]).unwrap();
+        store_b.insert(2, vec![3.0, 4.0]).unwrap();
+
+        assert_eq!(store_a.root_hash(), store_b.root_hash());
     }
 
     #[test]
-    fn test_anomaly_score() {
-        let mut store = HnswVectorStore::new(4);
+    fn test_proof_verifies_membership() {
+        let mut store = HnswVectorStore::new(2);
+        for i in 0..10u64 {
+            store.insert(i, vec![i as f32, (i * 2) as f32]).unwrap();
+        }
 
-        // Create a cluster of normal vectors
-        store.insert(1, vec![1.0, 0.0, 0.0, 0.0]).unwrap();
-        store.insert(2, vec![0.9, 0.1, 0.0, 0.0]).unwrap();
-        store.insert(3, vec![0.95, 0.05, 0.0, 0.0]).unwrap();
+        let root = store.root_hash();
 
-        // Normal query (close to cluster)
-        let normal_score = store.anomaly_score(&[1.0, 0.0, 0.0, 0.0]).unwrap();
+        for i in 0..10u64 {
+            let vector = vec![i as f32, (i * 2) as f32];
+            let proof = store.proof(i).unwrap();
+            assert!(verify_proof(root, i, &vector, &proof));
+        }
+    }
 
-        // Anomalous query (far from cluster)
-        let anomaly_score = store.anomaly_score(&[0.0, 0.0, 0.0, 1.0]).unwrap();
+    #[test]
+    fn test_proof_rejects_wrong_vector() {
+        let mut store = HnswVectorStore::new(2);
+        for i in 0..10u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
 
-        // Anomalous should have higher score
-        assert!(anomaly_score > normal_score);
+        let root = store.root_hash();
+        let proof = store.proof(3).unwrap();
+
+        assert!(!verify_proof(root, 3, &[99.0, 0.0], &proof));
     }
 
     #[test]
-    fn test_similarity_check() {
-        let store = HnswVectorStore::new(4);
+    fn test_proof_unknown_id_is_none() {
+        let mut store = HnswVectorStore::new(2);
+        store.insert(1, vec![1.0, 0.0]).unwrap();
 
-        // Similar vectors
-        let vec_a = vec![1.0, 0.0, 0.0, 0.0];
-        let vec_b = vec![0.95, 0.05, 0.0, 0.0];
+        assert!(store.proof(999).is_none());
+    }
 
-        let (is_similar, similarity) = store.similarity_check(&vec_a, &vec_b, 0.5).unwrap();
-        assert!(is_similar);
-        assert!(similarity > 0.5);
+    #[test]
+    fn test_proof_survives_remove_and_update() {
+        let mut store = HnswVectorStore::new(2);
+        for i in 0..12u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
 
-        // Different vectors
-        let vec_c = vec![0.0, 0.0, 0.0, 1.0];
-        let (is_similar, _) = store.similarity_check(&vec_a, &vec_c, 0.9).unwrap();
-        assert!(!is_similar);
+        store.remove(4).unwrap();
+        store.update(7, vec![70.0, 0.0]).unwrap();
+
+        let root = store.root_hash();
+
+        let proof7 = store.proof(7).unwrap();
+        assert!(verify_proof(root, 7, &[70.0, 0.0], &proof7));
+
+        assert!(store.proof(4).is_none());
     }
 
+    // ==================== Delta Serialization Tests ====================
+
     #[test]
-    fn test_get_vector() {
-        let mut store = HnswVectorStore::new(4);
+    fn test_delta_round_trips_an_insert() {
+        let mut prev = HnswVectorStore::new(2);
+        for i in 0..10u64 {
+            prev.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
 
-        let vector = vec![1.0, 2.0, 3.0, 4.0];
-        store.insert(42, vector.clone()).unwrap();
+        let mut next = HnswVectorStore::from_bytes(&prev.to_bytes()).unwrap();
+        next.insert(10, vec![10.0, 0.0]).unwrap();
 
-        // Should find stored vector
-        let retrieved = store.get_vector(42);
-        assert!(retrieved.is_some());
-        assert_eq!(retrieved.unwrap(), vector);
+        let delta = next.to_bytes_delta(&prev);
+        let applied = prev.apply_delta(&delta).unwrap();
 
-        // Should return None for unknown ID
-        let missing = store.get_vector(999);
-        assert!(missing.is_none());
+        assert_eq!(applied.root_hash(), next.root_hash());
+        assert_eq!(applied.len(), next.len());
     }
 
     #[test]
-    fn test_dimension() {
-        let store = HnswVectorStore::new(768);
-        assert_eq!(store.dimension(), 768);
+    fn test_delta_round_trips_remove_and_update() {
+        let mut prev = HnswVectorStore::new(2);
+        for i in 0..20u64 {
+            prev.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let mut next = HnswVectorStore::from_bytes(&prev.to_bytes()).unwrap();
+        next.remove(5).unwrap();
+        next.update(10, vec![99.0, 0.0]).unwrap();
+
+        let delta = next.to_bytes_delta(&prev);
+        let applied = prev.apply_delta(&delta).unwrap();
+
+        assert_eq!(applied.root_hash(), next.root_hash());
+        assert_eq!(applied.len(), next.len());
+    }
+
+    #[test]
+    fn test_delta_is_smaller_than_a_full_snapshot_for_a_small_change() {
+        let mut prev = HnswVectorStore::new(2);
+        for i in 0..200u64 {
+            prev.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let mut next = HnswVectorStore::from_bytes(&prev.to_bytes()).unwrap();
+        next.insert(200, vec![200.0, 0.0]).unwrap();
+
+        let delta = next.to_bytes_delta(&prev);
+        let snapshot = next.to_bytes();
+
+        assert!(delta.len() < snapshot.len());
+    }
+
+    #[test]
+    fn test_delta_rejects_wrong_base() {
+        let mut a = HnswVectorStore::new(2);
+        a.insert(1, vec![1.0, 0.0]).unwrap();
+
+        let mut b = HnswVectorStore::new(2);
+        b.insert(2, vec![2.0, 0.0]).unwrap();
+
+        let mut next = HnswVectorStore::from_bytes(&a.to_bytes()).unwrap();
+        next.insert(3, vec![3.0, 0.0]).unwrap();
+
+        let delta = next.to_bytes_delta(&a);
+        assert!(b.apply_delta(&delta).is_err());
+    }
+
+    // ==================== Hybrid Search (RRF) Tests ====================
+
+    #[test]
+    fn test_hybrid_search_pure_semantic_matches_plain_search() {
+        let mut store = HnswVectorStore::new(2);
+        for i in 0..20u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let vector_only = store.search(&[0.0, 0.0], 5).unwrap();
+        let hybrid = store.hybrid_search(&[0.0, 0.0], &[], 5, 1.0).unwrap();
+
+        let vector_ids: Vec<u64> = vector_only.iter().map(|(id, _)| *id).collect();
+        let hybrid_ids: Vec<u64> = hybrid.iter().map(|(id, _)| *id).collect();
+        assert_eq!(vector_ids, hybrid_ids);
+    }
+
+    #[test]
+    fn test_hybrid_search_lexical_signal_can_promote_a_distant_id() {
+        let mut store = HnswVectorStore::new(2);
+        for i in 0..20u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        // id 19 is the worst vector match to the origin, but by far the best
+        // lexical match — a heavy lexical weighting should pull it to #1.
+        let lexical_scores: Vec<(u64, f32)> = vec![(19, 100.0), (0, 0.01)];
+
+        let hybrid = store.hybrid_search(&[0.0, 0.0], &lexical_scores, 3, 0.1).unwrap();
+        assert_eq!(hybrid[0].0, 19);
+    }
+
+    #[test]
+    fn test_hybrid_search_respects_k() {
+        let mut store = HnswVectorStore::new(2);
+        for i in 0..30u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let hybrid = store.hybrid_search(&[0.0, 0.0], &[(5, 1.0), (10, 0.5)], 7, 0.5).unwrap();
+        assert_eq!(hybrid.len(), 7);
+    }
+
+    // ==================== Metadata Filtering Tests ====================
+
+    fn category_meta(category: &str) -> Meta {
+        let mut meta = Meta::new();
+        meta.insert("category".to_string(), MetaValue::Text(category.to_string()));
+        meta
+    }
+
+    #[test]
+    fn test_search_filtered_meta_only_returns_matching_category() {
+        let mut store = HnswVectorStore::new(2);
+
+        for i in 0..30u64 {
+            let category = if i % 2 == 0 { "even" } else { "odd" };
+            store.insert_with_meta(i, vec![i as f32, 0.0], category_meta(category)).unwrap();
+        }
+
+        let results = store
+            .search_filtered_meta(&[0.0, 0.0], 5, |meta| {
+                meta.get("category") == Some(&MetaValue::Text("even".to_string()))
+            })
+            .unwrap();
+
+        assert_eq!(results.len(), 5);
+        let ids: Vec<u64> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_search_filtered_meta_widens_for_a_rare_match() {
+        let mut store = HnswVectorStore::new(2);
+
+        for i in 0..200u64 {
+            let category = if i == 150 { "rare" } else { "common" };
+            store.insert_with_meta(i, vec![i as f32, 0.0], category_meta(category)).unwrap();
+        }
+
+        let results = store
+            .search_filtered_meta(&[0.0, 0.0], 1, |meta| {
+                meta.get("category") == Some(&MetaValue::Text("rare".to_string()))
+            })
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, 150);
+    }
+
+    #[test]
+    fn test_ids_without_metadata_never_pass_the_filter() {
+        let mut store = HnswVectorStore::new(2);
+        for i in 0..10u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap(); // no metadata
+        }
+
+        let results = store.search_filtered_meta(&[0.0, 0.0], 5, |_| true).unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_remove_drops_stored_metadata() {
+        let mut store = HnswVectorStore::new(2);
+        store.insert_with_meta(1, vec![1.0, 0.0], category_meta("even")).unwrap();
+
+        assert!(store.get_meta(1).is_some());
+        store.remove(1).unwrap();
+        assert!(store.get_meta(1).is_none());
+    }
+
+    // ==================== Scalar Quantization Tests ====================
+
+    #[test]
+    fn test_quantized_search_still_finds_nearest_neighbor() {
+        let mut index = HnswIndex::new_quantized(2);
+
+        for i in 0..50u64 {
+            index.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let results = index.search(&[10.0, 0.0], 1).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, 10);
+    }
+
+    #[test]
+    fn test_quantize_vector_round_trips_within_error_bound() {
+        let vector = vec![-3.0, 0.0, 1.5, 4.25];
+        let q = quantize_vector(&vector);
+        let dequantized = dequantize_vector(&q);
+
+        let max_err = (q.max - q.min) / 255.0;
+        for (original, approx) in vector.iter().zip(dequantized.iter()) {
+            assert!(
+                (original - approx).abs() <= max_err + f32::EPSILON,
+                "original {original} approx {approx} exceeds step {max_err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_quantized_index_round_trips_through_bytes() {
+        let mut index = HnswIndex::new_quantized(2);
+        for i in 0..10u64 {
+            index.insert(i, vec![i as f32, -(i as f32)]).unwrap();
+        }
+
+        let bytes = index.to_bytes();
+        let restored = HnswIndex::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.len(), index.len());
+        let results = restored.search(&[3.0, -3.0], 1).unwrap();
+        assert_eq!(results[0].0, 3);
+    }
+
+    // ==================== Archived (mmap) Index Tests ====================
+
+    #[test]
+    fn test_load_mmap_search_matches_live_index() {
+        let mut index = HnswIndex::new(2);
+        for i in 0..60u64 {
+            index.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let bytes = index.to_archived_bytes();
+        let archived = HnswIndex::load_mmap(&bytes).unwrap();
+
+        assert_eq!(archived.len(), index.len());
+
+        let live = index.search(&[20.0, 0.0], 3).unwrap();
+        let mmapped = archived.search(&[20.0, 0.0], 3).unwrap();
+        assert_eq!(live, mmapped);
+    }
+
+    #[test]
+    fn test_load_mmap_works_with_quantized_index() {
+        let mut index = HnswIndex::new_quantized(2);
+        for i in 0..30u64 {
+            index.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let archived = HnswIndex::load_mmap(&index.to_archived_bytes()).unwrap();
+        let results = archived.search(&[15.0, 0.0], 1).unwrap();
+        assert_eq!(results[0].0, 15);
+    }
+
+    #[test]
+    fn test_load_mmap_rejects_truncated_buffer() {
+        let mut index = HnswIndex::new(2);
+        index.insert(1, vec![1.0, 2.0]).unwrap();
+
+        let bytes = index.to_archived_bytes();
+        assert!(HnswIndex::load_mmap(&bytes[..20]).is_err());
+    }
+
+    // ==================== Soft Deletion / Compaction Tests ====================
+
+    #[test]
+    fn test_soft_delete_then_search_skips_tombstoned_id() {
+        let mut index = HnswIndex::new(2);
+        for i in 0..20u64 {
+            index.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        index.soft_delete(10).unwrap();
+
+        let results = index.search(&[10.4, 0.0], 1).unwrap();
+        assert_eq!(results[0].0, 11, "nearest live neighbor should be 11, not the tombstoned 10");
+        assert!(index.is_deleted(10));
+        assert_eq!(index.live_count(), 19);
+        assert_eq!(index.len(), 20, "len() still counts tombstoned nodes as routing hops");
+    }
+
+    #[test]
+    fn test_soft_delete_twice_errors() {
+        let mut index = HnswIndex::new(2);
+        index.insert(1, vec![1.0, 0.0]).unwrap();
+        index.soft_delete(1).unwrap();
+        assert!(matches!(index.soft_delete(1), Err(HnswError::NodeNotFound(1))));
+    }
+
+    #[test]
+    fn test_update_changes_nearest_neighbor() {
+        let mut store = HnswVectorStore::new(2);
+        for i in 0..10u64 {
+            store.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        let before = store.search(&[9.0, 0.0], 1).unwrap();
+        assert_eq!(before[0].0, 9);
+
+        // Move id 0 right next to the query point.
+        store.update(0, vec![9.1, 0.0]).unwrap();
+
+        let after = store.search(&[9.0, 0.0], 1).unwrap();
+        assert_eq!(after[0].0, 0, "updated vector should now be the nearest neighbor");
+    }
+
+    #[test]
+    fn test_compact_below_threshold_is_a_no_op() {
+        let mut index = HnswIndex::new(2);
+        for i in 0..10u64 {
+            index.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+        index.soft_delete(0).unwrap();
+
+        assert!(!index.compact(0.5));
+        assert_eq!(index.len(), 10);
+    }
+
+    #[test]
+    fn test_compact_rebuilds_and_preserves_recall() {
+        let mut index = HnswIndex::new(2);
+        for i in 0..40u64 {
+            index.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+
+        for i in 0..30u64 {
+            index.soft_delete(i).unwrap();
+        }
+
+        assert!(index.compact(0.5));
+        assert_eq!(index.len(), 10);
+        assert_eq!(index.live_count(), 10);
+        assert_eq!(index.deleted_ratio(), 0.0);
+
+        // Recall check: nearest surviving neighbor to a query near the
+        // purged range should be the closest id that's still present.
+        let results = index.search(&[25.0, 0.0], 1).unwrap();
+        assert_eq!(results[0].0, 30);
+    }
+
+    #[test]
+    fn test_to_bytes_from_bytes_round_trips_tombstones() {
+        let mut index = HnswIndex::new(2);
+        for i in 0..10u64 {
+            index.insert(i, vec![i as f32, 0.0]).unwrap();
+        }
+        index.soft_delete(3).unwrap();
+
+        let bytes = index.to_bytes();
+        let restored = HnswIndex::from_bytes(&bytes).unwrap();
+
+        assert!(restored.is_deleted(3));
+        assert_eq!(restored.live_count(), 9);
     }
 }
 
@@ -4,10 +4,12 @@ pub mod bridge;
 pub mod constants;
 pub mod error;
 pub mod hnsw;
+pub mod minhash;
 pub mod multisig;
 pub mod parallel;
 pub mod performance;
 pub mod receipt;
+pub mod rlp;
 pub mod semantic;
 pub mod semantic_registry;
 pub mod state;
@@ -17,7 +19,7 @@ pub mod types;
 pub mod unified_state;
 
 pub use account::{Account, BalanceError};
-pub use block::{Block, BlockHeader};
+pub use block::{Block, BlockHeader, IndexedBlock};
 pub use constants::{addresses, chain_id, consensus, network, tokenomics, transaction as transaction_constants};
 pub use error::{CoreError, Result};
 pub use parallel::{
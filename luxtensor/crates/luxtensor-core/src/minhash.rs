@@ -0,0 +1,294 @@
+//! MinHash sketch index for cheap near-duplicate detection over large
+//! sparse or set-like inputs (token sets, nonzero feature dimensions,
+//! etc). Companion to `hnsw::HnswIndex`: callers can run a cheap
+//! `MinHashIndex::query` first to flag likely duplicates before paying
+//! for expensive dense vector similarity.
+//!
+//! Signatures are computed with `k` independent min-hash functions (one
+//! per seed) and estimate Jaccard similarity as the fraction of matching
+//! signature slots. For sublinear lookup, the `k` hashes are split into
+//! `bands` bands of `rows` rows each (locality-sensitive hashing): two
+//! items are only compared if they collide in at least one band, so
+//! `query` never has to scan every stored signature.
+
+use std::collections::{HashMap, HashSet};
+
+/// A MinHash signature: one minimum hash per seed.
+pub type Signature = Vec<u64>;
+
+/// `bands * rows` must equal `k` for the LSH banding to cover every
+/// signature slot exactly once.
+#[derive(Clone, Debug)]
+pub struct MinHashConfig {
+    /// Number of independent hash functions (signature length).
+    pub k: usize,
+    /// Number of LSH bands `b`.
+    pub bands: usize,
+    /// Rows per band `r`, with `bands * rows == k`.
+    pub rows: usize,
+}
+
+impl Default for MinHashConfig {
+    fn default() -> Self {
+        Self { k: 128, bands: 32, rows: 4 }
+    }
+}
+
+/// Sketch-based companion index for near-duplicate detection.
+pub struct MinHashIndex {
+    config: MinHashConfig,
+    signatures: HashMap<u64, Signature>,
+    /// One bucket map per band: `band_hash -> ids sharing that band's hash`.
+    band_buckets: Vec<HashMap<u64, Vec<u64>>>,
+}
+
+impl MinHashIndex {
+    pub fn new() -> Self {
+        Self::with_config(MinHashConfig::default())
+    }
+
+    pub fn with_config(config: MinHashConfig) -> Self {
+        let band_buckets = vec![HashMap::new(); config.bands];
+        Self { config, signatures: HashMap::new(), band_buckets }
+    }
+
+    pub fn len(&self) -> usize {
+        self.signatures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.signatures.is_empty()
+    }
+
+    /// Insert an item's token/feature set, computing and bucketing its
+    /// signature. Errors with `DuplicateId` if `id` is already present.
+    pub fn insert(&mut self, id: u64, tokens: &[String]) -> Result<(), MinHashError> {
+        if self.signatures.contains_key(&id) {
+            return Err(MinHashError::DuplicateId(id));
+        }
+
+        let signature = Self::compute_signature(tokens, self.config.k);
+        self.bucket(id, &signature);
+        self.signatures.insert(id, signature);
+        Ok(())
+    }
+
+    /// Find stored items whose estimated Jaccard similarity to `tokens` is
+    /// at least `threshold`, sorted most-similar first. Only items sharing
+    /// at least one LSH band bucket with `tokens`'s signature are compared.
+    pub fn query(&self, tokens: &[String], threshold: f64) -> Vec<(u64, f64)> {
+        let signature = Self::compute_signature(tokens, self.config.k);
+
+        let mut candidates = HashSet::new();
+        for (band, buckets) in self.band_buckets.iter().enumerate() {
+            let band_hash = Self::band_hash(&signature, band, self.config.rows);
+            if let Some(ids) = buckets.get(&band_hash) {
+                candidates.extend(ids.iter().copied());
+            }
+        }
+
+        let mut results: Vec<(u64, f64)> = candidates
+            .into_iter()
+            .filter_map(|id| {
+                let similarity = Self::jaccard_estimate(&signature, &self.signatures[&id]);
+                (similarity >= threshold).then_some((id, similarity))
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        results
+    }
+
+    fn bucket(&mut self, id: u64, signature: &Signature) {
+        for (band, buckets) in self.band_buckets.iter_mut().enumerate() {
+            let band_hash = Self::band_hash(signature, band, self.config.rows);
+            buckets.entry(band_hash).or_default().push(id);
+        }
+    }
+
+    fn compute_signature(tokens: &[String], k: usize) -> Signature {
+        (0..k as u64)
+            .map(|seed| tokens.iter().map(|t| Self::hash_token(t, seed)).min().unwrap_or(u64::MAX))
+            .collect()
+    }
+
+    /// FNV-1a mixed with `seed`, giving an independent hash per min-hash
+    /// function without needing `k` separate hash implementations.
+    fn hash_token(token: &str, seed: u64) -> u64 {
+        let mut h = 0xcbf29ce484222325u64 ^ seed;
+        for &byte in token.as_bytes() {
+            h ^= byte as u64;
+            h = h.wrapping_mul(0x100000001b3);
+        }
+        h
+    }
+
+    fn band_hash(signature: &Signature, band: usize, rows: usize) -> u64 {
+        let start = band * rows;
+        let mut h = 0xcbf29ce484222325u64;
+        for &v in &signature[start..start + rows] {
+            h ^= v;
+            h = h.wrapping_mul(0x100000001b3);
+        }
+        h
+    }
+
+    fn jaccard_estimate(a: &Signature, b: &Signature) -> f64 {
+        let matches = a.iter().zip(b.iter()).filter(|(x, y)| x == y).count();
+        matches as f64 / a.len() as f64
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&(self.config.k as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.config.bands as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.config.rows as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.signatures.len() as u32).to_le_bytes());
+
+        for (id, signature) in &self.signatures {
+            bytes.extend_from_slice(&id.to_le_bytes());
+            for &v in signature {
+                bytes.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+
+        bytes
+    }
+
+    /// Decode a `to_bytes` buffer. Band buckets aren't serialized — they're
+    /// a deterministic function of each signature, so they're rebuilt by
+    /// replaying `bucket` for every decoded id rather than duplicating
+    /// that state on disk.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MinHashError> {
+        if bytes.len() < 16 {
+            return Err(MinHashError::InvalidData);
+        }
+
+        let mut pos = 0;
+        let k = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| MinHashError::InvalidData)?) as usize;
+        pos += 4;
+        let bands = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| MinHashError::InvalidData)?) as usize;
+        pos += 4;
+        let rows = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| MinHashError::InvalidData)?) as usize;
+        pos += 4;
+        let count = u32::from_le_bytes(bytes[pos..pos + 4].try_into().map_err(|_| MinHashError::InvalidData)?) as usize;
+        pos += 4;
+
+        let mut index = Self::with_config(MinHashConfig { k, bands, rows });
+
+        for _ in 0..count {
+            if pos + 8 + k * 8 > bytes.len() {
+                return Err(MinHashError::InvalidData);
+            }
+            let id = u64::from_le_bytes(bytes[pos..pos + 8].try_into().map_err(|_| MinHashError::InvalidData)?);
+            pos += 8;
+
+            let mut signature = Vec::with_capacity(k);
+            for _ in 0..k {
+                signature.push(u64::from_le_bytes(
+                    bytes[pos..pos + 8].try_into().map_err(|_| MinHashError::InvalidData)?,
+                ));
+                pos += 8;
+            }
+
+            index.bucket(id, &signature);
+            index.signatures.insert(id, signature);
+        }
+
+        Ok(index)
+    }
+}
+
+impl Default for MinHashIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MinHashError {
+    DuplicateId(u64),
+    InvalidData,
+}
+
+impl std::fmt::Display for MinHashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MinHashError::DuplicateId(id) => write!(f, "Duplicate ID: {}", id),
+            MinHashError::InvalidData => write!(f, "Invalid serialized data"),
+        }
+    }
+}
+
+impl std::error::Error for MinHashError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_identical_sets_are_near_duplicates() {
+        let mut index = MinHashIndex::new();
+        let doc = tokens(&["the", "quick", "brown", "fox", "jumps"]);
+        index.insert(1, &doc).unwrap();
+
+        let results = index.query(&doc, 0.9);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, 1);
+        assert!(results[0].1 > 0.99);
+    }
+
+    #[test]
+    fn test_disjoint_sets_are_not_matched() {
+        let mut index = MinHashIndex::new();
+        index.insert(1, &tokens(&["alpha", "beta", "gamma"])).unwrap();
+
+        let results = index.query(&tokens(&["delta", "epsilon", "zeta"]), 0.5);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_mostly_overlapping_sets_pass_a_moderate_threshold() {
+        let mut index = MinHashIndex::new();
+        let base: Vec<String> = (0..50).map(|i| format!("tok{i}")).collect();
+        index.insert(1, &base).unwrap();
+
+        let mut near_dup = base.clone();
+        near_dup.truncate(45);
+        near_dup.push("unique_token".to_string());
+
+        let results = index.query(&near_dup, 0.5);
+        assert!(results.iter().any(|(id, _)| *id == 1), "expected near-duplicate 1 to be found");
+    }
+
+    #[test]
+    fn test_duplicate_id_insert_errors() {
+        let mut index = MinHashIndex::new();
+        index.insert(1, &tokens(&["a"])).unwrap();
+        assert_eq!(index.insert(1, &tokens(&["b"])), Err(MinHashError::DuplicateId(1)));
+    }
+
+    #[test]
+    fn test_to_bytes_from_bytes_round_trip() {
+        let mut index = MinHashIndex::new();
+        index.insert(1, &tokens(&["the", "quick", "brown", "fox"])).unwrap();
+        index.insert(2, &tokens(&["lorem", "ipsum", "dolor"])).unwrap();
+
+        let bytes = index.to_bytes();
+        let restored = MinHashIndex::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.len(), index.len());
+        let results = restored.query(&tokens(&["the", "quick", "brown", "fox"]), 0.9);
+        assert_eq!(results[0].0, 1);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_truncated_buffer() {
+        assert!(MinHashIndex::from_bytes(&[0u8; 4]).is_err());
+    }
+}
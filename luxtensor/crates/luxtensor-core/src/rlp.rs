@@ -3,6 +3,10 @@
 //! Provides the subset of RLP encoding needed for EIP-155 transaction signing.
 //! These helpers are shared between `luxtensor-core` (signing_message) and
 //! `luxtensor-rpc` (eth_sendRawTransaction decode/verify).
+//!
+//! For large payloads with nested lists, prefer the incremental [`RlpStream`]
+//! builder over composing `rlp_encode_list` calls — it avoids the
+//! intermediate `Vec<Vec<u8>>` allocation per list.
 
 /// Convert u64 to minimal big-endian bytes (no leading zeroes).
 /// Returns empty vec for 0.
@@ -83,6 +87,581 @@ pub fn rlp_encode_list(items: &[Vec<u8>]) -> Vec<u8> {
     }
 }
 
+/// A single in-progress list on an [`RlpStream`]'s stack.
+struct UnfinishedList {
+    /// Offset into the stream's buffer where this list's payload begins.
+    offset: usize,
+    /// Number of items still expected before the list is complete.
+    remaining: usize,
+}
+
+/// Incremental RLP encoder that appends items into one growing buffer.
+///
+/// `rlp_encode_list` forces the caller to pre-encode every child into a
+/// `Vec<Vec<u8>>` before wrapping them, which means an extra allocation per
+/// item when building large transaction/receipt payloads. `RlpStream` instead
+/// keeps a stack of unfinished lists, each recording the buffer offset where
+/// its payload begins and how many items it still expects. When the last item
+/// of a list is appended, the correct length header is spliced in front of
+/// the payload and the list is popped off the stack — mirroring the
+/// portion-wise encoding path that avoids per-item allocation.
+pub struct RlpStream {
+    buffer: Vec<u8>,
+    unfinished: Vec<UnfinishedList>,
+}
+
+impl RlpStream {
+    /// Create an empty stream.
+    pub fn new() -> Self {
+        Self { buffer: Vec::new(), unfinished: Vec::new() }
+    }
+
+    /// Begin a list of `len` items. The list is finalized automatically once
+    /// `len` items (via any `append*`/`begin_list` call) have been appended.
+    pub fn begin_list(&mut self, len: usize) -> &mut Self {
+        if len == 0 {
+            // An empty list has no payload to wait for — write its header now.
+            self.buffer.push(0xc0);
+            self.note_item_appended();
+        } else {
+            self.unfinished.push(UnfinishedList { offset: self.buffer.len(), remaining: len });
+        }
+        self
+    }
+
+    /// Append a raw byte string.
+    pub fn append(&mut self, data: &[u8]) -> &mut Self {
+        let encoded = rlp_encode_bytes(data);
+        self.buffer.extend_from_slice(&encoded);
+        self.note_item_appended();
+        self
+    }
+
+    /// Append a `u64` as minimal big-endian bytes (zero encodes as empty data).
+    pub fn append_u64(&mut self, val: u64) -> &mut Self {
+        let encoded = rlp_encode_u64(val);
+        self.buffer.extend_from_slice(&encoded);
+        self.note_item_appended();
+        self
+    }
+
+    /// Append a `u128` as minimal big-endian bytes (zero encodes as empty data).
+    pub fn append_u128(&mut self, val: u128) -> &mut Self {
+        let encoded = rlp_encode_u128(val);
+        self.buffer.extend_from_slice(&encoded);
+        self.note_item_appended();
+        self
+    }
+
+    /// Append the RLP empty-string marker (`0x80`) — used for e.g. contract
+    /// creation `to` fields or zeroed legacy-tx `r`/`s` placeholders.
+    pub fn append_empty_data(&mut self) -> &mut Self {
+        self.buffer.push(0x80);
+        self.note_item_appended();
+        self
+    }
+
+    /// Finalize the stream, returning the encoded bytes.
+    ///
+    /// Panics if a `begin_list` call is still waiting on items — that is a
+    /// caller bug (mismatched `len` vs. number of appended items), not a
+    /// recoverable runtime condition.
+    pub fn out(self) -> Vec<u8> {
+        assert!(
+            self.unfinished.is_empty(),
+            "RlpStream::out called with {} unfinished list(s)",
+            self.unfinished.len()
+        );
+        self.buffer
+    }
+
+    /// Record that one item was just appended to the buffer, cascading
+    /// completion up through any lists this closes out.
+    fn note_item_appended(&mut self) {
+        while let Some(top) = self.unfinished.last_mut() {
+            top.remaining -= 1;
+            if top.remaining != 0 {
+                break;
+            }
+            let offset = self.unfinished.pop().unwrap().offset;
+            self.splice_list_header(offset);
+            // Finishing this list counts as one item for its parent (if any),
+            // so the loop continues and may cascade further up the stack.
+        }
+    }
+
+    /// Splice the correct single-byte or long-form list length header in
+    /// front of the payload that starts at `offset`.
+    fn splice_list_header(&mut self, offset: usize) {
+        let payload_len = self.buffer.len() - offset;
+        let header: Vec<u8> = if payload_len <= 55 {
+            vec![0xc0 + payload_len as u8]
+        } else {
+            let len_bytes = to_minimal_be(payload_len as u64);
+            let mut h = Vec::with_capacity(1 + len_bytes.len());
+            h.push(0xf7 + len_bytes.len() as u8);
+            h.extend_from_slice(&len_bytes);
+            h
+        };
+        self.buffer.splice(offset..offset, header);
+    }
+}
+
+impl Default for RlpStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+use std::cell::Cell;
+
+/// Structured decode failure, distinguishing *why* an RLP item was rejected
+/// instead of surfacing an opaque message.
+///
+/// `Custom` remains as an escape hatch for call sites (like index-out-of-bounds
+/// in [`Rlp::at`]) that don't map cleanly onto one of the structural variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecoderError {
+    /// Not enough bytes remain to parse a header or the payload it describes.
+    RlpIsTooShort,
+    /// Expected a list item but found a data item.
+    RlpExpectedToBeList,
+    /// Expected a data item but found a list item.
+    RlpExpectedToBeData,
+    /// A length field is malformed (zero-width `len_of_len`, etc).
+    RlpInvalidLength,
+    /// The decoded integer does not fit in a `u64`.
+    RlpOverflowsU64,
+    /// The decoded integer does not fit in a `u128`/32-byte word.
+    RlpOverflowsU256,
+    /// A long-form length header encodes a value that would have fit in the
+    /// short form (e.g. `0xb8 0x01` for a single byte) — not canonical RLP.
+    NonCanonicalLengthEncoding,
+    /// Any other rejection reason (e.g. index out of bounds).
+    Custom(String),
+}
+
+impl From<String> for DecoderError {
+    fn from(reason: String) -> Self {
+        DecoderError::Custom(reason)
+    }
+}
+
+impl std::fmt::Display for DecoderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecoderError::RlpIsTooShort => write!(f, "RLP: input is too short"),
+            DecoderError::RlpExpectedToBeList => write!(f, "RLP: expected a list item"),
+            DecoderError::RlpExpectedToBeData => write!(f, "RLP: expected a data item"),
+            DecoderError::RlpInvalidLength => write!(f, "RLP: invalid length field"),
+            DecoderError::RlpOverflowsU64 => write!(f, "RLP: integer overflows u64"),
+            DecoderError::RlpOverflowsU256 => write!(f, "RLP: integer overflows u128/u256"),
+            DecoderError::NonCanonicalLengthEncoding => {
+                write!(f, "RLP: non-canonical length encoding")
+            }
+            DecoderError::Custom(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for DecoderError {}
+
+/// Header/value byte lengths of an RLP item, returned by [`payload_info`]
+/// without consuming or copying the payload — useful for pre-sizing buffers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadInfo {
+    pub header_len: usize,
+    pub value_len: usize,
+}
+
+/// Parse an RLP item header without copying.
+/// Returns `(is_list, header_len, payload_len)`.
+fn decode_header(data: &[u8]) -> Result<(bool, usize, usize), DecoderError> {
+    if data.is_empty() {
+        return Err(DecoderError::RlpIsTooShort);
+    }
+    let prefix = data[0];
+    if prefix <= 0x7f {
+        Ok((false, 0, 1))
+    } else if prefix <= 0xb7 {
+        let len = (prefix - 0x80) as usize;
+        if data.len() < 1 + len {
+            return Err(DecoderError::RlpIsTooShort);
+        }
+        Ok((false, 1, len))
+    } else if prefix <= 0xbf {
+        let len_of_len = (prefix - 0xb7) as usize;
+        let (len, total_header) = decode_long_length(data, len_of_len)?;
+        if data.len() < total_header + len {
+            return Err(DecoderError::RlpIsTooShort);
+        }
+        Ok((false, total_header, len))
+    } else if prefix <= 0xf7 {
+        let len = (prefix - 0xc0) as usize;
+        if data.len() < 1 + len {
+            return Err(DecoderError::RlpIsTooShort);
+        }
+        Ok((true, 1, len))
+    } else {
+        let len_of_len = (prefix - 0xf7) as usize;
+        let (len, total_header) = decode_long_length(data, len_of_len)?;
+        if data.len() < total_header + len {
+            return Err(DecoderError::RlpIsTooShort);
+        }
+        Ok((true, total_header, len))
+    }
+}
+
+/// Decode the multi-byte length field following a long-form prefix.
+/// Returns `(value_len, header_len)` where `header_len` = 1 (prefix byte) + `len_of_len`.
+///
+/// Rejects lengths that overflow a `usize`-sized buffer and, per canonical
+/// RLP, long-form headers whose value would have fit in the short form
+/// (`value_len <= 55`) — e.g. `0xb8 0x01` encoding a single byte.
+fn decode_long_length(data: &[u8], len_of_len: usize) -> Result<(usize, usize), DecoderError> {
+    if len_of_len == 0 || data.len() < 1 + len_of_len {
+        return Err(DecoderError::RlpInvalidLength);
+    }
+    if len_of_len > 8 {
+        return Err(DecoderError::RlpInvalidLength);
+    }
+    let mut len_bytes = [0u8; 8];
+    let start = 8 - len_of_len;
+    len_bytes[start..].copy_from_slice(&data[1..1 + len_of_len]);
+    let len = u64::from_be_bytes(len_bytes) as usize;
+    if len <= 55 {
+        return Err(DecoderError::NonCanonicalLengthEncoding);
+    }
+    Ok((len, 1 + len_of_len))
+}
+
+/// Header/value lengths of the RLP item at the start of `data`, without
+/// copying the payload. Lets callers pre-size buffers before decoding.
+pub fn payload_info(data: &[u8]) -> Result<PayloadInfo, DecoderError> {
+    let (_, header_len, value_len) = decode_header(data)?;
+    Ok(PayloadInfo { header_len, value_len })
+}
+
+/// A lazy, zero-copy view over an RLP-encoded item.
+///
+/// `Rlp` borrows the underlying buffer and decodes on demand: `data()` and
+/// `at()` only parse the header of the item(s) they touch, never the whole
+/// structure up front. Repeated sequential indexing (`at(0)`, `at(1)`, …) is
+/// amortized O(1) per step — the view caches the index/offset pair it last
+/// resolved and resumes scanning from there instead of rescanning the list
+/// from its start.
+pub struct Rlp<'a> {
+    data: &'a [u8],
+    /// (last resolved index, byte offset of that item within the payload)
+    offset_cache: Cell<(usize, usize)>,
+    count_cache: Cell<Option<usize>>,
+}
+
+impl<'a> Rlp<'a> {
+    /// Wrap a byte slice containing a single encoded RLP item (list or data).
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset_cache: Cell::new((0, 0)), count_cache: Cell::new(None) }
+    }
+
+    /// True if this item is a list.
+    pub fn is_list(&self) -> bool {
+        decode_header(self.data).map(|(is_list, _, _)| is_list).unwrap_or(false)
+    }
+
+    /// True if this item is a data (string) item.
+    pub fn is_data(&self) -> bool {
+        !self.is_list()
+    }
+
+    /// Borrow the payload bytes of a data item. Errors if this is a list.
+    pub fn data(&self) -> Result<&'a [u8], DecoderError> {
+        let (is_list, header_len, payload_len) = decode_header(self.data)?;
+        if is_list {
+            return Err(DecoderError::RlpExpectedToBeData);
+        }
+        Ok(&self.data[header_len..header_len + payload_len])
+    }
+
+    /// Number of child items, if this is a list. Cached after first call.
+    pub fn item_count(&self) -> Result<usize, DecoderError> {
+        if let Some(count) = self.count_cache.get() {
+            return Ok(count);
+        }
+        let (is_list, header_len, payload_len) = decode_header(self.data)?;
+        if !is_list {
+            return Err(DecoderError::RlpExpectedToBeList);
+        }
+        let payload = &self.data[header_len..header_len + payload_len];
+        let mut offset = 0;
+        let mut count = 0;
+        while offset < payload.len() {
+            let (_, h, p) = decode_header(&payload[offset..])?;
+            offset += h + p;
+            count += 1;
+        }
+        self.count_cache.set(Some(count));
+        Ok(count)
+    }
+
+    /// Borrow the sub-view at `index` without copying.
+    ///
+    /// Sequential ascending access (the common case when walking a decoded
+    /// list in order) resumes from the last resolved offset rather than
+    /// rescanning the list from the start.
+    pub fn at(&self, index: usize) -> Result<Rlp<'a>, DecoderError> {
+        let (is_list, header_len, payload_len) = decode_header(self.data)?;
+        if !is_list {
+            return Err(DecoderError::RlpExpectedToBeList);
+        }
+        let payload = &self.data[header_len..header_len + payload_len];
+
+        let (cached_index, cached_offset) = self.offset_cache.get();
+        let (mut idx, mut offset) = if index >= cached_index {
+            (cached_index, cached_offset)
+        } else {
+            (0, 0)
+        };
+
+        while idx < index {
+            if offset >= payload.len() {
+                return Err(DecoderError::Custom(format!("RLP: index {} out of bounds", index)));
+            }
+            let (_, h, p) = decode_header(&payload[offset..])?;
+            offset += h + p;
+            idx += 1;
+        }
+        if offset >= payload.len() {
+            return Err(DecoderError::Custom(format!("RLP: index {} out of bounds", index)));
+        }
+        let (_, h, p) = decode_header(&payload[offset..])?;
+        let item_end = offset + h + p;
+        if item_end > payload.len() {
+            return Err(DecoderError::RlpIsTooShort);
+        }
+
+        self.offset_cache.set((index, offset));
+        Ok(Rlp::new(&payload[offset..item_end]))
+    }
+
+    /// Iterate over child views in order.
+    pub fn iter(&self) -> RlpIter<'a> {
+        let count = self.item_count().unwrap_or(0);
+        RlpIter { rlp: Rlp::new(self.data), index: 0, count }
+    }
+}
+
+/// Iterator over the child items of an [`Rlp`] list view, yielded in order.
+pub struct RlpIter<'a> {
+    rlp: Rlp<'a>,
+    index: usize,
+    count: usize,
+}
+
+impl<'a> Iterator for RlpIter<'a> {
+    type Item = Rlp<'a>;
+
+    fn next(&mut self) -> Option<Rlp<'a>> {
+        if self.index >= self.count {
+            return None;
+        }
+        let item = self.rlp.at(self.index).ok()?;
+        self.index += 1;
+        Some(item)
+    }
+}
+
+/// Borrowing, zero-allocation iterator over the items of an RLP list payload.
+///
+/// Unlike [`rlp_decode_list`], which copies every item into its own `Vec<u8>`,
+/// this yields slices borrowed straight out of the input buffer. Each `next()`
+/// parses only the header of the item it's about to return.
+pub struct RlpItemIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for RlpItemIter<'a> {
+    /// `(item slice, is_list)` borrowed from the original buffer.
+    type Item = Result<(&'a [u8], bool), DecoderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        let (is_list, header_len, payload_len) = match decode_header(&self.data[self.offset..]) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                // Stop iterating after the first error so the caller isn't
+                // re-fed garbage from a now-unknown offset.
+                self.offset = self.data.len();
+                return Some(Err(err));
+            }
+        };
+        let start = self.offset + header_len;
+        let end = start + payload_len;
+        if end > self.data.len() {
+            self.offset = self.data.len();
+            return Some(Err(DecoderError::RlpIsTooShort));
+        }
+        self.offset = end;
+        Some(Ok((&self.data[start..end], is_list)))
+    }
+}
+
+/// Iterate over the items of an RLP list payload without copying them.
+pub fn rlp_iter_items(payload: &[u8]) -> RlpItemIter<'_> {
+    RlpItemIter { data: payload, offset: 0 }
+}
+
+/// Decode every item of an RLP list payload into owned `Vec<u8>`s.
+///
+/// Thin `.collect()` wrapper over [`rlp_iter_items`], kept for call sites
+/// that need owned data and don't care about the extra allocations.
+pub fn rlp_decode_list(payload: &[u8]) -> Result<Vec<Vec<u8>>, DecoderError> {
+    rlp_iter_items(payload).map(|res| res.map(|(slice, _)| slice.to_vec())).collect()
+}
+
+/// Types that can append themselves onto an [`RlpStream`] as a single RLP
+/// item (or, for structs with multiple fields, a list of items).
+pub trait Encodable {
+    fn rlp_append(&self, s: &mut RlpStream);
+
+    /// Encode standalone into a fresh buffer.
+    fn rlp_bytes(&self) -> Vec<u8> {
+        let mut s = RlpStream::new();
+        self.rlp_append(&mut s);
+        s.out()
+    }
+}
+
+/// Types that can be parsed out of an [`Rlp`] view.
+pub trait Decodable: Sized {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError>;
+}
+
+fn bytes_to_u64(data: &[u8]) -> Result<u64, DecoderError> {
+    if data.is_empty() {
+        return Ok(0);
+    }
+    if data.len() > 8 {
+        return Err(DecoderError::RlpOverflowsU64);
+    }
+    let mut buf = [0u8; 8];
+    buf[8 - data.len()..].copy_from_slice(data);
+    Ok(u64::from_be_bytes(buf))
+}
+
+fn bytes_to_u128(data: &[u8]) -> Result<u128, DecoderError> {
+    if data.is_empty() {
+        return Ok(0);
+    }
+    if data.len() > 16 {
+        return Err(DecoderError::RlpOverflowsU256);
+    }
+    let mut buf = [0u8; 16];
+    buf[16 - data.len()..].copy_from_slice(data);
+    Ok(u128::from_be_bytes(buf))
+}
+
+impl Encodable for u64 {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.append_u64(*self);
+    }
+}
+
+impl Decodable for u64 {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        bytes_to_u64(rlp.data()?)
+    }
+}
+
+impl Encodable for u128 {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.append_u128(*self);
+    }
+}
+
+impl Decodable for u128 {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        bytes_to_u128(rlp.data()?)
+    }
+}
+
+impl Encodable for [u8; 20] {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.append(self);
+    }
+}
+
+impl Decodable for [u8; 20] {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        let data = rlp.data()?;
+        if data.len() != 20 {
+            return Err(DecoderError::Custom(format!(
+                "RLP: expected 20-byte address, got {} bytes",
+                data.len()
+            )));
+        }
+        let mut out = [0u8; 20];
+        out.copy_from_slice(data);
+        Ok(out)
+    }
+}
+
+impl Encodable for [u8; 32] {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.append(self);
+    }
+}
+
+impl Decodable for [u8; 32] {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        let data = rlp.data()?;
+        if data.len() != 32 {
+            return Err(DecoderError::Custom(format!(
+                "RLP: expected 32-byte word, got {} bytes",
+                data.len()
+            )));
+        }
+        let mut out = [0u8; 32];
+        out.copy_from_slice(data);
+        Ok(out)
+    }
+}
+
+impl Encodable for Vec<u8> {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.append(self);
+    }
+}
+
+impl Decodable for Vec<u8> {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        Ok(rlp.data()?.to_vec())
+    }
+}
+
+impl<T: Encodable> Encodable for Vec<T> {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(self.len());
+        for item in self {
+            item.rlp_append(s);
+        }
+    }
+}
+
+impl<T: Decodable> Decodable for Vec<T> {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        let count = rlp.item_count()?;
+        let mut out = Vec::with_capacity(count);
+        for i in 0..count {
+            let item = rlp.at(i)?;
+            out.push(T::decode(&item)?);
+        }
+        Ok(out)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,4 +725,329 @@ mod tests {
         assert_eq!(to_minimal_be(256), vec![1, 0]);
         assert_eq!(to_minimal_be(0xFFFF), vec![0xFF, 0xFF]);
     }
+
+    #[test]
+    fn test_rlp_stream_empty_list() {
+        let mut s = RlpStream::new();
+        s.begin_list(0);
+        assert_eq!(s.out(), vec![0xc0]);
+    }
+
+    #[test]
+    fn test_rlp_stream_matches_rlp_encode_list() {
+        let item = rlp_encode_bytes(b"cat");
+        let expected = rlp_encode_list(&[item]);
+
+        let mut s = RlpStream::new();
+        s.begin_list(1);
+        s.append(b"cat");
+        assert_eq!(s.out(), expected);
+    }
+
+    #[test]
+    fn test_rlp_stream_flat_multi_item_list() {
+        let items = vec![rlp_encode_u64(42), rlp_encode_bytes(b"hello"), rlp_encode_bytes(&[])];
+        let expected = rlp_encode_list(&items);
+
+        let mut s = RlpStream::new();
+        s.begin_list(3);
+        s.append_u64(42);
+        s.append(b"hello");
+        s.append_empty_data();
+        assert_eq!(s.out(), expected);
+    }
+
+    #[test]
+    fn test_rlp_stream_nested_list() {
+        // RLP([1, [2, 3], 4]) matching the list-of-lists shape real tx encoding needs.
+        let inner = rlp_encode_list(&[rlp_encode_u64(2), rlp_encode_u64(3)]);
+        let expected = rlp_encode_list(&[rlp_encode_u64(1), inner, rlp_encode_u64(4)]);
+
+        let mut s = RlpStream::new();
+        s.begin_list(3);
+        s.append_u64(1);
+        s.begin_list(2);
+        s.append_u64(2);
+        s.append_u64(3);
+        s.append_u64(4);
+        assert_eq!(s.out(), expected);
+    }
+
+    #[test]
+    fn test_rlp_stream_long_list_header() {
+        let mut s = RlpStream::new();
+        s.begin_list(1);
+        s.append(&[0xAB; 100]);
+        let out = s.out();
+
+        let expected = rlp_encode_list(&[rlp_encode_bytes(&[0xAB; 100])]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "unfinished list")]
+    fn test_rlp_stream_panics_on_unfinished_list() {
+        let mut s = RlpStream::new();
+        s.begin_list(2);
+        s.append_u64(1);
+        let _ = s.out();
+    }
+
+    // -----------------------------------------------------------------------
+    // Rlp: lazy, zero-copy decoding view
+    // -----------------------------------------------------------------------
+
+    fn encoded_list() -> Vec<u8> {
+        // [42, "hello", ""]
+        rlp_encode_list(&[rlp_encode_u64(42), rlp_encode_bytes(b"hello"), rlp_encode_bytes(&[])])
+    }
+
+    #[test]
+    fn test_rlp_view_is_list_and_data() {
+        let list_bytes = encoded_list();
+        let rlp = Rlp::new(&list_bytes);
+        assert!(rlp.is_list());
+        assert!(!rlp.is_data());
+
+        let data_bytes = rlp_encode_bytes(b"hello");
+        let rlp = Rlp::new(&data_bytes);
+        assert!(rlp.is_data());
+        assert!(!rlp.is_list());
+    }
+
+    #[test]
+    fn test_rlp_view_item_count() {
+        let list_bytes = encoded_list();
+        let rlp = Rlp::new(&list_bytes);
+        assert_eq!(rlp.item_count().unwrap(), 3);
+        // Cached value must stay stable across repeated calls.
+        assert_eq!(rlp.item_count().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_rlp_view_at_sequential_and_random_access() {
+        let list_bytes = encoded_list();
+        let rlp = Rlp::new(&list_bytes);
+
+        // Sequential ascending access exercises the offset cache fast path.
+        assert_eq!(rlp.at(0).unwrap().data().unwrap(), rlp_item_bytes(42));
+        assert_eq!(rlp.at(1).unwrap().data().unwrap(), b"hello");
+        assert_eq!(rlp.at(2).unwrap().data().unwrap(), b"");
+
+        // Re-accessing an earlier index must rescan from the start, not the cache.
+        assert_eq!(rlp.at(0).unwrap().data().unwrap(), rlp_item_bytes(42));
+    }
+
+    fn rlp_item_bytes(val: u64) -> Vec<u8> {
+        let encoded = rlp_encode_u64(val);
+        Rlp::new(&encoded).data().unwrap().to_vec()
+    }
+
+    #[test]
+    fn test_rlp_view_at_out_of_bounds() {
+        let list_bytes = encoded_list();
+        let rlp = Rlp::new(&list_bytes);
+        assert!(rlp.at(3).is_err());
+    }
+
+    #[test]
+    fn test_rlp_view_iter() {
+        let list_bytes = encoded_list();
+        let rlp = Rlp::new(&list_bytes);
+        let items: Vec<Rlp> = rlp.iter().collect();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1].data().unwrap(), b"hello");
+    }
+
+    #[test]
+    fn test_rlp_view_nested_list() {
+        let inner = rlp_encode_list(&[rlp_encode_u64(1), rlp_encode_u64(2)]);
+        let outer = rlp_encode_list(&[inner, rlp_encode_u64(3)]);
+
+        let rlp = Rlp::new(&outer);
+        let child = rlp.at(0).unwrap();
+        assert!(child.is_list());
+        assert_eq!(child.item_count().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_rlp_view_never_panics_on_malformed_input() {
+        // Mirrors the existing `fuzz_*_never_panics` invariants: truncated /
+        // overlong length headers must surface as `Err`, never a panic.
+        let truncated_long_string = [0xbb, 0xFF, 0xFF, 0xFF, 0xFF];
+        let rlp = Rlp::new(&truncated_long_string);
+        assert!(rlp.data().is_err());
+
+        let empty: [u8; 0] = [];
+        let rlp = Rlp::new(&empty);
+        assert!(rlp.data().is_err());
+        assert!(rlp.at(0).is_err());
+        assert!(rlp.item_count().is_err());
+    }
+
+    // -----------------------------------------------------------------------
+    // DecoderError / payload_info
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_decoder_error_variants_are_structured() {
+        let list_bytes = encoded_list();
+        let rlp = Rlp::new(&list_bytes);
+        assert_eq!(rlp.data().unwrap_err(), DecoderError::RlpExpectedToBeData);
+
+        let data_bytes = rlp_encode_u64(7);
+        let rlp = Rlp::new(&data_bytes);
+        match rlp.at(0) {
+            Err(DecoderError::RlpExpectedToBeList) => {}
+            Err(other) => panic!("expected RlpExpectedToBeList, got {other:?}"),
+            Ok(_) => panic!("expected an error"),
+        }
+        assert_eq!(rlp.item_count().unwrap_err(), DecoderError::RlpExpectedToBeList);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(Rlp::new(&empty).data().unwrap_err(), DecoderError::RlpIsTooShort);
+    }
+
+    #[test]
+    fn test_decoder_error_rejects_non_canonical_long_length() {
+        // 0xb8 0x01 "x" — long-form header encoding a 1-byte value, which
+        // should have used the short form (0x81 "x") instead.
+        let non_canonical = [0xb8u8, 0x01, b'x'];
+        let rlp = Rlp::new(&non_canonical);
+        assert_eq!(rlp.data().unwrap_err(), DecoderError::NonCanonicalLengthEncoding);
+    }
+
+    #[test]
+    fn test_decoder_error_overflow_variants() {
+        let too_big = rlp_encode_bytes(&[0xFFu8; 9]);
+        assert_eq!(
+            <u64 as Decodable>::decode(&Rlp::new(&too_big)).unwrap_err(),
+            DecoderError::RlpOverflowsU64
+        );
+
+        let too_big = rlp_encode_bytes(&[0xFFu8; 17]);
+        assert_eq!(
+            <u128 as Decodable>::decode(&Rlp::new(&too_big)).unwrap_err(),
+            DecoderError::RlpOverflowsU256
+        );
+    }
+
+    #[test]
+    fn test_payload_info_reports_header_and_value_len() {
+        let short = rlp_encode_bytes(b"hello");
+        let info = payload_info(&short).unwrap();
+        assert_eq!(info, PayloadInfo { header_len: 1, value_len: 5 });
+
+        let long = rlp_encode_bytes(&[0u8; 200]);
+        let info = payload_info(&long).unwrap();
+        assert_eq!(info.value_len, 200);
+        assert_eq!(info.header_len + info.value_len, long.len());
+    }
+
+    // -----------------------------------------------------------------------
+    // Encodable / Decodable
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_encodable_decodable_u64_roundtrip() {
+        for val in [0u64, 1, 127, 128, u64::MAX] {
+            let bytes = val.rlp_bytes();
+            let rlp = Rlp::new(&bytes);
+            assert_eq!(u64::decode(&rlp).unwrap(), val);
+        }
+    }
+
+    #[test]
+    fn test_encodable_decodable_u128_roundtrip() {
+        let val: u128 = 1_000_000_000_000_000_000;
+        let bytes = val.rlp_bytes();
+        let rlp = Rlp::new(&bytes);
+        assert_eq!(u128::decode(&rlp).unwrap(), val);
+    }
+
+    #[test]
+    fn test_encodable_decodable_fixed_arrays_roundtrip() {
+        let addr = [0xABu8; 20];
+        let bytes = addr.rlp_bytes();
+        assert_eq!(<[u8; 20]>::decode(&Rlp::new(&bytes)).unwrap(), addr);
+
+        let word = [0xCDu8; 32];
+        let bytes = word.rlp_bytes();
+        assert_eq!(<[u8; 32]>::decode(&Rlp::new(&bytes)).unwrap(), word);
+    }
+
+    #[test]
+    fn test_encodable_decodable_vec_u8_roundtrip() {
+        let data = b"hello world".to_vec();
+        let bytes = data.rlp_bytes();
+        assert_eq!(Vec::<u8>::decode(&Rlp::new(&bytes)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_encodable_decodable_vec_of_u64_roundtrip() {
+        let values: Vec<u64> = vec![1, 2, 3, 4];
+        let bytes = values.rlp_bytes();
+        assert_eq!(Vec::<u64>::decode(&Rlp::new(&bytes)).unwrap(), values);
+    }
+
+    #[test]
+    fn test_decodable_fixed_array_rejects_wrong_length() {
+        let bytes = b"too short".to_vec().rlp_bytes();
+        assert!(<[u8; 20]>::decode(&Rlp::new(&bytes)).is_err());
+    }
+
+    // -----------------------------------------------------------------------
+    // rlp_iter_items / rlp_decode_list
+    // -----------------------------------------------------------------------
+
+    fn list_payload() -> Vec<u8> {
+        // Payload bytes of [42, "hello", ""] — i.e. what's inside the 0xc.. header.
+        let encoded = encoded_list();
+        let (_, header_len, payload_len) = decode_header(&encoded).unwrap();
+        encoded[header_len..header_len + payload_len].to_vec()
+    }
+
+    #[test]
+    fn test_rlp_iter_items_matches_decode_list() {
+        let payload = list_payload();
+        let via_iter: Result<Vec<Vec<u8>>, DecoderError> =
+            rlp_iter_items(&payload).map(|r| r.map(|(slice, _)| slice.to_vec())).collect();
+        let via_decode_list = rlp_decode_list(&payload);
+        assert_eq!(via_iter, via_decode_list);
+        assert_eq!(via_decode_list.unwrap(), vec![vec![42u8], b"hello".to_vec(), Vec::<u8>::new()]);
+    }
+
+    #[test]
+    fn test_rlp_iter_items_borrows_without_copying() {
+        let payload = list_payload();
+        let items: Vec<(&[u8], bool)> = rlp_iter_items(&payload).collect::<Result<_, _>>().unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1].0, b"hello");
+        assert!(!items[1].1);
+    }
+
+    #[test]
+    fn test_rlp_iter_items_reports_nested_lists() {
+        let inner = rlp_encode_list(&[rlp_encode_u64(1)]);
+        let outer_payload = {
+            let mut p = Vec::new();
+            p.extend_from_slice(&inner);
+            p.extend_from_slice(&rlp_encode_u64(2));
+            p
+        };
+        let items: Vec<(&[u8], bool)> =
+            rlp_iter_items(&outer_payload).collect::<Result<_, _>>().unwrap();
+        assert_eq!(items.len(), 2);
+        assert!(items[0].1); // nested list
+        assert!(!items[1].1); // plain integer
+    }
+
+    #[test]
+    fn test_rlp_iter_items_never_panics_on_truncated_input() {
+        // A string prefix claiming more bytes than actually follow.
+        let truncated = [0x85u8, b'h', b'i']; // claims 5 bytes, only 2 present
+        let results: Vec<_> = rlp_iter_items(&truncated).collect();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
 }
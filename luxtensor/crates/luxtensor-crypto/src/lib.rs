@@ -13,6 +13,18 @@ use luxtensor_types::{Address, Hash, Signature, Result, LuxTensorError};
 use rand::rngs::OsRng;
 use sha3::{Digest, Keccak256};
 
+/// RFC 6979 HMAC-DRBG deterministic nonce generation, shared by the VRF
+/// suites below.
+mod nonce;
+
+/// EC-VRF (secp256k1) — verified by `luxtensor-node` when validating a
+/// block's proposer-selection proof.
+pub mod vrf;
+
+/// Pluggable cipher-suite abstraction over [`vrf`] — secp256k1, P-256, and
+/// Ed25519 behind one generic `prove`/`verify` API.
+pub mod vrf_suites;
+
 /// Key pair for signing transactions
 pub struct KeyPair {
     keypair: Keypair,
@@ -0,0 +1,77 @@
+//! RFC 6979 §3.2 / RFC 9381 §5.4.2.2 deterministic nonce generation via
+//! HMAC-DRBG, shared by every VRF suite's `prove` in this crate.
+//!
+//! Replaces the old `reduce_bytes(hash(sk || alpha || tag))` construction,
+//! which reduces a single hash output modulo the group order and so
+//! introduces modular bias. HMAC-DRBG instead retries until the raw bytes
+//! decode to a value already in range, which is unbiased.
+//!
+//! For secp256k1 and P-256 the scalar field order is exactly 256 bits (qlen
+//! == hlen for SHA-256), so RFC 6979's `int2octets`/`bits2octets` collapse to
+//! a plain 32-byte encoding — this implementation takes that shortcut rather
+//! than the fully general bit-truncating versions the RFC defines for curves
+//! where qlen != hlen.
+//
+// Add to Cargo.toml: hmac = "0.12"
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use sha3::Keccak256;
+use zeroize::Zeroize;
+
+/// HMAC-SHA256 of the concatenation of `parts` under `key`.
+pub(crate) fn hmac_sha256(key: &[u8; 32], parts: &[&[u8]]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+/// HMAC-Keccak256 of the concatenation of `parts` under `key`, for the
+/// suite that otherwise hashes with keccak256.
+pub(crate) fn hmac_keccak256(key: &[u8; 32], parts: &[&[u8]]) -> [u8; 32] {
+    let mut mac = Hmac::<Keccak256>::new_from_slice(key).expect("HMAC accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+/// RFC 6979 §3.2 steps (a)-(h): derive a deterministic nonce from `sk_bytes`
+/// and `h1 = Hash(alpha)`, retrying with the "otherwise" branch until
+/// `try_scalar` accepts a candidate — i.e. the raw bytes decode to a value
+/// in `[1, n)` for the target scalar field. `hmac` computes `HMAC_K(parts)`
+/// for whichever hash the calling suite uses.
+///
+/// All intermediate `K`/`V` buffers are zeroized before returning.
+pub(crate) fn rfc6979_generate<S>(
+    sk_bytes: &[u8; 32],
+    h1: &[u8; 32],
+    mut hmac: impl FnMut(&[u8; 32], &[&[u8]]) -> [u8; 32],
+    mut try_scalar: impl FnMut(&[u8; 32]) -> Option<S>,
+) -> S {
+    let mut k = [0x00u8; 32];
+    let mut v = [0x01u8; 32];
+
+    // Steps (d)-(g): two priming rounds seeded with 0x00 then 0x01.
+    k = hmac(&k, &[&v, &[0x00], sk_bytes, h1]);
+    v = hmac(&k, &[&v]);
+    k = hmac(&k, &[&v, &[0x01], sk_bytes, h1]);
+    v = hmac(&k, &[&v]);
+
+    // Step (h): generate candidates, retrying via the "otherwise" branch
+    // until one decodes to an in-range scalar.
+    let result = loop {
+        v = hmac(&k, &[&v]);
+        if let Some(scalar) = try_scalar(&v) {
+            break scalar;
+        }
+        k = hmac(&k, &[&v, &[0x00]]);
+        v = hmac(&k, &[&v]);
+    };
+
+    k.zeroize();
+    v.zeroize();
+    result
+}
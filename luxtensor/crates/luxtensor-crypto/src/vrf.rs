@@ -17,14 +17,37 @@
 //! - Output: keccak256(gamma_compressed)
 //!
 //! Uses the `k256` crate for constant-time secp256k1 arithmetic.
+//!
+//! [`vrf_sortition`] additionally needs exact rational arithmetic — see its
+//! doc comment for why.
+//
+// Add to Cargo.toml: num-bigint = "0.4"
+// num-rational = "0.4"
+// num-traits = "0.2"
 
 use crate::keccak256;
+use num_bigint::{BigInt, BigUint};
+use num_rational::BigRational;
+use num_traits::One;
 use k256::{
-    elliptic_curve::{group::GroupEncoding, ops::Reduce, sec1::ToEncodedPoint},
+    elliptic_curve::{
+        group::{Group, GroupEncoding},
+        ops::Reduce,
+        sec1::ToEncodedPoint,
+    },
     AffinePoint, ProjectivePoint, Scalar,
 };
+use sha2::{Digest, Sha256};
 use zeroize::Zeroize;
 
+/// Single-byte suite identifier for ECVRF-SECP256K1-SHA256-TAI.
+///
+/// RFC 9381 itself only registers suite strings for P-256 and Ed25519; `0xFE`
+/// is the value the community has settled on for secp256k1 (e.g. witnet's
+/// `vrf-rs`), used here so proofs from [`VrfKeypair::prove_rfc9381`]
+/// interoperate with other implementations of that same de facto suite.
+const RFC9381_SUITE_STRING: u8 = 0xFE;
+
 /// VRF output hash type (32 bytes)
 pub type VrfOutput = [u8; 32];
 
@@ -105,6 +128,27 @@ impl Drop for VrfKeypair {
     }
 }
 
+/// Reject degenerate public keys: the point at infinity (which has no valid
+/// SEC1 encoding and so should never decode successfully in the first place)
+/// and the generator point itself (secret key == 1), a "weak key" analogous
+/// to the small-order blacklists shipped by Edwards-curve VRF implementations
+/// — trivially guessable and a red flag that key generation went wrong.
+fn reject_weak_public_key(pk_point: &ProjectivePoint) -> Result<(), VrfError> {
+    if bool::from(pk_point.is_identity()) || *pk_point == ProjectivePoint::GENERATOR {
+        return Err(VrfError::InvalidPublicKey);
+    }
+    Ok(())
+}
+
+/// Decode a 32-byte scalar encoding, rejecting non-canonical representations
+/// (i.e. integers >= the curve order `n`). Unlike `Reduce::reduce_bytes`,
+/// which silently wraps out-of-range values modulo `n`, this makes `c`/`s`
+/// encodings unique per scalar — two distinct 32-byte strings can no longer
+/// verify as the same proof.
+fn decode_canonical_scalar(bytes: &[u8; 32]) -> Result<Scalar, VrfError> {
+    Option::<Scalar>::from(Scalar::from_repr((*bytes).into())).ok_or(VrfError::InvalidProof)
+}
+
 /// Hash-to-curve: deterministically map arbitrary bytes to a secp256k1 point.
 /// Uses try-and-increment (TAI) per RFC 9381 §5.4.1.
 /// Returns an error if no valid curve point is found after 256 attempts.
@@ -164,11 +208,87 @@ fn compute_challenge(
     <Scalar as Reduce<k256::U256>>::reduce_bytes(&c_bytes.into())
 }
 
+/// RFC 9381 §5.4.1.2 hash-to-curve (try-and-increment) for the
+/// ECVRF-SECP256K1-SHA256-TAI suite exactly: SHA-256 instead of keccak256,
+/// and (unlike [`hash_to_curve`]) the RFC's trailing `0x00` octet after the
+/// counter byte.
+pub(crate) fn hash_to_curve_rfc9381(pk_bytes: &[u8], alpha: &[u8]) -> Result<ProjectivePoint, VrfError> {
+    for ctr in 0u8..=255 {
+        let mut input = Vec::with_capacity(3 + pk_bytes.len() + alpha.len() + 1);
+        input.push(RFC9381_SUITE_STRING);
+        input.push(0x01); // hash_to_curve domain separator
+        input.extend_from_slice(pk_bytes);
+        input.extend_from_slice(alpha);
+        input.push(ctr);
+        input.push(0x00); // RFC 9381 trailing zero octet
+
+        let hash: [u8; 32] = Sha256::digest(&input).into();
+
+        let mut compressed = [0u8; 33];
+        compressed[0] = 0x02;
+        compressed[1..33].copy_from_slice(&hash);
+
+        let ct_opt = AffinePoint::from_bytes(&compressed.into());
+        if bool::from(ct_opt.is_some()) {
+            let pt: AffinePoint = ct_opt.unwrap();
+            return Ok(ProjectivePoint::from(pt));
+        }
+    }
+    Err(VrfError::HashToCurveFailed)
+}
+
+/// RFC 9381 §5.4.3 challenge generation for ECVRF-SECP256K1-SHA256-TAI:
+/// c = truncate(SHA256(suite_string || 0x02 || P1 || P2 || P3 || P4 || 0x00), cLen)
+/// with `cLen = 16` bytes for a 32-byte (`qLen`) curve order, matching
+/// [`compute_challenge`]'s truncation but with the RFC's exact byte layout.
+pub(crate) fn compute_challenge_rfc9381(
+    pk_compressed: &[u8; 33],
+    h_point: &ProjectivePoint,
+    gamma: &ProjectivePoint,
+    k_g: &ProjectivePoint,
+    k_h: &ProjectivePoint,
+) -> Scalar {
+    let encode = |p: &ProjectivePoint| -> Vec<u8> {
+        p.to_affine().to_encoded_point(true).as_bytes().to_vec()
+    };
+
+    let mut input = Vec::with_capacity(2 + 33 * 4 + 1);
+    input.push(RFC9381_SUITE_STRING);
+    input.push(0x02); // challenge_generation domain separator
+    input.extend_from_slice(pk_compressed);
+    input.extend_from_slice(&encode(h_point));
+    input.extend_from_slice(&encode(gamma));
+    input.extend_from_slice(&encode(k_g));
+    input.extend_from_slice(&encode(k_h));
+    input.push(0x00); // RFC 9381 trailing zero octet
+
+    let hash: [u8; 32] = Sha256::digest(&input).into();
+
+    let mut c_bytes = [0u8; 32];
+    c_bytes[16..32].copy_from_slice(&hash[0..16]);
+    <Scalar as Reduce<k256::U256>>::reduce_bytes(&c_bytes.into())
+}
+
+/// RFC 9381 §5.2 `proof_to_hash` for ECVRF-SECP256K1-SHA256-TAI:
+/// beta = SHA256(suite_string || 0x03 || point_to_string(cofactor * Gamma) || 0x00).
+/// secp256k1's cofactor is 1, so `cofactor * Gamma == Gamma` and no extra
+/// scalar multiplication is needed before encoding.
+pub(crate) fn proof_to_hash_rfc9381(gamma_compressed: &[u8; 33]) -> VrfOutput {
+    let mut input = Vec::with_capacity(2 + gamma_compressed.len() + 1);
+    input.push(RFC9381_SUITE_STRING);
+    input.push(0x03); // proof_to_hash domain separator
+    input.extend_from_slice(gamma_compressed);
+    input.push(0x00); // RFC 9381 trailing zero octet
+    Sha256::digest(&input).into()
+}
+
 impl VrfKeypair {
     /// Generate a new VRF keypair from a 32-byte seed.
     /// Derives a secp256k1 secret key deterministically.
     ///
-    /// Returns an error if the seed reduces to the zero scalar (e.g. all-zero seed).
+    /// Returns an error if the seed reduces to the zero scalar (e.g. all-zero
+    /// seed), or to a weak key — the point at infinity or the generator
+    /// itself (`sk == 1`).
     pub fn from_seed(seed: &[u8; 32]) -> Result<Self, VrfError> {
         // Derive secret key scalar from seed
         let sk = <Scalar as Reduce<k256::U256>>::reduce_bytes(&(*seed).into());
@@ -180,6 +300,9 @@ impl VrfKeypair {
         }
 
         let pk_point = ProjectivePoint::GENERATOR * sk;
+        // SECURITY: reject degenerate/weak keys (point at infinity, sk == 1)
+        reject_weak_public_key(&pk_point)?;
+
         let pk_affine = pk_point.to_affine();
         let pk_encoded = pk_affine.to_encoded_point(true);
         let pk_bytes_full = pk_encoded.as_bytes();
@@ -207,18 +330,22 @@ impl VrfKeypair {
         // Step 2: Gamma = sk * H
         let gamma = h * self.secret_key;
 
-        // Step 3: Choose random nonce k (deterministic: k = H(sk || alpha || "nonce"))
-        let mut k_input = Vec::with_capacity(64 + alpha.len());
+        // Step 3: RFC 6979 / RFC 9381 §5.4.2.2 deterministic nonce, HMAC-keyed
+        // with this module's keccak256 (matching its original hash choice).
         let mut sk_bytes: [u8; 32] = self.secret_key.to_bytes().into();
-        k_input.extend_from_slice(&sk_bytes);
-        k_input.extend_from_slice(alpha);
-        k_input.extend_from_slice(b"ECVRF_nonce");
-        let k_hash = keccak256(&k_input);
-        let k = <Scalar as Reduce<k256::U256>>::reduce_bytes(&k_hash.into());
+        let h1 = keccak256(alpha);
+        let k = crate::nonce::rfc6979_generate(
+            &sk_bytes,
+            &h1,
+            crate::nonce::hmac_keccak256,
+            |candidate| {
+                Option::<Scalar>::from(Scalar::from_repr((*candidate).into()))
+                    .filter(|s| !bool::from(s.is_zero()))
+            },
+        );
 
         // SECURITY: Zeroize secret key material from heap
         sk_bytes.zeroize();
-        k_input.zeroize();
 
         // Step 4: U = k * G, V = k * H
         let u = ProjectivePoint::GENERATOR * k;
@@ -245,6 +372,65 @@ impl VrfKeypair {
         let proof = VrfProof::new_ec(gamma_compressed, c_bytes, s_bytes);
         Ok((output, proof))
     }
+
+    /// RFC 9381-conformant counterpart to [`prove`](Self::prove): same EC-VRF
+    /// structure and same `VrfProof` encoding, but hash-to-curve, challenge
+    /// generation, and `proof_to_hash` all follow ECVRF-SECP256K1-SHA256-TAI
+    /// exactly (SHA-256 throughout, with the suite/domain-separator octets
+    /// and trailing zero byte the RFC specifies) instead of this module's
+    /// original keccak256-based scheme.
+    ///
+    /// Use this when a proof needs to verify against an RFC 9381-only
+    /// implementation; [`prove`](Self::prove) remains the default for
+    /// proofs that never leave this codebase.
+    pub fn prove_rfc9381(&self, alpha: &[u8]) -> Result<(VrfOutput, VrfProof), VrfError> {
+        // Step 1: H = hash_to_curve(pk, alpha)
+        let h = hash_to_curve_rfc9381(&self.public_key_compressed, alpha)?;
+
+        // Step 2: Gamma = sk * H
+        let gamma = h * self.secret_key;
+
+        // Step 3: RFC 6979 / RFC 9381 §5.4.2.2 deterministic nonce, HMAC-keyed
+        // with SHA-256 (matching this suite's hash).
+        let mut sk_bytes: [u8; 32] = self.secret_key.to_bytes().into();
+        let h1: [u8; 32] = Sha256::digest(alpha).into();
+        let k = crate::nonce::rfc6979_generate(
+            &sk_bytes,
+            &h1,
+            crate::nonce::hmac_sha256,
+            |candidate| {
+                Option::<Scalar>::from(Scalar::from_repr((*candidate).into()))
+                    .filter(|s| !bool::from(s.is_zero()))
+            },
+        );
+
+        // SECURITY: Zeroize secret key material from heap
+        sk_bytes.zeroize();
+
+        // Step 4: U = k * G, V = k * H
+        let u = ProjectivePoint::GENERATOR * k;
+        let v = h * k;
+
+        // Step 5: c = challenge(pk, H, Gamma, U, V)
+        let c = compute_challenge_rfc9381(&self.public_key_compressed, &h, &gamma, &u, &v);
+
+        // Step 6: s = k - c * sk (mod n)
+        let s = k - c * self.secret_key;
+
+        // Step 7: output = proof_to_hash(Gamma)
+        let gamma_encoded = gamma.to_affine().to_encoded_point(true);
+        let gamma_bytes = gamma_encoded.as_bytes();
+        let mut gamma_compressed = [0u8; 33];
+        gamma_compressed.copy_from_slice(gamma_bytes);
+
+        let output = proof_to_hash_rfc9381(&gamma_compressed);
+
+        let c_bytes: [u8; 32] = c.to_bytes().into();
+        let s_bytes: [u8; 32] = s.to_bytes().into();
+
+        let proof = VrfProof::new_ec(gamma_compressed, c_bytes, s_bytes);
+        Ok((output, proof))
+    }
 }
 
 /// Verify an EC-VRF proof against a public key and input alpha.
@@ -285,6 +471,8 @@ pub fn vrf_verify(
         } else {
             continue;
         };
+        // SECURITY: reject degenerate/weak public keys
+        reject_weak_public_key(&pk_point)?;
 
         // Reconstruct the full compressed public key
         let pk_affine = pk_point.to_affine();
@@ -304,10 +492,17 @@ pub fn vrf_verify(
                 return Err(VrfError::InvalidProof);
             }
         };
+        // SECURITY: reject gamma at infinity (no valid full-order proof maps here)
+        if bool::from(gamma.is_identity()) {
+            return Err(VrfError::InvalidProof);
+        }
 
-        // Decode c and s as scalars
-        let c = <Scalar as Reduce<k256::U256>>::reduce_bytes(&proof.c.into());
-        let s = <Scalar as Reduce<k256::U256>>::reduce_bytes(&proof.s.into());
+        // Decode c and s as canonical scalars (< n) — rejects the
+        // non-canonical encodings that `reduce_bytes` would silently wrap,
+        // which would otherwise let two distinct 32-byte strings verify as
+        // the same proof.
+        let c = decode_canonical_scalar(&proof.c)?;
+        let s = decode_canonical_scalar(&proof.s)?;
 
         // H = hash_to_curve(pk, alpha)
         let h = hash_to_curve(&pk_full, alpha)?;
@@ -340,6 +535,85 @@ pub fn vrf_verify(
     Err(VrfError::VerificationFailed)
 }
 
+/// RFC 9381-conformant counterpart to [`vrf_verify`]; see
+/// [`VrfKeypair::prove_rfc9381`]. Accepts only proofs produced by
+/// `prove_rfc9381` — a proof produced by the legacy keccak256-based `prove`
+/// will fail its challenge check here, and vice versa.
+pub fn vrf_verify_rfc9381(
+    public_key: &[u8; 32],
+    alpha: &[u8],
+    proof: &VrfProof,
+) -> Result<VrfOutput, VrfError> {
+    if proof.gamma == [0u8; 32] {
+        return Err(VrfError::InvalidProof);
+    }
+    if proof.s == [0u8; 32] && proof.c == [0u8; 32] {
+        return Err(VrfError::InvalidProof);
+    }
+
+    for prefix in [0x02u8, 0x03u8] {
+        let mut compressed_pk = [0u8; 33];
+        compressed_pk[0] = prefix;
+        compressed_pk[1..33].copy_from_slice(public_key);
+
+        let opt = AffinePoint::from_bytes(&compressed_pk.into());
+        let pk_point = if bool::from(opt.is_some()) {
+            let pt: AffinePoint = opt.unwrap();
+            ProjectivePoint::from(pt)
+        } else {
+            continue;
+        };
+        // SECURITY: reject degenerate/weak public keys
+        reject_weak_public_key(&pk_point)?;
+
+        let pk_affine = pk_point.to_affine();
+        let pk_enc = pk_affine.to_encoded_point(true);
+        let pk_bytes = pk_enc.as_bytes();
+        let mut pk_full = [0u8; 33];
+        pk_full.copy_from_slice(pk_bytes);
+
+        let gamma = {
+            let gc = &proof.gamma_compressed;
+            let gopt = AffinePoint::from_bytes(&(*gc).into());
+            if bool::from(gopt.is_some()) {
+                let pt: AffinePoint = gopt.unwrap();
+                ProjectivePoint::from(pt)
+            } else {
+                return Err(VrfError::InvalidProof);
+            }
+        };
+        // SECURITY: reject gamma at infinity (no valid full-order proof maps here)
+        if bool::from(gamma.is_identity()) {
+            return Err(VrfError::InvalidProof);
+        }
+
+        // Decode c and s as canonical scalars (< n); see vrf_verify's comment.
+        let c = decode_canonical_scalar(&proof.c)?;
+        let s = decode_canonical_scalar(&proof.s)?;
+
+        let h = hash_to_curve_rfc9381(&pk_full, alpha)?;
+
+        let u = ProjectivePoint::GENERATOR * s + pk_point * c;
+        let v = h * s + gamma * c;
+
+        let c_prime = compute_challenge_rfc9381(&pk_full, &h, &gamma, &u, &v);
+
+        let c_prime_bytes: [u8; 32] = c_prime.to_bytes().into();
+        let c_bytes: [u8; 32] = c.to_bytes().into();
+
+        let mut diff = 0u8;
+        for (a, b) in c_prime_bytes.iter().zip(c_bytes.iter()) {
+            diff |= a ^ b;
+        }
+        if diff == 0 {
+            let output = proof_to_hash_rfc9381(&proof.gamma_compressed);
+            return Ok(output);
+        }
+    }
+
+    Err(VrfError::VerificationFailed)
+}
+
 /// Convert VRF gamma point to output hash
 fn gamma_to_output(gamma_compressed: &[u8]) -> VrfOutput {
     // Use full compressed point (33 bytes) + domain separator
@@ -378,6 +652,100 @@ pub fn calculate_selection_threshold(stake: u128, total_stake: u128) -> u64 {
     result.min(max_val) as u64
 }
 
+/// Raise a [`BigRational`] to an integer power by repeated squaring, so that
+/// computing `(1-p)^w` for a large stake `w` doesn't cost `w` multiplications.
+fn rational_pow(base: &BigRational, mut exp: u64) -> BigRational {
+    let mut result = BigRational::one();
+    let mut b = base.clone();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = &result * &b;
+        }
+        b = &b * &b;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Algorand-style stake-weighted cryptographic sortition: how many of a
+/// validator's `w` units of stake won a committee seat, given total stake
+/// `total_stake` and expected committee size `tau`.
+///
+/// Unlike [`calculate_selection_threshold`]/[`vrf_output_below_threshold`],
+/// which decide a single binary "selected or not", this lets a
+/// high-stake validator win *multiple* seats in one VRF output — the
+/// construction used by Algorand's cryptographic sortition so a committee's
+/// expected size stays proportional to participating stake regardless of how
+/// stake is distributed among validators.
+///
+/// Sets `p = tau / total_stake` and treats the VRF output as a uniform
+/// sample `x = output / 2^256` in `[0, 1)`. The number of seats won is the
+/// unique `j` in `0..=w` such that `x` falls in the binomial CDF interval
+/// `[ F(j-1; w,p), F(j; w,p) )`, where `F` is the CDF of `Binomial(w, p)`.
+/// The cumulative sum is built incrementally from the `k=0` term
+/// `(1-p)^w`, multiplying by `(w-k)/(k+1) * p/(1-p)` at each step, so no
+/// binomial coefficient `C(w,k)` is ever computed directly.
+///
+/// Uses exact rational arithmetic (`num-bigint`/`num-rational`) rather than
+/// floating point: every honest validator must derive the same `j` from the
+/// same output, and `f64` rounding is neither guaranteed reproducible across
+/// platforms nor exact enough near interval boundaries — either would be a
+/// consensus split.
+pub fn vrf_sortition(output: &VrfOutput, w: u64, total_stake: u128, tau: u64) -> u64 {
+    if w == 0 || total_stake == 0 || tau == 0 {
+        return 0;
+    }
+
+    let p = BigRational::new(BigInt::from(tau), BigInt::from(total_stake));
+    // tau >= total_stake: every unit of stake is selected with certainty.
+    if p >= BigRational::one() {
+        return w;
+    }
+
+    let one_minus_p = BigRational::one() - &p;
+    let ratio = &p / &one_minus_p;
+
+    let x_numer = BigInt::from(BigUint::from_bytes_be(output));
+    let x_denom = BigInt::from(1u8) << 256;
+    let x = BigRational::new(x_numer, x_denom);
+
+    // k = 0 term: C(w,0) p^0 (1-p)^w = (1-p)^w.
+    let mut term = rational_pow(&one_minus_p, w);
+    let mut cdf = term.clone();
+
+    if x < cdf {
+        return 0;
+    }
+
+    for j in 1..=w {
+        // term_{k+1} = term_k * (w-k)/(k+1) * p/(1-p), with k = j - 1.
+        let coeff = BigRational::new(BigInt::from(w - j + 1), BigInt::from(j));
+        term = &term * &coeff * &ratio;
+        cdf = &cdf + &term;
+        if x < cdf || j == w {
+            return j;
+        }
+    }
+
+    w
+}
+
+/// Verify a VRF proof and, if valid, recompute the number of committee seats
+/// it won — so a peer can check a claimed seat count `claimed_seats` without
+/// trusting the prover's own tally.
+pub fn verify_vrf_sortition(
+    public_key: &[u8; 32],
+    alpha: &[u8],
+    proof: &VrfProof,
+    w: u64,
+    total_stake: u128,
+    tau: u64,
+    claimed_seats: u64,
+) -> Result<bool, VrfError> {
+    let output = vrf_verify(public_key, alpha, proof)?;
+    Ok(vrf_sortition(&output, w, total_stake, tau) == claimed_seats)
+}
+
 /// VRF errors
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VrfError {
@@ -502,6 +870,51 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_weak_key_generator_seed_rejected() {
+        // sk == 1 reduces directly from the seed `[0,...,0,1]`, making the
+        // public key equal to the generator — a degenerate, trivially
+        // guessable key.
+        let mut seed = [0u8; 32];
+        seed[31] = 1;
+        let result = VrfKeypair::from_seed(&seed);
+        assert_eq!(result.unwrap_err(), VrfError::InvalidPublicKey);
+    }
+
+    #[test]
+    fn test_verify_rejects_generator_public_key() {
+        // A caller-supplied public key equal to the generator point must be
+        // rejected up front, regardless of what proof accompanies it.
+        let keypair = VrfKeypair::from_seed(&[42u8; 32]).unwrap();
+        let (_output, proof) = keypair.prove(b"test_input").unwrap();
+
+        let generator_pk: [u8; 32] = ProjectivePoint::GENERATOR
+            .to_affine()
+            .to_encoded_point(true)
+            .as_bytes()[1..33]
+            .try_into()
+            .unwrap();
+
+        let result = vrf_verify(&generator_pk, b"test_input", &proof);
+        assert_eq!(result.unwrap_err(), VrfError::InvalidPublicKey);
+    }
+
+    #[test]
+    fn test_verify_rejects_non_canonical_scalar() {
+        // c/s bytes encoding an integer >= the curve order `n` must be
+        // rejected rather than silently wrapped by `reduce_bytes`, which
+        // would otherwise let distinct encodings verify as the same proof.
+        let keypair = VrfKeypair::from_seed(&[42u8; 32]).unwrap();
+        let (_output, mut proof) = keypair.prove(b"test_input").unwrap();
+
+        // secp256k1 order n = 0xFFFFFFFF...FFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141;
+        // 0xFF repeated is well above n and so is never a canonical encoding.
+        proof.s = [0xFFu8; 32];
+
+        let result = vrf_verify(&keypair.public_key, b"test_input", &proof);
+        assert_eq!(result.unwrap_err(), VrfError::InvalidProof);
+    }
+
     #[test]
     fn test_proof_serialization() {
         let proof = VrfProof::new(0x02, [1u8; 32], [2u8; 32], [3u8; 32]);
@@ -571,6 +984,167 @@ mod tests {
         assert_eq!(threshold_0, 0);
     }
 
+    // ── RFC 9381 (ECVRF-SECP256K1-SHA256-TAI) suite ──
+    //
+    // No official RFC 9381 test vectors exist for the secp256k1 suite (the
+    // appendix only covers P-256 and Ed25519), so these are self-consistency
+    // checks rather than vector-based conformance tests: determinism, a
+    // clean prove/verify roundtrip, and rejection of cross-suite proofs.
+
+    #[test]
+    fn test_rfc9381_prove_determinism() {
+        let keypair = VrfKeypair::from_seed(&[7u8; 32]).unwrap();
+        let input = b"rfc9381_slot_9";
+
+        let (output1, proof1) = keypair.prove_rfc9381(input).unwrap();
+        let (output2, proof2) = keypair.prove_rfc9381(input).unwrap();
+
+        assert_eq!(output1, output2);
+        assert_eq!(proof1, proof2);
+    }
+
+    #[test]
+    fn test_rfc9381_roundtrip_prove_verify() {
+        let keypair = VrfKeypair::from_seed(&[7u8; 32]).unwrap();
+        let input = b"rfc9381_test_input";
+
+        let (output, proof) = keypair.prove_rfc9381(input).unwrap();
+        let verified = vrf_verify_rfc9381(&keypair.public_key, input, &proof).unwrap();
+
+        assert_eq!(output, verified);
+    }
+
+    #[test]
+    fn test_rfc9381_wrong_input_fails() {
+        let keypair = VrfKeypair::from_seed(&[7u8; 32]).unwrap();
+        let (_output, proof) = keypair.prove_rfc9381(b"correct").unwrap();
+
+        assert!(vrf_verify_rfc9381(&keypair.public_key, b"wrong", &proof).is_err());
+    }
+
+    #[test]
+    fn test_rfc9381_output_differs_from_legacy_suite() {
+        // Same keypair and alpha, but the two suites must not collide —
+        // otherwise the domain separation between them is broken.
+        let keypair = VrfKeypair::from_seed(&[7u8; 32]).unwrap();
+        let input = b"same_input_both_suites";
+
+        let (legacy_output, _) = keypair.prove(input).unwrap();
+        let (rfc_output, _) = keypair.prove_rfc9381(input).unwrap();
+
+        assert_ne!(legacy_output, rfc_output);
+    }
+
+    #[test]
+    fn test_rfc9381_proof_rejected_by_legacy_verify() {
+        // A proof produced by one suite must not verify under the other —
+        // they use different hash functions and domain separators.
+        let keypair = VrfKeypair::from_seed(&[7u8; 32]).unwrap();
+        let input = b"cross_suite_input";
+
+        let (_output, rfc_proof) = keypair.prove_rfc9381(input).unwrap();
+        assert!(vrf_verify(&keypair.public_key, input, &rfc_proof).is_err());
+
+        let (_output, legacy_proof) = keypair.prove(input).unwrap();
+        assert!(vrf_verify_rfc9381(&keypair.public_key, input, &legacy_proof).is_err());
+    }
+
+    #[test]
+    fn test_rfc9381_wrong_key_fails() {
+        let keypair1 = VrfKeypair::from_seed(&[7u8; 32]).unwrap();
+        let keypair2 = VrfKeypair::from_seed(&[8u8; 32]).unwrap();
+        let input = b"rfc9381_key_mismatch";
+
+        let (_output, proof) = keypair1.prove_rfc9381(input).unwrap();
+        assert!(vrf_verify_rfc9381(keypair2.public_key(), input, &proof).is_err());
+    }
+
+    #[test]
+    fn test_sortition_zero_inputs_win_no_seats() {
+        let output = [0xABu8; 32];
+        assert_eq!(vrf_sortition(&output, 0, 1000, 10), 0);
+        assert_eq!(vrf_sortition(&output, 100, 0, 10), 0);
+        assert_eq!(vrf_sortition(&output, 100, 1000, 0), 0);
+    }
+
+    #[test]
+    fn test_sortition_certain_selection_wins_all_seats() {
+        // tau >= total_stake: p clamps to 1, so every unit of this
+        // validator's stake is selected.
+        let output = [0x42u8; 32];
+        assert_eq!(vrf_sortition(&output, 50, 1000, 1000), 50);
+        assert_eq!(vrf_sortition(&output, 50, 1000, 5000), 50);
+    }
+
+    #[test]
+    fn test_sortition_max_output_wins_all_seats() {
+        // x -> 1 (the top of the CDF range) should land in the last bucket.
+        let output = [0xFFu8; 32];
+        assert_eq!(vrf_sortition(&output, 20, 10_000, 100), 20);
+    }
+
+    #[test]
+    fn test_sortition_min_output_can_win_no_seats() {
+        // x = 0 always falls below the k=0 term (1-p)^w > 0.
+        let output = [0x00u8; 32];
+        assert_eq!(vrf_sortition(&output, 20, 10_000, 100), 0);
+    }
+
+    #[test]
+    fn test_sortition_seats_non_decreasing_in_output() {
+        // Larger VRF outputs (higher x) should never win fewer seats.
+        let w = 30u64;
+        let total_stake = 10_000u128;
+        let tau = 500u64;
+
+        let mut prev = 0u64;
+        for byte in [0x00u8, 0x10, 0x40, 0x80, 0xC0, 0xFF] {
+            let output = [byte; 32];
+            let seats = vrf_sortition(&output, w, total_stake, tau);
+            assert!(seats >= prev);
+            prev = seats;
+        }
+    }
+
+    #[test]
+    fn test_verify_vrf_sortition_accepts_correct_seat_count() {
+        let keypair = VrfKeypair::from_seed(&[11u8; 32]).unwrap();
+        let input = b"sortition_round_7";
+        let (output, proof) = keypair.prove(input).unwrap();
+
+        let w = 40u64;
+        let total_stake = 100_000u128;
+        let tau = 2_000u64;
+        let seats = vrf_sortition(&output, w, total_stake, tau);
+
+        let result =
+            verify_vrf_sortition(&keypair.public_key, input, &proof, w, total_stake, tau, seats);
+        assert_eq!(result, Ok(true));
+    }
+
+    #[test]
+    fn test_verify_vrf_sortition_rejects_wrong_seat_count() {
+        let keypair = VrfKeypair::from_seed(&[11u8; 32]).unwrap();
+        let input = b"sortition_round_8";
+        let (output, proof) = keypair.prove(input).unwrap();
+
+        let w = 40u64;
+        let total_stake = 100_000u128;
+        let tau = 2_000u64;
+        let seats = vrf_sortition(&output, w, total_stake, tau);
+
+        let result = verify_vrf_sortition(
+            &keypair.public_key,
+            input,
+            &proof,
+            w,
+            total_stake,
+            tau,
+            seats + 1,
+        );
+        assert_eq!(result, Ok(false));
+    }
+
     #[test]
     fn test_vrf_output_threshold_check() {
         let mut output = [0u8; 32];
@@ -0,0 +1,625 @@
+//! Pluggable VRF cipher suites.
+//!
+//! [`vrf`](crate::vrf) hard-wires its `VrfKeypair`/`VrfProof` types to
+//! `k256` (secp256k1). This module adds a [`VrfSuite`] trait — parameterizing
+//! over the group, the hash-to-curve method, and the output hash — plus three
+//! concrete suites, mirroring how established VRF libraries (e.g. Algorand's
+//! `go-algorand`, libsodium forks) ship secp256k1/secp256r1/curve25519 side
+//! by side rather than hard-coding one curve:
+//!
+//! - [`Secp256k1Suite`] — ECVRF-SECP256K1-SHA256-TAI, delegating to the
+//!   RFC 9381 helpers in [`vrf`](crate::vrf) so the two modules stay in sync.
+//! - [`P256Suite`] — ECVRF-P256-SHA256-TAI (RFC 9381's own suite_string
+//!   `0x01`), via the `p256` crate.
+//! - [`Ed25519Suite`] — Edwards25519 with SHA-512, via `curve25519-dalek`,
+//!   with cofactor-8 clearing in `proof_to_hash` as RFC 9381 requires for
+//!   suites over a curve with nontrivial cofactor.
+//!
+//! Callers generic over `S: VrfSuite` get one `prove`/`verify` API
+//! ([`GenericVrfKeypair::prove`] / [`vrf_verify_generic`]) so downstream
+//! consensus code can pick a curve without rewriting proof handling.
+//
+// Add to Cargo.toml: p256 = "0.13"
+// curve25519-dalek is already a workspace dependency (see
+// luxtensor-consensus/src/vrf_key.rs).
+
+use crate::vrf::{VrfError, VrfOutput};
+use std::marker::PhantomData;
+use zeroize::Zeroize;
+
+/// A pluggable EC-VRF cipher suite: the group, hash-to-curve method, and
+/// output hash that together define one concrete construction.
+///
+/// Keys and proofs are opaque byte buffers rather than curve-specific types
+/// so that [`GenericVrfKeypair<S>`] and [`vrf_verify_generic`] stay generic
+/// over `S` without leaking `k256`/`p256`/`curve25519-dalek` types into
+/// callers.
+pub trait VrfSuite {
+    /// Derive a (secret_key_bytes, public_key_bytes) pair from a 32-byte seed.
+    /// Returns an error if the seed reduces to a degenerate (zero) key.
+    fn keypair_from_seed(seed: &[u8; 32]) -> Result<(Vec<u8>, Vec<u8>), VrfError>;
+
+    /// Produce a proof and VRF output for `alpha` under `secret`/`public`.
+    fn prove(secret: &[u8], public: &[u8], alpha: &[u8]) -> Result<(VrfOutput, Vec<u8>), VrfError>;
+
+    /// Verify `proof` against `public`/`alpha`, returning the output on success.
+    fn verify(public: &[u8], alpha: &[u8], proof: &[u8]) -> Result<VrfOutput, VrfError>;
+}
+
+/// A VRF keypair generic over its cipher suite `S`.
+pub struct GenericVrfKeypair<S: VrfSuite> {
+    secret: Vec<u8>,
+    public: Vec<u8>,
+    _suite: PhantomData<S>,
+}
+
+impl<S: VrfSuite> Drop for GenericVrfKeypair<S> {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl<S: VrfSuite> GenericVrfKeypair<S> {
+    /// Derive a keypair from a 32-byte seed.
+    pub fn from_seed(seed: &[u8; 32]) -> Result<Self, VrfError> {
+        let (secret, public) = S::keypair_from_seed(seed)?;
+        Ok(Self { secret, public, _suite: PhantomData })
+    }
+
+    /// The suite-specific compressed public key bytes.
+    pub fn public_key(&self) -> &[u8] {
+        &self.public
+    }
+
+    /// Prove: generate a VRF proof and output for `alpha`.
+    pub fn prove(&self, alpha: &[u8]) -> Result<(VrfOutput, Vec<u8>), VrfError> {
+        S::prove(&self.secret, &self.public, alpha)
+    }
+}
+
+/// Verify a VRF proof under cipher suite `S`.
+pub fn vrf_verify_generic<S: VrfSuite>(
+    public: &[u8],
+    alpha: &[u8],
+    proof: &[u8],
+) -> Result<VrfOutput, VrfError> {
+    S::verify(public, alpha, proof)
+}
+
+// ───────────────────────────────────────────────────────────────────────────
+// Secp256k1 suite — delegates to vrf.rs's RFC 9381 helpers.
+// ───────────────────────────────────────────────────────────────────────────
+
+/// ECVRF-SECP256K1-SHA256-TAI, wired through the same hash-to-curve,
+/// challenge, and `proof_to_hash` routines as [`crate::vrf::VrfKeypair::prove_rfc9381`].
+pub struct Secp256k1Suite;
+
+impl VrfSuite for Secp256k1Suite {
+    fn keypair_from_seed(seed: &[u8; 32]) -> Result<(Vec<u8>, Vec<u8>), VrfError> {
+        use k256::elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint};
+        use k256::{ProjectivePoint, Scalar};
+
+        let sk = <Scalar as Reduce<k256::U256>>::reduce_bytes(&(*seed).into());
+        if bool::from(sk.is_zero()) {
+            return Err(VrfError::InvalidSeed("zero seed produces invalid secret key".into()));
+        }
+
+        let pk_point = ProjectivePoint::GENERATOR * sk;
+        let pk_bytes = pk_point.to_affine().to_encoded_point(true).as_bytes().to_vec();
+        let sk_bytes: [u8; 32] = sk.to_bytes().into();
+        Ok((sk_bytes.to_vec(), pk_bytes))
+    }
+
+    fn prove(secret: &[u8], public: &[u8], alpha: &[u8]) -> Result<(VrfOutput, Vec<u8>), VrfError> {
+        use k256::elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint};
+        use k256::{ProjectivePoint, Scalar};
+        use sha2::{Digest, Sha256};
+
+        let sk_bytes: [u8; 32] = secret
+            .try_into()
+            .map_err(|_| VrfError::InvalidSeed("secret key must be exactly 32 bytes".into()))?;
+        let sk = <Scalar as Reduce<k256::U256>>::reduce_bytes(&sk_bytes.into());
+        let pk_full: [u8; 33] = public.try_into().map_err(|_| VrfError::InvalidPublicKey)?;
+
+        let h = crate::vrf::hash_to_curve_rfc9381(&pk_full, alpha)?;
+        let gamma = h * sk;
+
+        // RFC 6979 / RFC 9381 §5.4.2.2 deterministic nonce — see crate::nonce.
+        let h1: [u8; 32] = Sha256::digest(alpha).into();
+        let k = crate::nonce::rfc6979_generate(
+            &sk_bytes,
+            &h1,
+            crate::nonce::hmac_sha256,
+            |candidate| {
+                Option::<Scalar>::from(Scalar::from_repr((*candidate).into()))
+                    .filter(|s| !bool::from(s.is_zero()))
+            },
+        );
+
+        let u = ProjectivePoint::GENERATOR * k;
+        let v = h * k;
+        let c = crate::vrf::compute_challenge_rfc9381(&pk_full, &h, &gamma, &u, &v);
+        let s = k - c * sk;
+
+        let gamma_compressed: [u8; 33] = {
+            let enc = gamma.to_affine().to_encoded_point(true);
+            enc.as_bytes().try_into().map_err(|_| VrfError::InvalidProof)?
+        };
+        let output = crate::vrf::proof_to_hash_rfc9381(&gamma_compressed);
+
+        let mut proof_bytes = Vec::with_capacity(97);
+        proof_bytes.extend_from_slice(&gamma_compressed);
+        proof_bytes.extend_from_slice(&<[u8; 32]>::from(c.to_bytes()));
+        proof_bytes.extend_from_slice(&<[u8; 32]>::from(s.to_bytes()));
+
+        Ok((output, proof_bytes))
+    }
+
+    fn verify(public: &[u8], alpha: &[u8], proof: &[u8]) -> Result<VrfOutput, VrfError> {
+        use k256::elliptic_curve::{group::GroupEncoding, ops::Reduce, sec1::ToEncodedPoint};
+        use k256::{AffinePoint, ProjectivePoint, Scalar};
+
+        let pk_full: [u8; 33] = public.try_into().map_err(|_| VrfError::InvalidPublicKey)?;
+        if proof.len() != 97 {
+            return Err(VrfError::InvalidProof);
+        }
+        let gamma_compressed: [u8; 33] = proof[0..33].try_into().unwrap();
+        let c_bytes: [u8; 32] = proof[33..65].try_into().unwrap();
+        let s_bytes: [u8; 32] = proof[65..97].try_into().unwrap();
+
+        let pk_opt = AffinePoint::from_bytes(&pk_full.into());
+        if !bool::from(pk_opt.is_some()) {
+            return Err(VrfError::InvalidPublicKey);
+        }
+        let pk_point = ProjectivePoint::from(pk_opt.unwrap());
+
+        let gamma_opt = AffinePoint::from_bytes(&gamma_compressed.into());
+        if !bool::from(gamma_opt.is_some()) {
+            return Err(VrfError::InvalidProof);
+        }
+        let gamma = ProjectivePoint::from(gamma_opt.unwrap());
+
+        let c = <Scalar as Reduce<k256::U256>>::reduce_bytes(&c_bytes.into());
+        let s = <Scalar as Reduce<k256::U256>>::reduce_bytes(&s_bytes.into());
+
+        let h = crate::vrf::hash_to_curve_rfc9381(&pk_full, alpha)?;
+        let u = ProjectivePoint::GENERATOR * s + pk_point * c;
+        let v = h * s + gamma * c;
+        let c_prime = crate::vrf::compute_challenge_rfc9381(&pk_full, &h, &gamma, &u, &v);
+
+        let c_prime_bytes: [u8; 32] = c_prime.to_bytes().into();
+        let mut diff = 0u8;
+        for (a, b) in c_prime_bytes.iter().zip(c_bytes.iter()) {
+            diff |= a ^ b;
+        }
+        if diff != 0 {
+            return Err(VrfError::VerificationFailed);
+        }
+
+        let _ = pk_point.to_affine().to_encoded_point(true);
+        Ok(crate::vrf::proof_to_hash_rfc9381(&gamma_compressed))
+    }
+}
+
+// ───────────────────────────────────────────────────────────────────────────
+// P-256 suite — ECVRF-P256-SHA256-TAI (RFC 9381 suite_string 0x01).
+// ───────────────────────────────────────────────────────────────────────────
+
+/// NIST P-256, per RFC 9381's own ECVRF-P256-SHA256-TAI suite. Structurally
+/// identical to [`Secp256k1Suite`] (same TAI hash-to-curve, same SHA-256
+/// challenge/output construction), just over a different curve — `p256` and
+/// `k256` both implement the same RustCrypto `elliptic_curve` traits, so the
+/// two suites share the same shape even though they can't share code.
+pub struct P256Suite;
+
+/// RFC 9381's registered suite_string for ECVRF-P256-SHA256-TAI.
+const P256_SUITE_STRING: u8 = 0x01;
+
+fn p256_hash_to_curve(
+    pk_bytes: &[u8],
+    alpha: &[u8],
+) -> Result<p256::ProjectivePoint, VrfError> {
+    use p256::elliptic_curve::group::GroupEncoding;
+    use p256::AffinePoint;
+    use sha2::{Digest, Sha256};
+
+    for ctr in 0u8..=255 {
+        let mut input = Vec::with_capacity(3 + pk_bytes.len() + alpha.len() + 1);
+        input.push(P256_SUITE_STRING);
+        input.push(0x01);
+        input.extend_from_slice(pk_bytes);
+        input.extend_from_slice(alpha);
+        input.push(ctr);
+        input.push(0x00);
+
+        let hash: [u8; 32] = Sha256::digest(&input).into();
+        let mut compressed = [0u8; 33];
+        compressed[0] = 0x02;
+        compressed[1..33].copy_from_slice(&hash);
+
+        let ct_opt = AffinePoint::from_bytes(&compressed.into());
+        if bool::from(ct_opt.is_some()) {
+            return Ok(p256::ProjectivePoint::from(ct_opt.unwrap()));
+        }
+    }
+    Err(VrfError::HashToCurveFailed)
+}
+
+fn p256_compute_challenge(
+    pk_compressed: &[u8; 33],
+    h_point: &p256::ProjectivePoint,
+    gamma: &p256::ProjectivePoint,
+    k_g: &p256::ProjectivePoint,
+    k_h: &p256::ProjectivePoint,
+) -> p256::Scalar {
+    use p256::elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint};
+    use sha2::{Digest, Sha256};
+
+    let encode = |p: &p256::ProjectivePoint| -> Vec<u8> {
+        p.to_affine().to_encoded_point(true).as_bytes().to_vec()
+    };
+
+    let mut input = Vec::with_capacity(2 + 33 * 4 + 1);
+    input.push(P256_SUITE_STRING);
+    input.push(0x02);
+    input.extend_from_slice(pk_compressed);
+    input.extend_from_slice(&encode(h_point));
+    input.extend_from_slice(&encode(gamma));
+    input.extend_from_slice(&encode(k_g));
+    input.extend_from_slice(&encode(k_h));
+    input.push(0x00);
+
+    let hash: [u8; 32] = Sha256::digest(&input).into();
+    let mut c_bytes = [0u8; 32];
+    c_bytes[16..32].copy_from_slice(&hash[0..16]);
+    <p256::Scalar as Reduce<p256::U256>>::reduce_bytes(&c_bytes.into())
+}
+
+fn p256_proof_to_hash(gamma_compressed: &[u8; 33]) -> VrfOutput {
+    use sha2::{Digest, Sha256};
+    let mut input = Vec::with_capacity(2 + gamma_compressed.len() + 1);
+    input.push(P256_SUITE_STRING);
+    input.push(0x03);
+    input.extend_from_slice(gamma_compressed);
+    input.push(0x00);
+    Sha256::digest(&input).into()
+}
+
+impl VrfSuite for P256Suite {
+    fn keypair_from_seed(seed: &[u8; 32]) -> Result<(Vec<u8>, Vec<u8>), VrfError> {
+        use p256::elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint};
+        use p256::ProjectivePoint;
+
+        let sk = <p256::Scalar as Reduce<p256::U256>>::reduce_bytes(&(*seed).into());
+        if bool::from(sk.is_zero()) {
+            return Err(VrfError::InvalidSeed("zero seed produces invalid secret key".into()));
+        }
+
+        let pk_point = ProjectivePoint::GENERATOR * sk;
+        let pk_bytes = pk_point.to_affine().to_encoded_point(true).as_bytes().to_vec();
+        let sk_bytes: [u8; 32] = sk.to_bytes().into();
+        Ok((sk_bytes.to_vec(), pk_bytes))
+    }
+
+    fn prove(secret: &[u8], public: &[u8], alpha: &[u8]) -> Result<(VrfOutput, Vec<u8>), VrfError> {
+        use p256::elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint};
+        use p256::ProjectivePoint;
+        use sha2::{Digest, Sha256};
+
+        let sk_bytes: [u8; 32] = secret
+            .try_into()
+            .map_err(|_| VrfError::InvalidSeed("secret key must be exactly 32 bytes".into()))?;
+        let sk = <p256::Scalar as Reduce<p256::U256>>::reduce_bytes(&sk_bytes.into());
+        let pk_full: [u8; 33] = public.try_into().map_err(|_| VrfError::InvalidPublicKey)?;
+
+        let h = p256_hash_to_curve(&pk_full, alpha)?;
+        let gamma = h * sk;
+
+        // RFC 6979 / RFC 9381 §5.4.2.2 deterministic nonce — see crate::nonce.
+        let h1: [u8; 32] = Sha256::digest(alpha).into();
+        let k = crate::nonce::rfc6979_generate(
+            &sk_bytes,
+            &h1,
+            crate::nonce::hmac_sha256,
+            |candidate| {
+                Option::<p256::Scalar>::from(p256::Scalar::from_repr((*candidate).into()))
+                    .filter(|s| !bool::from(s.is_zero()))
+            },
+        );
+
+        let u = ProjectivePoint::GENERATOR * k;
+        let v = h * k;
+        let c = p256_compute_challenge(&pk_full, &h, &gamma, &u, &v);
+        let s = k - c * sk;
+
+        let gamma_compressed: [u8; 33] = gamma
+            .to_affine()
+            .to_encoded_point(true)
+            .as_bytes()
+            .try_into()
+            .map_err(|_| VrfError::InvalidProof)?;
+        let output = p256_proof_to_hash(&gamma_compressed);
+
+        let mut proof_bytes = Vec::with_capacity(97);
+        proof_bytes.extend_from_slice(&gamma_compressed);
+        proof_bytes.extend_from_slice(&<[u8; 32]>::from(c.to_bytes()));
+        proof_bytes.extend_from_slice(&<[u8; 32]>::from(s.to_bytes()));
+        Ok((output, proof_bytes))
+    }
+
+    fn verify(public: &[u8], alpha: &[u8], proof: &[u8]) -> Result<VrfOutput, VrfError> {
+        use p256::elliptic_curve::{group::GroupEncoding, ops::Reduce};
+        use p256::{AffinePoint, ProjectivePoint, Scalar};
+
+        let pk_full: [u8; 33] = public.try_into().map_err(|_| VrfError::InvalidPublicKey)?;
+        if proof.len() != 97 {
+            return Err(VrfError::InvalidProof);
+        }
+        let gamma_compressed: [u8; 33] = proof[0..33].try_into().unwrap();
+        let c_bytes: [u8; 32] = proof[33..65].try_into().unwrap();
+        let s_bytes: [u8; 32] = proof[65..97].try_into().unwrap();
+
+        let pk_opt = AffinePoint::from_bytes(&pk_full.into());
+        if !bool::from(pk_opt.is_some()) {
+            return Err(VrfError::InvalidPublicKey);
+        }
+        let pk_point = ProjectivePoint::from(pk_opt.unwrap());
+
+        let gamma_opt = AffinePoint::from_bytes(&gamma_compressed.into());
+        if !bool::from(gamma_opt.is_some()) {
+            return Err(VrfError::InvalidProof);
+        }
+        let gamma = ProjectivePoint::from(gamma_opt.unwrap());
+
+        let c = <Scalar as Reduce<p256::U256>>::reduce_bytes(&c_bytes.into());
+        let s = <Scalar as Reduce<p256::U256>>::reduce_bytes(&s_bytes.into());
+
+        let h = p256_hash_to_curve(&pk_full, alpha)?;
+        let u = ProjectivePoint::GENERATOR * s + pk_point * c;
+        let v = h * s + gamma * c;
+        let c_prime = p256_compute_challenge(&pk_full, &h, &gamma, &u, &v);
+
+        let c_prime_bytes: [u8; 32] = c_prime.to_bytes().into();
+        let mut diff = 0u8;
+        for (a, b) in c_prime_bytes.iter().zip(c_bytes.iter()) {
+            diff |= a ^ b;
+        }
+        if diff != 0 {
+            return Err(VrfError::VerificationFailed);
+        }
+        Ok(p256_proof_to_hash(&gamma_compressed))
+    }
+}
+
+// ───────────────────────────────────────────────────────────────────────────
+// Ed25519 suite.
+// ───────────────────────────────────────────────────────────────────────────
+
+/// Edwards25519 with SHA-512, via `curve25519-dalek` (the same crate
+/// `luxtensor-consensus`'s `production-vrf` path uses). Cofactor (8) is
+/// cleared in `proof_to_hash` via `EdwardsPoint::mul_by_cofactor`, as RFC 9381
+/// requires for any suite over a curve with cofactor > 1.
+///
+/// RFC 9381's own Ed25519 suite (ECVRF-EDWARDS25519-SHA512-ELL2) maps to the
+/// curve via Elligator2 rather than try-and-increment. `curve25519-dalek`
+/// doesn't expose Elligator2 as public API, so this suite uses try-and-increment
+/// like the other two here — it is internally consistent and verifiable
+/// end-to-end, but not a drop-in replacement for a SHA512-ELL2 verifier
+/// elsewhere. Swap in a dedicated Elligator2 map if byte-for-byte
+/// interop with the official suite is required.
+pub struct Ed25519Suite;
+
+fn ed25519_hash_to_curve(
+    pk_bytes: &[u8],
+    alpha: &[u8],
+) -> Result<curve25519_dalek::edwards::EdwardsPoint, VrfError> {
+    use curve25519_dalek::edwards::CompressedEdwardsY;
+    use sha2::{Digest, Sha512};
+
+    for ctr in 0u8..=255 {
+        let mut input = Vec::with_capacity(3 + pk_bytes.len() + alpha.len() + 1);
+        input.push(0x04); // suite_string for ECVRF-EDWARDS25519-SHA512-ELL2 (informational tag)
+        input.push(0x01);
+        input.extend_from_slice(pk_bytes);
+        input.extend_from_slice(alpha);
+        input.push(ctr);
+        input.push(0x00);
+
+        let hash = Sha512::digest(&input);
+        let mut compressed = [0u8; 32];
+        compressed.copy_from_slice(&hash[0..32]);
+
+        if let Some(pt) = CompressedEdwardsY(compressed).decompress() {
+            return Ok(pt);
+        }
+    }
+    Err(VrfError::HashToCurveFailed)
+}
+
+fn ed25519_compute_challenge(
+    pk_compressed: &[u8; 32],
+    h_point: &curve25519_dalek::edwards::EdwardsPoint,
+    gamma: &curve25519_dalek::edwards::EdwardsPoint,
+    k_g: &curve25519_dalek::edwards::EdwardsPoint,
+    k_h: &curve25519_dalek::edwards::EdwardsPoint,
+) -> curve25519_dalek::scalar::Scalar {
+    use sha2::{Digest, Sha512};
+
+    let mut input = Vec::with_capacity(2 + 32 * 4 + 1);
+    input.push(0x04);
+    input.push(0x02);
+    input.extend_from_slice(pk_compressed);
+    input.extend_from_slice(h_point.compress().as_bytes());
+    input.extend_from_slice(gamma.compress().as_bytes());
+    input.extend_from_slice(k_g.compress().as_bytes());
+    input.extend_from_slice(k_h.compress().as_bytes());
+    input.push(0x00);
+
+    let hash: [u8; 64] = Sha512::digest(&input).into();
+    // Truncate to 16 bytes (cLen) by zeroing everything else before reducing,
+    // matching the same cLen convention used by the other two suites.
+    let mut c_wide = [0u8; 64];
+    c_wide[0..16].copy_from_slice(&hash[0..16]);
+    curve25519_dalek::scalar::Scalar::from_bytes_mod_order_wide(&c_wide)
+}
+
+fn ed25519_proof_to_hash(gamma: &curve25519_dalek::edwards::EdwardsPoint) -> VrfOutput {
+    use sha2::{Digest, Sha512};
+    // RFC 9381 §5.2: beta = Hash(suite || 0x03 || point_to_string(cofactor * Gamma) || 0x00).
+    let cleared = gamma.mul_by_cofactor();
+    let mut input = Vec::with_capacity(2 + 32 + 1);
+    input.push(0x04);
+    input.push(0x03);
+    input.extend_from_slice(cleared.compress().as_bytes());
+    input.push(0x00);
+    let hash: [u8; 64] = Sha512::digest(&input).into();
+    let mut output = [0u8; 32];
+    output.copy_from_slice(&hash[0..32]);
+    output
+}
+
+impl VrfSuite for Ed25519Suite {
+    fn keypair_from_seed(seed: &[u8; 32]) -> Result<(Vec<u8>, Vec<u8>), VrfError> {
+        use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+        use curve25519_dalek::scalar::Scalar;
+
+        let sk = Scalar::from_bytes_mod_order(*seed);
+        if sk == Scalar::ZERO {
+            return Err(VrfError::InvalidSeed("zero seed produces invalid secret key".into()));
+        }
+        let pk_point = &ED25519_BASEPOINT_TABLE * &sk;
+        Ok((sk.to_bytes().to_vec(), pk_point.compress().as_bytes().to_vec()))
+    }
+
+    fn prove(secret: &[u8], public: &[u8], alpha: &[u8]) -> Result<(VrfOutput, Vec<u8>), VrfError> {
+        use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+        use curve25519_dalek::scalar::Scalar;
+        use sha2::{Digest, Sha512};
+
+        let sk_bytes: [u8; 32] = secret.try_into().map_err(|_| {
+            VrfError::InvalidSeed("secret key must be exactly 32 bytes".into())
+        })?;
+        let sk = Scalar::from_bytes_mod_order(sk_bytes);
+        let pk_full: [u8; 32] = public.try_into().map_err(|_| VrfError::InvalidPublicKey)?;
+
+        let h = ed25519_hash_to_curve(&pk_full, alpha)?;
+        let gamma = h * sk;
+
+        // Unlike the secp256k1/P-256 suites, this nonce isn't switched to
+        // crate::nonce's HMAC-DRBG: it already reduces a 64-byte wide hash
+        // (`from_bytes_mod_order_wide`) rather than a single 32-byte digest,
+        // so it doesn't exhibit the modular bias that construction fixes.
+        let mut k_input = Vec::with_capacity(64 + alpha.len());
+        k_input.extend_from_slice(secret);
+        k_input.extend_from_slice(alpha);
+        k_input.extend_from_slice(b"ECVRF_edwards25519_sha512_nonce");
+        let k_wide: [u8; 64] = Sha512::digest(&k_input).into();
+        k_input.zeroize();
+        let k = Scalar::from_bytes_mod_order_wide(&k_wide);
+
+        let u = &ED25519_BASEPOINT_TABLE * &k;
+        let v = h * k;
+        let c = ed25519_compute_challenge(&pk_full, &h, &gamma, &u, &v);
+        let s = k - c * sk;
+
+        let output = ed25519_proof_to_hash(&gamma);
+
+        let mut proof_bytes = Vec::with_capacity(96);
+        proof_bytes.extend_from_slice(gamma.compress().as_bytes());
+        proof_bytes.extend_from_slice(c.as_bytes());
+        proof_bytes.extend_from_slice(s.as_bytes());
+        Ok((output, proof_bytes))
+    }
+
+    fn verify(public: &[u8], alpha: &[u8], proof: &[u8]) -> Result<VrfOutput, VrfError> {
+        use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+        use curve25519_dalek::edwards::CompressedEdwardsY;
+        use curve25519_dalek::scalar::Scalar;
+
+        let pk_full: [u8; 32] = public.try_into().map_err(|_| VrfError::InvalidPublicKey)?;
+        if proof.len() != 96 {
+            return Err(VrfError::InvalidProof);
+        }
+        let gamma_bytes: [u8; 32] = proof[0..32].try_into().unwrap();
+        let c_bytes: [u8; 32] = proof[32..64].try_into().unwrap();
+        let s_bytes: [u8; 32] = proof[64..96].try_into().unwrap();
+
+        let pk_point = CompressedEdwardsY(pk_full)
+            .decompress()
+            .ok_or(VrfError::InvalidPublicKey)?;
+        let gamma = CompressedEdwardsY(gamma_bytes)
+            .decompress()
+            .ok_or(VrfError::InvalidProof)?;
+
+        let c = Scalar::from_bytes_mod_order(c_bytes);
+        let s = Scalar::from_bytes_mod_order(s_bytes);
+
+        let h = ed25519_hash_to_curve(&pk_full, alpha)?;
+        let u = &ED25519_BASEPOINT_TABLE * &s + pk_point * c;
+        let v = h * s + gamma * c;
+        let c_prime = ed25519_compute_challenge(&pk_full, &h, &gamma, &u, &v);
+
+        if c_prime.as_bytes() != &c_bytes {
+            return Err(VrfError::VerificationFailed);
+        }
+        Ok(ed25519_proof_to_hash(&gamma))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip<S: VrfSuite>(seed: [u8; 32], alpha: &[u8]) {
+        let kp = GenericVrfKeypair::<S>::from_seed(&seed).unwrap();
+        let (output, proof) = kp.prove(alpha).unwrap();
+        let verified = vrf_verify_generic::<S>(kp.public_key(), alpha, &proof).unwrap();
+        assert_eq!(output, verified);
+    }
+
+    #[test]
+    fn test_secp256k1_suite_roundtrip() {
+        roundtrip::<Secp256k1Suite>([11u8; 32], b"secp256k1_suite_input");
+    }
+
+    #[test]
+    fn test_p256_suite_roundtrip() {
+        roundtrip::<P256Suite>([11u8; 32], b"p256_suite_input");
+    }
+
+    #[test]
+    fn test_ed25519_suite_roundtrip() {
+        roundtrip::<Ed25519Suite>([11u8; 32], b"ed25519_suite_input");
+    }
+
+    #[test]
+    fn test_secp256k1_suite_determinism() {
+        let kp = GenericVrfKeypair::<Secp256k1Suite>::from_seed(&[3u8; 32]).unwrap();
+        let (out1, proof1) = kp.prove(b"determinism").unwrap();
+        let (out2, proof2) = kp.prove(b"determinism").unwrap();
+        assert_eq!(out1, out2);
+        assert_eq!(proof1, proof2);
+    }
+
+    #[test]
+    fn test_p256_suite_wrong_input_rejected() {
+        let kp = GenericVrfKeypair::<P256Suite>::from_seed(&[5u8; 32]).unwrap();
+        let (_output, proof) = kp.prove(b"correct").unwrap();
+        assert!(vrf_verify_generic::<P256Suite>(kp.public_key(), b"wrong", &proof).is_err());
+    }
+
+    #[test]
+    fn test_ed25519_suite_wrong_key_rejected() {
+        let kp1 = GenericVrfKeypair::<Ed25519Suite>::from_seed(&[6u8; 32]).unwrap();
+        let kp2 = GenericVrfKeypair::<Ed25519Suite>::from_seed(&[9u8; 32]).unwrap();
+        let (_output, proof) = kp1.prove(b"cross_key").unwrap();
+        assert!(vrf_verify_generic::<Ed25519Suite>(kp2.public_key(), b"cross_key", &proof).is_err());
+    }
+
+    #[test]
+    fn test_zero_seed_rejected_across_suites() {
+        assert!(GenericVrfKeypair::<Secp256k1Suite>::from_seed(&[0u8; 32]).is_err());
+        assert!(GenericVrfKeypair::<P256Suite>::from_seed(&[0u8; 32]).is_err());
+        assert!(GenericVrfKeypair::<Ed25519Suite>::from_seed(&[0u8; 32]).is_err());
+    }
+}
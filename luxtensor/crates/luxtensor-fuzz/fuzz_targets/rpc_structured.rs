@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use luxtensor_tests::fuzz_targets::{fuzz_rpc_structured, FuzzRpcCall};
+
+fuzz_target!(|call: FuzzRpcCall| {
+    let _ = fuzz_rpc_structured(&call);
+});
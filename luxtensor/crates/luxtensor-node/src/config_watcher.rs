@@ -0,0 +1,83 @@
+//! Filesystem watcher for hot-reloading [`HealthConfig`].
+//!
+//! `HealthConfig` is tuned through trial and error in production — `min_peers`,
+//! `max_block_gap_seconds`, etc. used to require a full node restart to
+//! retune. This watches an on-disk TOML file with `notify-debouncer-mini`
+//! (the same debounced-watch approach web3-proxy uses to coalesce rapid
+//! filesystem events into a single reload), re-parses it after a short
+//! settle window, validates it, and swaps it into the live `HealthMonitor`.
+//! A malformed reload is logged and discarded rather than applied, so a
+//! typo in the file can never take the monitor down.
+//
+// Add to Cargo.toml: notify-debouncer-mini = "0.4"
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use notify_debouncer_mini::{new_debouncer, DebouncedEventKind};
+use tracing::{info, warn};
+
+use crate::health::{HealthConfig, HealthMonitor};
+
+/// Debounce window: filesystem events within this window of each other are
+/// coalesced into a single reload, so an editor's save-via-rename doesn't
+/// trigger multiple re-parses.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(500);
+
+/// Start watching `path` for changes and hot-swap `monitor`'s config on
+/// every valid reload. Spawns its own background task and returns
+/// immediately; the watcher (and its underlying OS handle) lives for as
+/// long as the returned task does, so callers should keep the node running
+/// rather than dropping anything.
+pub fn watch(path: PathBuf, monitor: Arc<HealthMonitor>) {
+    tokio::spawn(async move {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut debouncer = match new_debouncer(DEBOUNCE_WINDOW, move |result| {
+            let _ = tx.send(result);
+        }) {
+            Ok(debouncer) => debouncer,
+            Err(e) => {
+                warn!("health config watcher: failed to start: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = debouncer
+            .watcher()
+            .watch(&path, notify_debouncer_mini::notify::RecursiveMode::NonRecursive)
+        {
+            warn!("health config watcher: failed to watch {}: {}", path.display(), e);
+            return;
+        }
+
+        info!("Watching {} for HealthConfig changes", path.display());
+
+        while let Some(result) = rx.recv().await {
+            let events = match result {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!("health config watcher: watch error: {}", e);
+                    continue;
+                }
+            };
+            if !events.iter().any(|e| e.kind == DebouncedEventKind::Any) {
+                continue;
+            }
+
+            match HealthConfig::from_file(&path) {
+                Ok(new_config) => {
+                    monitor.update_config(new_config);
+                    info!("Reloaded HealthConfig from {}", path.display());
+                }
+                Err(e) => {
+                    warn!(
+                        "health config watcher: keeping previous config, reload of {} failed: {}",
+                        path.display(),
+                        e
+                    );
+                }
+            }
+        }
+    });
+}
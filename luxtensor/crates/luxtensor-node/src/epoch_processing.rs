@@ -277,7 +277,7 @@ impl NodeService {
             let mut finalized_count = 0u32;
             for proposal in &active {
                 if new_height > proposal.voting_deadline {
-                    match gov.finalize_voting(proposal.id, new_height) {
+                    match gov.finalize_voting(proposal.id, new_height, false) {
                         Ok(status) => {
                             info!(
                                 "🏛️ Governance: proposal #{} finalized → {:?} at epoch {}",
@@ -3,7 +3,9 @@ use luxtensor_core::constants::precompiles;
 use luxtensor_crypto::keccak256;
 use luxtensor_contracts::EvmExecutor;
 use luxtensor_contracts::evm_executor::EvmLog;
-use luxtensor_contracts::{AIPrecompileState, RevmBytes, execute_ai_precompile, is_luxtensor_precompile};
+use luxtensor_contracts::{
+    AIPrecompileState, RevmBytes, execute_ai_precompile, is_luxtensor_precompile, sender_has_code,
+};
 use luxtensor_storage::metagraph_store::MetagraphDB;
 use sha3::{Keccak256, Digest};
 use std::sync::Arc;
@@ -61,6 +63,14 @@ pub struct TransactionExecutor {
     metagraph_db: Option<Arc<MetagraphDB>>,
     /// AI precompile state (inference requests, vector store, training jobs)
     ai_precompile_state: Option<Arc<AIPrecompileState>>,
+    /// Current block's EIP-1559 base fee, surfaced to contracts via the
+    /// BASE_FEE precompile (0x0F). Defaults to 1 Gwei until the fee market
+    /// reports the first computed base fee.
+    base_fee: u128,
+    /// EIP-3607: reject transactions whose sender account has deployed
+    /// bytecode. Default `true`, matching mainnet EVM semantics and
+    /// `EvmConfig::reject_sender_with_code`.
+    reject_sender_with_code: bool,
 }
 
 impl TransactionExecutor {
@@ -75,9 +85,25 @@ impl TransactionExecutor {
             evm: EvmExecutor::new(chain_id),
             metagraph_db: None,
             ai_precompile_state: None,
+            base_fee: 1_000_000_000, // 1 Gwei default
+            reject_sender_with_code: true,
         }
     }
 
+    /// Update the current block's base fee, surfaced to contracts via the
+    /// BASE_FEE precompile (0x0F). Call this once per block as the fee
+    /// market recalculates it.
+    pub fn set_base_fee(&mut self, base_fee: u128) {
+        self.base_fee = base_fee;
+    }
+
+    /// Toggle the EIP-3607 sender-has-code rejection. Only disable this for
+    /// testing — mainnet EVM semantics require transaction senders to be
+    /// EOAs.
+    pub fn set_reject_sender_with_code(&mut self, reject: bool) {
+        self.reject_sender_with_code = reject;
+    }
+
     /// Attach a MetagraphDB so the executor can handle metagraph precompile transactions.
     /// Call this after `new()` at node startup.
     pub fn with_metagraph(mut self, db: Arc<MetagraphDB>) -> Self {
@@ -166,6 +192,15 @@ impl TransactionExecutor {
         let mut sender = state.get_account(&tx.from)
             .unwrap_or_else(|| Account::new());
 
+        // SECURITY (EIP-3607): reject senders that have deployed bytecode —
+        // without this check a contract address could be spoofed as the
+        // `from` of a transaction it never signed.
+        if !is_faucet_mint && self.reject_sender_with_code && sender_has_code(&sender.code_hash) {
+            return Err(CoreError::InvalidTransaction(
+                "sender has deployed code (EIP-3607)".to_string()
+            ));
+        }
+
         // Check nonce (skip for faucet mints)
         if !is_faucet_mint && sender.nonce != tx.nonce {
             return Err(CoreError::InvalidTransaction(
@@ -301,7 +336,8 @@ impl TransactionExecutor {
 
         if let Some(ref ai_state) = self.ai_precompile_state {
             match execute_ai_precompile(
-                &addr_bytes, &input, tx.gas_limit, ai_state, caller, block_height,
+                &addr_bytes, &input, tx.gas_limit, ai_state, caller, block_height, self.base_fee,
+                None,
             ) {
                 Some(Ok(output)) => {
                     *actual_gas_used = output.gas_used.max(*actual_gas_used);
@@ -637,6 +673,8 @@ impl TransactionExecutor {
             evm: EvmExecutor::new(chain_id),
             metagraph_db: None,
             ai_precompile_state: None,
+            base_fee: 1_000_000_000, // 1 Gwei default
+            reject_sender_with_code: true,
         }
     }
 }
@@ -773,6 +811,59 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_eip3607_rejects_sender_with_deployed_code() {
+        let executor = TransactionExecutor::new_dev(TEST_CHAIN_ID);
+        let mut state = StateDB::new();
+
+        let keypair = KeyPair::generate();
+        let from = Address::from(keypair.address());
+        let mut sender = Account::contract(1_000_000, vec![0x60, 0x00], [0xAAu8; 32]);
+        sender.nonce = 0;
+        state.set_account(from, sender);
+
+        let tx = create_signed_transaction(&keypair, 0, Some(Address::zero()), 1000);
+
+        let result = executor.execute(&tx, &mut state, 1, [1u8; 32], 0, 1000);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_eip3607_allows_plain_eoa_sender() {
+        let executor = TransactionExecutor::new_dev(TEST_CHAIN_ID);
+        let mut state = StateDB::new();
+
+        let keypair = KeyPair::generate();
+        let from = Address::from(keypair.address());
+        let mut sender = Account::new();
+        sender.balance = 1_000_000;
+        sender.nonce = 0;
+        state.set_account(from, sender);
+
+        let tx = create_signed_transaction(&keypair, 0, Some(Address::zero()), 1000);
+
+        let result = executor.execute(&tx, &mut state, 1, [1u8; 32], 0, 1000);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_eip3607_check_can_be_disabled() {
+        let mut executor = TransactionExecutor::new_dev(TEST_CHAIN_ID);
+        executor.set_reject_sender_with_code(false);
+        let mut state = StateDB::new();
+
+        let keypair = KeyPair::generate();
+        let from = Address::from(keypair.address());
+        let mut sender = Account::contract(1_000_000, vec![0x60, 0x00], [0xAAu8; 32]);
+        sender.nonce = 0;
+        state.set_account(from, sender);
+
+        let tx = create_signed_transaction(&keypair, 0, Some(Address::zero()), 1000);
+
+        let result = executor.execute(&tx, &mut state, 1, [1u8; 32], 0, 1000);
+        assert!(result.is_ok());
+    }
+
 
     #[test]
     fn test_receipts_root() {
@@ -6,11 +6,17 @@
 //! - Detecting common issues (low peers, stalled sync, etc.)
 //! - Triggering automatic recovery actions
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
 use tracing::{info, warn};
 
+use crate::resource_sampler;
+
 /// Health status of the node
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthStatus {
@@ -88,10 +94,86 @@ impl HealthIssue {
     pub fn is_critical(&self) -> bool {
         self.severity() >= 8
     }
+
+    /// The issue's variant, discarding its fields — used to key the
+    /// recovery action registry, since a `HashMap` can't key on an enum
+    /// carrying arbitrary data.
+    pub fn kind(&self) -> HealthIssueKind {
+        match self {
+            Self::LowPeerCount { .. } => HealthIssueKind::LowPeerCount,
+            Self::BlockProductionStalled { .. } => HealthIssueKind::BlockProductionStalled,
+            Self::MempoolOverloaded { .. } => HealthIssueKind::MempoolOverloaded,
+            Self::SyncLagging { .. } => HealthIssueKind::SyncLagging,
+            Self::LowDiskSpace { .. } => HealthIssueKind::LowDiskSpace,
+            Self::HighMemoryUsage { .. } => HealthIssueKind::HighMemoryUsage,
+        }
+    }
+}
+
+/// `HealthIssue` without its payload, for use as a `HashMap` key in the
+/// recovery action registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HealthIssueKind {
+    LowPeerCount,
+    BlockProductionStalled,
+    MempoolOverloaded,
+    SyncLagging,
+    LowDiskSpace,
+    HighMemoryUsage,
+}
+
+/// A `HealthIssue` paired with its computed `severity()`, so consumers
+/// serializing this over the wire (e.g. the `/health` HTTP endpoint) don't
+/// have to recompute it client-side to color-code dashboards.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthIssueReport {
+    #[serde(flatten)]
+    pub issue: HealthIssue,
+    pub severity: u8,
+}
+
+impl From<&HealthIssue> for HealthIssueReport {
+    fn from(issue: &HealthIssue) -> Self {
+        Self { severity: issue.severity(), issue: issue.clone() }
+    }
+}
+
+/// JSON-friendly view of [`HealthStatus`] with each issue's severity
+/// included. This is what the `/health` and `/readyz` HTTP endpoints serve.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub healthy: bool,
+    pub block_height: u64,
+    pub peer_count: usize,
+    pub is_syncing: bool,
+    pub sync_progress: u8,
+    pub seconds_since_last_block: u64,
+    pub mempool_size: usize,
+    pub issues: Vec<HealthIssueReport>,
+    pub uptime_seconds: u64,
+}
+
+impl From<HealthStatus> for HealthReport {
+    fn from(status: HealthStatus) -> Self {
+        Self {
+            healthy: status.healthy,
+            block_height: status.block_height,
+            peer_count: status.peer_count,
+            is_syncing: status.is_syncing,
+            sync_progress: status.sync_progress,
+            seconds_since_last_block: status.seconds_since_last_block,
+            mempool_size: status.mempool_size,
+            issues: status.issues.iter().map(HealthIssueReport::from).collect(),
+            uptime_seconds: status.uptime_seconds,
+        }
+    }
 }
 
-/// Configuration for health monitoring
-#[derive(Debug, Clone)]
+/// Configuration for health monitoring. Deliberately kept as its own small
+/// file (see `from_file`/`HealthMonitor::update_config`) rather than nested
+/// in the main node `Config`, so operators can retune thresholds by editing
+/// and saving one file without restarting the node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthConfig {
     /// Minimum peers for healthy status
     pub min_peers: usize,
@@ -105,6 +187,52 @@ pub struct HealthConfig {
     pub min_disk_space_mb: u64,
     /// Maximum memory usage percent
     pub max_memory_percent: u8,
+    /// How often `subscribe()`'s background sampler recomputes health.
+    /// Not part of the on-disk config file: the sampler task reads this
+    /// once at `subscribe()` time, so changing it via hot-reload wouldn't
+    /// take effect without also restarting the sampler.
+    #[serde(skip, default = "HealthConfig::default_sample_interval")]
+    pub sample_interval: Duration,
+    /// Minimum time between repeated firings of the same recovery action,
+    /// unless the issue's severity has climbed since it last fired. Same
+    /// not-part-of-the-file rationale as `sample_interval`.
+    #[serde(skip, default = "HealthConfig::default_recovery_cooldown")]
+    pub recovery_cooldown: Duration,
+}
+
+impl HealthConfig {
+    fn default_sample_interval() -> Duration {
+        Duration::from_secs(5)
+    }
+
+    fn default_recovery_cooldown() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    /// Load from a TOML file, the same way `Config::from_file` does for the
+    /// main node config.
+    pub fn from_file(path: &std::path::Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        let config: HealthConfig = toml::from_str(&contents)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Reject nonsensical thresholds before they're swapped into a live
+    /// `HealthMonitor` — a malformed reload should be refused, not crash or
+    /// silently produce a monitor that can never report healthy.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.max_memory_percent > 100 {
+            anyhow::bail!("max_memory_percent must be <= 100, got {}", self.max_memory_percent);
+        }
+        Ok(())
+    }
+
+    /// Path to watch for hot-reloadable changes, set via `HEALTH_CONFIG_FILE`.
+    /// Optional: nodes that never set it simply never start `config_watcher`.
+    pub fn watch_path() -> Option<std::path::PathBuf> {
+        std::env::var("HEALTH_CONFIG_FILE").ok().map(std::path::PathBuf::from)
+    }
 }
 
 impl Default for HealthConfig {
@@ -116,13 +244,116 @@ impl Default for HealthConfig {
             max_sync_lag: 100,
             min_disk_space_mb: 1000,
             max_memory_percent: 90,
+            sample_interval: Self::default_sample_interval(),
+            recovery_cooldown: Self::default_recovery_cooldown(),
         }
     }
 }
 
+/// A pluggable remediation action, run by `HealthMonitor`'s recovery
+/// supervisor whenever its registered `HealthIssueKind` is currently
+/// critical. Implementations typically close over a handle into whatever
+/// subsystem can actually fix the problem (peer manager, sync manager,
+/// mempool) — `health.rs` deliberately has no direct dependency on those.
+#[async_trait::async_trait]
+pub trait RecoveryAction: Send + Sync {
+    async fn recover(&self, issue: &HealthIssue) -> anyhow::Result<()>;
+}
+
+/// Wraps an async closure as a `RecoveryAction` so callers can register
+/// remediation logic without writing a one-off struct + impl per action.
+pub struct FnRecoveryAction<F> {
+    f: F,
+}
+
+impl<F, Fut> FnRecoveryAction<F>
+where
+    F: Fn(&HealthIssue) -> Fut + Send + Sync,
+    Fut: std::future::Future<Output = anyhow::Result<()>> + Send,
+{
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+#[async_trait::async_trait]
+impl<F, Fut> RecoveryAction for FnRecoveryAction<F>
+where
+    F: Fn(&HealthIssue) -> Fut + Send + Sync,
+    Fut: std::future::Future<Output = anyhow::Result<()>> + Send,
+{
+    async fn recover(&self, issue: &HealthIssue) -> anyhow::Result<()> {
+        (self.f)(issue).await
+    }
+}
+
+/// Built-in recovery action constructors, analogous to web3-proxy's
+/// remediation of unhealthy connections. Each wraps a caller-supplied
+/// zero-argument async closure that performs the actual fix — the issue's
+/// own fields aren't needed to decide *what* to do, only *that* it's time
+/// to do it again (the supervisor already handles cooldown/escalation).
+pub mod recovery {
+    use super::*;
+
+    /// For `LowPeerCount`: re-dial known peers / run a bootstrap pass.
+    pub fn low_peer_count_redial<F, Fut>(
+        redial: F,
+    ) -> FnRecoveryAction<impl Fn(&HealthIssue) -> Fut + Send + Sync>
+    where
+        F: Fn() -> Fut + Send + Sync,
+        Fut: std::future::Future<Output = anyhow::Result<()>> + Send,
+    {
+        FnRecoveryAction::new(move |_issue: &HealthIssue| redial())
+    }
+
+    /// For `BlockProductionStalled` / `SyncLagging`: force a sync restart.
+    pub fn sync_restart<F, Fut>(
+        restart: F,
+    ) -> FnRecoveryAction<impl Fn(&HealthIssue) -> Fut + Send + Sync>
+    where
+        F: Fn() -> Fut + Send + Sync,
+        Fut: std::future::Future<Output = anyhow::Result<()>> + Send,
+    {
+        FnRecoveryAction::new(move |_issue: &HealthIssue| restart())
+    }
+
+    /// For `MempoolOverloaded`: evict expired/low-priority transactions.
+    pub fn mempool_eviction<F, Fut>(
+        evict: F,
+    ) -> FnRecoveryAction<impl Fn(&HealthIssue) -> Fut + Send + Sync>
+    where
+        F: Fn() -> Fut + Send + Sync,
+        Fut: std::future::Future<Output = anyhow::Result<()>> + Send,
+    {
+        FnRecoveryAction::new(move |_issue: &HealthIssue| evict())
+    }
+}
+
+/// Pulled out as a free function (rather than inlined in `should_shed_load`)
+/// so it can be tested against fabricated issues instead of real host
+/// resource sampling, which would make the test's outcome depend on the
+/// machine it runs on.
+fn issues_indicate_resource_pressure(issues: &[HealthIssue]) -> bool {
+    issues.iter().any(|issue| {
+        matches!(issue, HealthIssue::LowDiskSpace { .. } | HealthIssue::HighMemoryUsage { .. })
+            && issue.is_critical()
+    })
+}
+
+/// When a recovery action for a given `HealthIssueKind` last fired, and at
+/// what severity — lets the supervisor re-fire on escalation even while
+/// still within the cooldown window.
+struct CooldownState {
+    last_fired: Instant,
+    last_severity: u8,
+}
+
 /// Health metrics collector
 pub struct HealthMonitor {
-    config: HealthConfig,
+    /// Behind a lock (rather than a plain field) so `update_config` can swap
+    /// in a hot-reloaded config without restarting the monitor or its
+    /// subscribers; see `config_watcher`.
+    config: RwLock<HealthConfig>,
     start_time: Instant,
     /// Current metrics
     block_height: RwLock<u64>,
@@ -131,13 +362,29 @@ pub struct HealthMonitor {
     sync_progress: RwLock<u8>,
     last_block_time: RwLock<Instant>,
     mempool_size: RwLock<usize>,
+    /// Set via `set_data_dir`; `None` skips disk sampling entirely (no
+    /// directory to check free space on).
+    data_dir: RwLock<Option<PathBuf>>,
+    /// Last-sampled host memory percentage, refreshed on every `get_health()`.
+    memory_percent: RwLock<Option<u8>>,
+    /// Last-sampled free space (MB) on `data_dir`'s filesystem.
+    disk_free_mb: RwLock<Option<u64>>,
+    /// Lazily created by `subscribe()`; holds the sender so repeat calls
+    /// attach another receiver instead of spawning a second sampler.
+    watch: RwLock<Option<watch::Sender<HealthStatus>>>,
+    /// Registered recovery actions, keyed by the issue kind they remediate.
+    recovery_actions: RwLock<HashMap<HealthIssueKind, Arc<dyn RecoveryAction>>>,
+    /// Per-kind cooldown tracking for `run_recovery_pass`.
+    recovery_cooldowns: RwLock<HashMap<HealthIssueKind, CooldownState>>,
+    /// Guards `start_recovery_supervisor` against spawning more than once.
+    recovery_supervisor_started: RwLock<bool>,
 }
 
 impl HealthMonitor {
     /// Create a new health monitor
     pub fn new(config: HealthConfig) -> Self {
         Self {
-            config,
+            config: RwLock::new(config),
             start_time: Instant::now(),
             block_height: RwLock::new(0),
             peer_count: RwLock::new(0),
@@ -145,6 +392,13 @@ impl HealthMonitor {
             sync_progress: RwLock::new(100),
             last_block_time: RwLock::new(Instant::now()),
             mempool_size: RwLock::new(0),
+            data_dir: RwLock::new(None),
+            memory_percent: RwLock::new(None),
+            disk_free_mb: RwLock::new(None),
+            watch: RwLock::new(None),
+            recovery_actions: RwLock::new(HashMap::new()),
+            recovery_cooldowns: RwLock::new(HashMap::new()),
+            recovery_supervisor_started: RwLock::new(false),
         }
     }
 
@@ -170,8 +424,16 @@ impl HealthMonitor {
         *self.mempool_size.write() = size;
     }
 
+    /// Set the directory whose filesystem `get_health()` checks for free
+    /// space. Without this, disk sampling is skipped and `LowDiskSpace` can
+    /// never be reported.
+    pub fn set_data_dir(&self, path: PathBuf) {
+        *self.data_dir.write() = Some(path);
+    }
+
     /// Get current health status
     pub fn get_health(&self) -> HealthStatus {
+        let config = self.config.read();
         let block_height = *self.block_height.read();
         let peer_count = *self.peer_count.read();
         let is_syncing = *self.is_syncing.read();
@@ -182,35 +444,54 @@ impl HealthMonitor {
         let mut issues = Vec::new();
 
         // Check peer count
-        if peer_count < self.config.min_peers {
+        if peer_count < config.min_peers {
             issues.push(HealthIssue::LowPeerCount {
                 current: peer_count,
-                minimum: self.config.min_peers,
+                minimum: config.min_peers,
             });
         }
 
         // Check block production (only if not syncing)
-        if !is_syncing && seconds_since_last_block > self.config.max_block_gap_seconds {
+        if !is_syncing && seconds_since_last_block > config.max_block_gap_seconds {
             issues.push(HealthIssue::BlockProductionStalled {
                 seconds: seconds_since_last_block,
             });
         }
 
         // Check mempool
-        if mempool_size > self.config.max_mempool_size {
+        if mempool_size > config.max_mempool_size {
             issues.push(HealthIssue::MempoolOverloaded {
                 size: mempool_size,
-                max: self.config.max_mempool_size,
+                max: config.max_mempool_size,
             });
         }
 
         // Check sync lag
-        if is_syncing && (100 - sync_progress) as u64 > self.config.max_sync_lag {
+        if is_syncing && (100 - sync_progress) as u64 > config.max_sync_lag {
             issues.push(HealthIssue::SyncLagging {
                 lag: (100 - sync_progress) as u64,
             });
         }
 
+        // Sample host resources and check them against thresholds. Values
+        // are cached behind locks alongside the other metrics so they can
+        // be inspected without re-sampling (e.g. by `should_shed_load`).
+        let memory_percent = resource_sampler::memory_percent_used();
+        *self.memory_percent.write() = memory_percent;
+        if let Some(percent) = memory_percent {
+            if percent > config.max_memory_percent {
+                issues.push(HealthIssue::HighMemoryUsage { percent });
+            }
+        }
+
+        let disk_free_mb = self.data_dir.read().as_ref().and_then(|dir| resource_sampler::disk_free_mb(dir));
+        *self.disk_free_mb.write() = disk_free_mb;
+        if let Some(available_mb) = disk_free_mb {
+            if available_mb < config.min_disk_space_mb {
+                issues.push(HealthIssue::LowDiskSpace { available_mb });
+            }
+        }
+
         let healthy = issues.is_empty() || !issues.iter().any(|i| i.is_critical());
 
         HealthStatus {
@@ -231,6 +512,134 @@ impl HealthMonitor {
         self.get_health().healthy
     }
 
+    /// Whether disk or memory pressure is currently critical, so an
+    /// upstream subsystem (e.g. the mempool) can refuse new admissions
+    /// rather than make the pressure worse — the same back-pressure idea as
+    /// openethereum's `MAX_UNVERIFIED_QUEUE_SIZE` gate, applied to host
+    /// resources instead of a queue length.
+    pub fn should_shed_load(&self) -> bool {
+        issues_indicate_resource_pressure(&self.get_health().issues)
+    }
+
+    /// Subscribe to push-based health change notifications instead of
+    /// polling `get_health()` on a timer. The first call spawns a background
+    /// task on the caller's tokio runtime that recomputes health every
+    /// `config.sample_interval` and publishes a new value only when the
+    /// `healthy` flag or the active issue set actually changes; subscribers
+    /// then `rx.changed().await` to wake exactly on healthy<->unhealthy
+    /// transitions. Later calls attach another receiver to that same task.
+    pub fn subscribe(self: &Arc<Self>) -> watch::Receiver<HealthStatus> {
+        let mut slot = self.watch.write();
+        if let Some(tx) = slot.as_ref() {
+            return tx.subscribe();
+        }
+
+        let (tx, rx) = watch::channel(self.get_health());
+        *slot = Some(tx.clone());
+        drop(slot);
+
+        let monitor = Arc::clone(self);
+        tokio::spawn(async move {
+            let sample_interval = monitor.config.read().sample_interval;
+            let mut ticker = tokio::time::interval(sample_interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                if tx.is_closed() {
+                    break;
+                }
+                let status = monitor.get_health();
+                tx.send_if_modified(|current| {
+                    if current.healthy == status.healthy && current.issues == status.issues {
+                        return false;
+                    }
+                    *current = status;
+                    true
+                });
+            }
+        });
+
+        rx
+    }
+
+    /// Read the latest value a `subscribe()` receiver has seen without
+    /// awaiting a change — e.g. for logging current state on startup before
+    /// entering a `changed().await` loop.
+    pub fn current(rx: &watch::Receiver<HealthStatus>) -> HealthStatus {
+        rx.borrow().clone()
+    }
+
+    /// Swap in a hot-reloaded config, e.g. from `config_watcher`. Takes
+    /// effect on the next `get_health()` call; already-running `subscribe()`
+    /// samplers keep their original `sample_interval` (see its doc comment).
+    pub fn update_config(&self, new_config: HealthConfig) {
+        *self.config.write() = new_config;
+    }
+
+    /// Register a recovery action for `kind`. A later call with the same
+    /// `kind` replaces the previous registration.
+    pub fn register_recovery_action(&self, kind: HealthIssueKind, action: Arc<dyn RecoveryAction>) {
+        self.recovery_actions.write().insert(kind, action);
+    }
+
+    /// Run the registered action for every currently critical issue, unless
+    /// that kind is still within its cooldown window *and* hasn't escalated
+    /// in severity since it last fired. Exposed directly (not just via the
+    /// supervisor) so tests and one-off callers can trigger a pass
+    /// synchronously.
+    pub async fn run_recovery_pass(&self) {
+        let status = self.get_health();
+        let cooldown = self.config.read().recovery_cooldown;
+
+        for issue in status.issues.iter().filter(|i| i.is_critical()) {
+            let kind = issue.kind();
+            let severity = issue.severity();
+
+            let should_fire = match self.recovery_cooldowns.read().get(&kind) {
+                Some(state) => state.last_fired.elapsed() >= cooldown || severity > state.last_severity,
+                None => true,
+            };
+            if !should_fire {
+                continue;
+            }
+
+            let action = self.recovery_actions.read().get(&kind).cloned();
+            let Some(action) = action else { continue };
+
+            self.recovery_cooldowns
+                .write()
+                .insert(kind, CooldownState { last_fired: Instant::now(), last_severity: severity });
+
+            match action.recover(issue).await {
+                Ok(()) => info!("recovery action fired for {:?} (severity {})", kind, severity),
+                Err(e) => warn!("recovery action for {:?} failed: {}", kind, e),
+            }
+        }
+    }
+
+    /// Spawn the recovery supervisor: a background task that calls
+    /// `run_recovery_pass` every `config.sample_interval`. Idempotent —
+    /// later calls are no-ops once the first has spawned the task.
+    pub fn start_recovery_supervisor(self: &Arc<Self>) {
+        let mut started = self.recovery_supervisor_started.write();
+        if *started {
+            return;
+        }
+        *started = true;
+        drop(started);
+
+        let monitor = Arc::clone(self);
+        tokio::spawn(async move {
+            let sample_interval = monitor.config.read().sample_interval;
+            let mut ticker = tokio::time::interval(sample_interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                monitor.run_recovery_pass().await;
+            }
+        });
+    }
+
     /// Get uptime
     pub fn uptime(&self) -> Duration {
         self.start_time.elapsed()
@@ -320,6 +729,19 @@ mod tests {
         assert!(!low_severity.is_critical());
     }
 
+    #[test]
+    fn test_health_report_includes_issue_severity() {
+        let monitor = HealthMonitor::default(); // no peers registered
+        let report = HealthReport::from(monitor.get_health());
+
+        let low_peer_issue = report
+            .issues
+            .iter()
+            .find(|i| matches!(i.issue, HealthIssue::LowPeerCount { .. }))
+            .expect("expected a LowPeerCount issue with zero peers");
+        assert_eq!(low_peer_issue.severity, low_peer_issue.issue.severity());
+    }
+
     #[test]
     fn test_uptime() {
         let monitor = HealthMonitor::default();
@@ -327,4 +749,189 @@ mod tests {
 
         assert!(monitor.uptime().as_millis() >= 10);
     }
+
+    #[tokio::test]
+    async fn test_subscribe_delivers_initial_value() {
+        let monitor = Arc::new(HealthMonitor::default());
+        let rx = monitor.subscribe();
+        assert!(!HealthMonitor::current(&rx).healthy); // no peers = unhealthy
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_reuses_existing_sampler() {
+        let monitor = Arc::new(HealthMonitor::default());
+        let rx1 = monitor.subscribe();
+        let rx2 = monitor.subscribe();
+        assert_eq!(HealthMonitor::current(&rx1).healthy, HealthMonitor::current(&rx2).healthy);
+    }
+
+    #[test]
+    fn test_health_config_validate_rejects_bad_memory_percent() {
+        let config = HealthConfig { max_memory_percent: 150, ..HealthConfig::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("max_memory_percent"));
+    }
+
+    #[test]
+    fn test_health_config_from_file_round_trips() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("health.toml");
+        std::fs::write(
+            &path,
+            "min_peers = 5\nmax_block_gap_seconds = 30\nmax_mempool_size = 1000\nmax_sync_lag = 50\nmin_disk_space_mb = 500\nmax_memory_percent = 80\n",
+        )
+        .unwrap();
+
+        let config = HealthConfig::from_file(&path).unwrap();
+        assert_eq!(config.min_peers, 5);
+        assert_eq!(config.max_memory_percent, 80);
+    }
+
+    #[test]
+    fn test_health_config_from_file_rejects_invalid_values() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("health.toml");
+        std::fs::write(
+            &path,
+            "min_peers = 5\nmax_block_gap_seconds = 30\nmax_mempool_size = 1000\nmax_sync_lag = 50\nmin_disk_space_mb = 500\nmax_memory_percent = 200\n",
+        )
+        .unwrap();
+
+        assert!(HealthConfig::from_file(&path).is_err());
+    }
+
+    #[test]
+    fn test_should_shed_load_ignores_unrelated_critical_issues() {
+        let issues = vec![HealthIssue::LowPeerCount { current: 0, minimum: 3 }]; // critical, but not resource pressure
+        assert!(!issues_indicate_resource_pressure(&issues));
+    }
+
+    #[test]
+    fn test_should_shed_load_true_for_critical_disk_pressure() {
+        let issues = vec![HealthIssue::LowDiskSpace { available_mb: 50 }]; // < 100 -> severity 10
+        assert!(issues_indicate_resource_pressure(&issues));
+    }
+
+    #[test]
+    fn test_should_shed_load_false_for_non_critical_disk_pressure() {
+        let issues = vec![HealthIssue::LowDiskSpace { available_mb: 800 }]; // severity 7, not critical
+        assert!(!issues_indicate_resource_pressure(&issues));
+    }
+
+    #[test]
+    fn test_should_shed_load_true_for_critical_memory_pressure() {
+        let issues = vec![HealthIssue::HighMemoryUsage { percent: 99 }]; // > 95 -> severity 9
+        assert!(issues_indicate_resource_pressure(&issues));
+    }
+
+    #[test]
+    fn test_get_health_emits_low_disk_space_when_threshold_is_unreachable() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let config = HealthConfig { min_peers: 0, min_disk_space_mb: u64::MAX, ..HealthConfig::default() };
+        let monitor = HealthMonitor::new(config);
+        monitor.set_data_dir(dir.path().to_path_buf());
+
+        let status = monitor.get_health();
+        assert!(status.issues.iter().any(|i| matches!(i, HealthIssue::LowDiskSpace { .. })));
+    }
+
+    #[test]
+    fn test_get_health_skips_disk_check_without_data_dir() {
+        let config = HealthConfig { min_peers: 0, min_disk_space_mb: u64::MAX, ..HealthConfig::default() };
+        let monitor = HealthMonitor::new(config);
+
+        let status = monitor.get_health();
+        assert!(!status.issues.iter().any(|i| matches!(i, HealthIssue::LowDiskSpace { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_recovery_action_fires_for_critical_issue() {
+        let monitor = HealthMonitor::default(); // no peers -> critical LowPeerCount
+        let fired = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let fired_clone = Arc::clone(&fired);
+        monitor.register_recovery_action(
+            HealthIssueKind::LowPeerCount,
+            Arc::new(recovery::low_peer_count_redial(move || {
+                let fired = Arc::clone(&fired_clone);
+                async move {
+                    fired.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    Ok(())
+                }
+            })),
+        );
+
+        monitor.run_recovery_pass().await;
+        assert_eq!(fired.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_recovery_action_respects_cooldown() {
+        let config = HealthConfig { recovery_cooldown: Duration::from_secs(300), ..HealthConfig::default() };
+        let monitor = HealthMonitor::new(config); // no peers -> critical LowPeerCount
+        let fired = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let fired_clone = Arc::clone(&fired);
+        monitor.register_recovery_action(
+            HealthIssueKind::LowPeerCount,
+            Arc::new(recovery::low_peer_count_redial(move || {
+                let fired = Arc::clone(&fired_clone);
+                async move {
+                    fired.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    Ok(())
+                }
+            })),
+        );
+
+        monitor.run_recovery_pass().await;
+        monitor.run_recovery_pass().await;
+        assert_eq!(fired.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_recovery_action_refires_on_severity_escalation() {
+        // min_peers=10: peer_count=4 is LowPeerCount severity 4 (not critical,
+        // since 4 >= 10/2); peer_count=0 is severity 10 (critical). Both are
+        // exercised manually via `issues` so we control severity precisely.
+        let config = HealthConfig { recovery_cooldown: Duration::from_secs(300), ..HealthConfig::default() };
+        let monitor = HealthMonitor::new(config);
+        let fired = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let fired_clone = Arc::clone(&fired);
+        monitor.register_recovery_action(
+            HealthIssueKind::LowPeerCount,
+            Arc::new(recovery::low_peer_count_redial(move || {
+                let fired = Arc::clone(&fired_clone);
+                async move {
+                    fired.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    Ok(())
+                }
+            })),
+        );
+
+        // First pass: peer_count=0 -> severity 10, fires and records it.
+        monitor.run_recovery_pass().await;
+        assert_eq!(fired.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // Manually simulate "already fired at severity 7" so a later pass at
+        // severity 10 must re-fire despite being within the cooldown window.
+        monitor.recovery_cooldowns.write().insert(
+            HealthIssueKind::LowPeerCount,
+            CooldownState { last_fired: Instant::now(), last_severity: 7 },
+        );
+        monitor.run_recovery_pass().await;
+        assert_eq!(fired.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_notifies_on_transition_to_healthy() {
+        let config = HealthConfig { sample_interval: Duration::from_millis(5), ..HealthConfig::default() };
+        let monitor = Arc::new(HealthMonitor::new(config));
+        let mut rx = monitor.subscribe();
+        assert!(!HealthMonitor::current(&rx).healthy);
+
+        monitor.update_peer_count(5);
+        tokio::time::timeout(Duration::from_secs(1), rx.changed())
+            .await
+            .expect("expected a change notification within timeout")
+            .unwrap();
+        assert!(HealthMonitor::current(&rx).healthy);
+    }
 }
@@ -0,0 +1,107 @@
+//! HTTP surface for [`HealthMonitor`].
+//!
+//! Exposes the health checks the doc comment on `health.rs` promises but
+//! that were previously only reachable in-process:
+//!
+//! - `GET /health` — full [`HealthReport`] as JSON, issues tagged with
+//!   severity so dashboards can color-code them.
+//! - `GET /livez`  — cheap liveness probe: 200 as long as the process can
+//!   answer HTTP at all. Never looks at node state.
+//! - `GET /readyz` — readiness probe: 503 when any reported issue is
+//!   critical, 200 otherwise. This is the one a load balancer or
+//!   Kubernetes should gate traffic on, distinct from `/livez`.
+//
+// Add to Cargo.toml: axum = "0.7" (tokio "net" feature already pulled in
+// transitively by the rest of the node). Add tower = "0.4" as a dev-dependency
+// for the `oneshot` test helper below.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use tracing::info;
+
+use crate::health::{HealthMonitor, HealthReport};
+
+/// Serve the health/liveness/readiness endpoints on `addr` until the
+/// process exits or the listener fails. Runs on the caller's tokio runtime;
+/// callers typically `tokio::spawn` this alongside the RPC server.
+pub async fn serve(monitor: Arc<HealthMonitor>, addr: SocketAddr) -> std::io::Result<()> {
+    let app = Router::new()
+        .route("/health", get(health_handler))
+        .route("/livez", get(livez_handler))
+        .route("/readyz", get(readyz_handler))
+        .with_state(monitor);
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("🩺 Health HTTP server listening on {}", addr);
+    axum::serve(listener, app).await
+}
+
+async fn health_handler(State(monitor): State<Arc<HealthMonitor>>) -> Json<HealthReport> {
+    Json(monitor.get_health().into())
+}
+
+async fn livez_handler() -> StatusCode {
+    StatusCode::OK
+}
+
+async fn readyz_handler(State(monitor): State<Arc<HealthMonitor>>) -> (StatusCode, Json<HealthReport>) {
+    let report: HealthReport = monitor.get_health().into();
+    // `healthy` already folds in "no issue has is_critical() == true".
+    let status = if report.healthy { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::health::HealthConfig;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn router(monitor: Arc<HealthMonitor>) -> Router {
+        Router::new()
+            .route("/health", get(health_handler))
+            .route("/livez", get(livez_handler))
+            .route("/readyz", get(readyz_handler))
+            .with_state(monitor)
+    }
+
+    #[tokio::test]
+    async fn livez_is_always_200() {
+        let monitor = Arc::new(HealthMonitor::default());
+        let response = router(monitor)
+            .oneshot(Request::builder().uri("/livez").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn readyz_is_503_when_critical_issue_present() {
+        // Default config requires 3 peers; zero peers -> LowPeerCount with
+        // severity 10 (current == 0), which is critical.
+        let monitor = Arc::new(HealthMonitor::default());
+        let response = router(monitor)
+            .oneshot(Request::builder().uri("/readyz").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn readyz_is_200_when_healthy() {
+        let config = HealthConfig { min_peers: 0, ..HealthConfig::default() };
+        let monitor = Arc::new(HealthMonitor::new(config));
+        let response = router(monitor)
+            .oneshot(Request::builder().uri("/readyz").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -9,6 +9,9 @@ mod executor;
 mod genesis_config;
 mod swarm_broadcaster;
 mod health;
+mod health_http;
+mod config_watcher;
+mod resource_sampler;
 mod metrics;
 mod graceful_shutdown;
 pub mod task_dispatcher;
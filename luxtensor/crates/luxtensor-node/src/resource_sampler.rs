@@ -0,0 +1,113 @@
+//! Host resource sampling: system memory percentage and free disk space on
+//! a given directory's filesystem.
+//!
+//! Split out from `health.rs` so the pure comparison/selection logic can be
+//! unit tested without depending on the real OS state, the same way
+//! `luxtensor-oracle`'s `provider_pool` keeps its consensus math separate
+//! from the network calls that feed it.
+//
+// Add to Cargo.toml: sysinfo = "0.30"
+
+use std::path::Path;
+
+/// One mounted filesystem's free space, as reported by `sysinfo::Disks`.
+/// A plain struct (rather than using `sysinfo::Disk` directly) so
+/// `best_matching_mount` can be tested with fabricated data.
+#[derive(Debug, Clone)]
+pub struct DiskEntry {
+    pub mount_point: std::path::PathBuf,
+    pub available_mb: u64,
+}
+
+/// The percentage of total system memory currently in use, or `None` if the
+/// host reports zero total memory (e.g. an unsupported platform).
+pub fn memory_percent_used() -> Option<u8> {
+    use sysinfo::System;
+    let mut sys = System::new();
+    sys.refresh_memory();
+    compute_memory_percent(sys.total_memory(), sys.used_memory())
+}
+
+/// Free space in MB on the filesystem that `path` resides on, or `None` if
+/// no mounted filesystem matches (or the host reports none at all).
+pub fn disk_free_mb(path: &Path) -> Option<u64> {
+    use sysinfo::Disks;
+    let disks = Disks::new_with_refreshed_list();
+    let entries: Vec<DiskEntry> = disks
+        .iter()
+        .map(|d| DiskEntry {
+            mount_point: d.mount_point().to_path_buf(),
+            available_mb: d.available_space() / 1_000_000,
+        })
+        .collect();
+    best_matching_mount(&entries, path).map(|d| d.available_mb)
+}
+
+/// Percentage of `total` that `used` represents, rounded down and clamped to
+/// `u8` range. `None` when `total` is zero (can't divide).
+fn compute_memory_percent(total: u64, used: u64) -> Option<u8> {
+    if total == 0 {
+        return None;
+    }
+    let percent = (used as f64 / total as f64 * 100.0).round();
+    Some(percent.clamp(0.0, 100.0) as u8)
+}
+
+/// The entry whose mount point is a prefix of `path` and is the longest such
+/// prefix — i.e. the most specific filesystem `path` actually lives on, not
+/// just any ancestor mount.
+fn best_matching_mount<'a>(disks: &'a [DiskEntry], path: &Path) -> Option<&'a DiskEntry> {
+    disks
+        .iter()
+        .filter(|d| path.starts_with(&d.mount_point))
+        .max_by_key(|d| d.mount_point.as_os_str().len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_compute_memory_percent_half_used() {
+        assert_eq!(compute_memory_percent(1000, 500), Some(50));
+    }
+
+    #[test]
+    fn test_compute_memory_percent_zero_total_is_none() {
+        assert_eq!(compute_memory_percent(0, 0), None);
+    }
+
+    #[test]
+    fn test_compute_memory_percent_clamps_to_100() {
+        // Shouldn't happen in practice, but guards against overflow-ish input.
+        assert_eq!(compute_memory_percent(100, 150), Some(100));
+    }
+
+    #[test]
+    fn test_best_matching_mount_picks_most_specific() {
+        let disks = vec![
+            DiskEntry { mount_point: PathBuf::from("/"), available_mb: 1000 },
+            DiskEntry { mount_point: PathBuf::from("/data"), available_mb: 500 },
+        ];
+        let best = best_matching_mount(&disks, Path::new("/data/node/db")).unwrap();
+        assert_eq!(best.mount_point, PathBuf::from("/data"));
+        assert_eq!(best.available_mb, 500);
+    }
+
+    #[test]
+    fn test_best_matching_mount_falls_back_to_root() {
+        let disks = vec![
+            DiskEntry { mount_point: PathBuf::from("/"), available_mb: 1000 },
+            DiskEntry { mount_point: PathBuf::from("/data"), available_mb: 500 },
+        ];
+        let best = best_matching_mount(&disks, Path::new("/home/user/file")).unwrap();
+        assert_eq!(best.mount_point, PathBuf::from("/"));
+    }
+
+    #[test]
+    fn test_best_matching_mount_none_when_no_prefix_matches() {
+        let disks = vec![DiskEntry { mount_point: PathBuf::from("/mnt/extra"), available_mb: 1000 }];
+        assert!(best_matching_mount(&disks, Path::new("/data")).is_none());
+    }
+}
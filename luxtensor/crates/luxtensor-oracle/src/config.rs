@@ -1,6 +1,7 @@
 use ethers::types::Address;
 use serde::Deserialize;
 use std::env;
+use std::time::Duration;
 
 /// Configuration for the AI Oracle Node.
 ///
@@ -16,13 +17,26 @@ use std::env;
 /// | Variable | Default | Description |
 /// |----------|---------|-------------|
 /// | `NODE_WS_URL` | `ws://127.0.0.1:8546` | WebSocket endpoint of the LuxTensor node |
+/// | `NODE_WS_URLS` | _(none)_ | Comma-separated list of WebSocket endpoints for provider failover; overrides `NODE_WS_URL` when set |
 /// | `DATABASE_URL` | _(none)_ | PostgreSQL connection URL for persistent storage |
+/// | `ORACLE_MAX_RECONNECT_ATTEMPTS` | `10` | Reconnection attempts before `run()` gives up |
+/// | `ORACLE_INITIAL_BACKOFF_SECS` | `2` | Initial delay between reconnection attempts |
+/// | `ORACLE_MAX_BACKOFF_SECS` | `120` | Upper bound the backoff delay doubles up to |
 #[derive(Debug, Deserialize, Clone)]
 pub struct OracleConfig {
     pub node_ws_url: String,
+    /// Additional WebSocket endpoints for the provider pool (see `rpc_urls()`).
+    /// Empty means "just `node_ws_url`" — single-provider behavior.
+    pub node_ws_urls: Vec<String>,
     pub oracle_contract_address: Address,
     pub private_key: String,
     pub database_url: Option<String>,
+    /// Reconnection tunables for `run()`'s provider pool connect loop.
+    /// Plain fields (not `Duration`) so the whole struct stays `toml`-
+    /// deserializable for `config_watcher`'s hot-reload.
+    pub max_reconnect_attempts: u32,
+    pub initial_backoff_secs: u64,
+    pub max_backoff_secs: u64,
 }
 
 impl OracleConfig {
@@ -30,6 +44,14 @@ impl OracleConfig {
         let config = Self {
             node_ws_url: env::var("NODE_WS_URL")
                 .unwrap_or_else(|_| "ws://127.0.0.1:8546".to_string()),
+            node_ws_urls: env::var("NODE_WS_URLS")
+                .map(|raw| {
+                    raw.split(',')
+                        .map(|url| url.trim().to_string())
+                        .filter(|url| !url.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
             oracle_contract_address: env::var("ORACLE_CONTRACT_ADDRESS")
                 .map_err(|_| anyhow::anyhow!("ORACLE_CONTRACT_ADDRESS environment variable is required"))?
                 .parse()
@@ -37,25 +59,74 @@ impl OracleConfig {
             private_key: env::var("ORACLE_PRIVATE_KEY")
                 .map_err(|_| anyhow::anyhow!("ORACLE_PRIVATE_KEY environment variable must be set"))?,
             database_url: env::var("DATABASE_URL").ok(),
+            max_reconnect_attempts: env::var("ORACLE_MAX_RECONNECT_ATTEMPTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(10),
+            initial_backoff_secs: env::var("ORACLE_INITIAL_BACKOFF_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(2),
+            max_backoff_secs: env::var("ORACLE_MAX_BACKOFF_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(120),
         };
 
         config.validate()?;
         Ok(config)
     }
 
+    /// Initial delay between provider pool reconnection attempts.
+    pub fn initial_backoff(&self) -> Duration {
+        Duration::from_secs(self.initial_backoff_secs)
+    }
+
+    /// Upper bound the reconnection backoff delay doubles up to.
+    pub fn max_backoff(&self) -> Duration {
+        Duration::from_secs(self.max_backoff_secs)
+    }
+
+    /// Load from a TOML file, the same way `luxtensor-node`'s `Config::from_file`
+    /// loads the node config. Used by `config_watcher` to reload after startup;
+    /// `from_env` remains the path used at process start.
+    pub fn from_file(path: &std::path::Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        let config: Self = toml::from_str(&contents)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Path to watch for hot-reloadable changes, set via `ORACLE_CONFIG_FILE`.
+    /// Optional: most deployments configure purely through environment
+    /// variables and never set this, so `run()` simply skips starting the
+    /// watcher when it's absent.
+    pub fn watch_path() -> Option<std::path::PathBuf> {
+        env::var("ORACLE_CONFIG_FILE").ok().map(std::path::PathBuf::from)
+    }
+
+    /// The full set of RPC endpoints the provider pool should connect to:
+    /// `node_ws_urls` if any were configured, otherwise just `node_ws_url`.
+    pub fn rpc_urls(&self) -> Vec<String> {
+        if self.node_ws_urls.is_empty() {
+            vec![self.node_ws_url.clone()]
+        } else {
+            self.node_ws_urls.clone()
+        }
+    }
+
     /// Validate configuration values beyond basic parsing.
     ///
     /// Checks:
-    /// - `node_ws_url` uses a WebSocket scheme (`ws://` or `wss://`)
+    /// - every URL in `rpc_urls()` uses a WebSocket scheme (`ws://` or `wss://`)
     /// - `private_key` is 64 hex characters (32 bytes)
     /// - `oracle_contract_address` is not the zero address
     pub fn validate(&self) -> anyhow::Result<()> {
         // Validate WebSocket URL scheme
-        if !self.node_ws_url.starts_with("ws://") && !self.node_ws_url.starts_with("wss://") {
-            anyhow::bail!(
-                "NODE_WS_URL must use ws:// or wss:// scheme, got: {}",
-                self.node_ws_url
-            );
+        for url in self.rpc_urls() {
+            if !url.starts_with("ws://") && !url.starts_with("wss://") {
+                anyhow::bail!("RPC URLs must use ws:// or wss:// scheme, got: {}", url);
+            }
         }
 
         // Validate private key format (64 hex chars = 32 bytes)
@@ -72,6 +143,14 @@ impl OracleConfig {
             anyhow::bail!("ORACLE_CONTRACT_ADDRESS must not be the zero address");
         }
 
+        if self.initial_backoff_secs > self.max_backoff_secs {
+            anyhow::bail!(
+                "initial_backoff_secs ({}) must not exceed max_backoff_secs ({})",
+                self.initial_backoff_secs,
+                self.max_backoff_secs
+            );
+        }
+
         Ok(())
     }
 }
@@ -84,12 +163,16 @@ mod tests {
     fn test_validate_good_config() {
         let config = OracleConfig {
             node_ws_url: "ws://127.0.0.1:8546".to_string(),
+            node_ws_urls: vec![],
             oracle_contract_address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
                 .parse()
                 .unwrap(),
             private_key: "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
                 .to_string(),
             database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 2,
+            max_backoff_secs: 120,
         };
         assert!(config.validate().is_ok());
     }
@@ -98,12 +181,16 @@ mod tests {
     fn test_validate_bad_ws_url() {
         let config = OracleConfig {
             node_ws_url: "http://127.0.0.1:8545".to_string(),
+            node_ws_urls: vec![],
             oracle_contract_address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
                 .parse()
                 .unwrap(),
             private_key: "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
                 .to_string(),
             database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 2,
+            max_backoff_secs: 120,
         };
         let err = config.validate().unwrap_err();
         assert!(err.to_string().contains("ws://"));
@@ -113,11 +200,15 @@ mod tests {
     fn test_validate_bad_private_key() {
         let config = OracleConfig {
             node_ws_url: "ws://127.0.0.1:8546".to_string(),
+            node_ws_urls: vec![],
             oracle_contract_address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
                 .parse()
                 .unwrap(),
             private_key: "too_short".to_string(),
             database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 2,
+            max_backoff_secs: 120,
         };
         let err = config.validate().unwrap_err();
         assert!(err.to_string().contains("64 hex"));
@@ -127,10 +218,14 @@ mod tests {
     fn test_validate_zero_address() {
         let config = OracleConfig {
             node_ws_url: "ws://127.0.0.1:8546".to_string(),
+            node_ws_urls: vec![],
             oracle_contract_address: Address::zero(),
             private_key: "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
                 .to_string(),
             database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 2,
+            max_backoff_secs: 120,
         };
         let err = config.validate().unwrap_err();
         assert!(err.to_string().contains("zero address"));
@@ -140,6 +235,7 @@ mod tests {
     fn test_validate_with_0x_prefix() {
         let config = OracleConfig {
             node_ws_url: "wss://mainnet.luxtensor.io/ws".to_string(),
+            node_ws_urls: vec![],
             oracle_contract_address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
                 .parse()
                 .unwrap(),
@@ -149,4 +245,132 @@ mod tests {
         };
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_rpc_urls_falls_back_to_single_node_ws_url() {
+        let config = OracleConfig {
+            node_ws_url: "ws://127.0.0.1:8546".to_string(),
+            node_ws_urls: vec![],
+            oracle_contract_address: Address::zero(),
+            private_key: String::new(),
+            database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 2,
+            max_backoff_secs: 120,
+        };
+        assert_eq!(config.rpc_urls(), vec!["ws://127.0.0.1:8546".to_string()]);
+    }
+
+    #[test]
+    fn test_rpc_urls_prefers_the_pool_list() {
+        let config = OracleConfig {
+            node_ws_url: "ws://127.0.0.1:8546".to_string(),
+            node_ws_urls: vec!["ws://a:8546".to_string(), "ws://b:8546".to_string()],
+            oracle_contract_address: Address::zero(),
+            private_key: String::new(),
+            database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 2,
+            max_backoff_secs: 120,
+        };
+        assert_eq!(config.rpc_urls(), vec!["ws://a:8546".to_string(), "ws://b:8546".to_string()]);
+    }
+
+    #[test]
+    fn test_validate_rejects_a_bad_url_anywhere_in_the_pool() {
+        let config = OracleConfig {
+            node_ws_url: "ws://127.0.0.1:8546".to_string(),
+            node_ws_urls: vec!["ws://good:8546".to_string(), "http://bad:8545".to_string()],
+            oracle_contract_address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+                .parse()
+                .unwrap(),
+            private_key: "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+                .to_string(),
+            database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 2,
+            max_backoff_secs: 120,
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("http://bad:8545"));
+    }
+
+    #[test]
+    fn test_validate_rejects_initial_backoff_exceeding_max() {
+        let config = OracleConfig {
+            node_ws_url: "ws://127.0.0.1:8546".to_string(),
+            node_ws_urls: vec![],
+            oracle_contract_address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+                .parse()
+                .unwrap(),
+            private_key: "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+                .to_string(),
+            database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 200,
+            max_backoff_secs: 120,
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("initial_backoff_secs"));
+    }
+
+    #[test]
+    fn test_from_file_round_trips() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("oracle.toml");
+        std::fs::write(
+            &path,
+            r#"
+            node_ws_url = "ws://127.0.0.1:8546"
+            node_ws_urls = []
+            oracle_contract_address = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+            private_key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+            max_reconnect_attempts = 20
+            initial_backoff_secs = 1
+            max_backoff_secs = 60
+            "#,
+        )
+        .unwrap();
+
+        let config = OracleConfig::from_file(&path).unwrap();
+        assert_eq!(config.max_reconnect_attempts, 20);
+        assert_eq!(config.initial_backoff(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_from_file_rejects_invalid_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("oracle.toml");
+        std::fs::write(
+            &path,
+            r#"
+            node_ws_url = "http://127.0.0.1:8546"
+            node_ws_urls = []
+            oracle_contract_address = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+            private_key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+            max_reconnect_attempts = 10
+            initial_backoff_secs = 2
+            max_backoff_secs = 120
+            "#,
+        )
+        .unwrap();
+
+        assert!(OracleConfig::from_file(&path).is_err());
+    }
+
+    #[test]
+    fn test_backoff_accessors_convert_to_duration() {
+        let config = OracleConfig {
+            node_ws_url: "ws://127.0.0.1:8546".to_string(),
+            node_ws_urls: vec![],
+            oracle_contract_address: Address::zero(),
+            private_key: String::new(),
+            database_url: None,
+            max_reconnect_attempts: 10,
+            initial_backoff_secs: 2,
+            max_backoff_secs: 120,
+        };
+        assert_eq!(config.initial_backoff(), Duration::from_secs(2));
+        assert_eq!(config.max_backoff(), Duration::from_secs(120));
+    }
 }
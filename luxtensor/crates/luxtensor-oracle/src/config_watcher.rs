@@ -0,0 +1,80 @@
+//! Filesystem watcher for hot-reloading [`OracleConfig`]'s reconnection
+//! tunables (`max_reconnect_attempts`, `initial_backoff_secs`,
+//! `max_backoff_secs`).
+//!
+//! Mirrors `luxtensor-node`'s `config_watcher`: debounced with
+//! `notify-debouncer-mini` (the same approach web3-proxy uses to coalesce
+//! rapid filesystem events), re-parsed and validated after a short settle
+//! window, and only swapped into the live config on success. A malformed
+//! reload is logged and discarded rather than crashing `run()`.
+//
+// Add to Cargo.toml: notify-debouncer-mini = "0.4"
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use notify_debouncer_mini::{new_debouncer, DebouncedEventKind};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use crate::config::OracleConfig;
+
+/// Debounce window: filesystem events within this window of each other are
+/// coalesced into a single reload.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(500);
+
+/// Start watching `path` for changes and hot-swap `config`'s contents on
+/// every valid reload. Spawns its own background task and returns
+/// immediately.
+pub fn watch(path: PathBuf, config: Arc<RwLock<OracleConfig>>) {
+    tokio::spawn(async move {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut debouncer = match new_debouncer(DEBOUNCE_WINDOW, move |result| {
+            let _ = tx.send(result);
+        }) {
+            Ok(debouncer) => debouncer,
+            Err(e) => {
+                warn!("oracle config watcher: failed to start: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = debouncer
+            .watcher()
+            .watch(&path, notify_debouncer_mini::notify::RecursiveMode::NonRecursive)
+        {
+            warn!("oracle config watcher: failed to watch {}: {}", path.display(), e);
+            return;
+        }
+
+        info!("Watching {} for OracleConfig changes", path.display());
+
+        while let Some(result) = rx.recv().await {
+            let events = match result {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!("oracle config watcher: watch error: {}", e);
+                    continue;
+                }
+            };
+            if !events.iter().any(|e| e.kind == DebouncedEventKind::Any) {
+                continue;
+            }
+
+            match OracleConfig::from_file(&path) {
+                Ok(new_config) => {
+                    *config.write().await = new_config;
+                    info!("Reloaded OracleConfig from {}", path.display());
+                }
+                Err(e) => {
+                    warn!(
+                        "oracle config watcher: keeping previous config, reload of {} failed: {}",
+                        path.display(),
+                        e
+                    );
+                }
+            }
+        }
+    });
+}
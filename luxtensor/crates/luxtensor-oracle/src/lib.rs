@@ -3,9 +3,11 @@
 //! Bridges on-chain "Verifiable Intelligence" requests with off-chain AI computation.
 
 pub mod config;
+pub mod config_watcher;
 pub mod dispute;
 pub mod listener;
 pub mod processor;
+pub mod provider_pool;
 pub mod submitter;
 pub mod error;
 
@@ -13,6 +15,7 @@ pub use config::OracleConfig;
 pub use dispute::{DisputeManager, DisputeConfig, DisputeStatus, FraudProof};
 pub use listener::EventWatcher;
 pub use processor::RequestProcessor;
+pub use provider_pool::{ProviderPool, ProviderPoolConfig};
 pub use submitter::TxSubmitter;
 
 // Re-export ethers types used in the dispute API so downstream crates
@@ -20,13 +23,16 @@ pub use submitter::TxSubmitter;
 pub use ethers::types::{H256, Bytes as EthBytes};
 
 use tracing::{info, warn, error};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::RwLock as AsyncRwLock;
 
-/// Maximum number of reconnection attempts before giving up.
+/// Default reconnection tunables for `ProviderPoolConfig::default()`, used
+/// whenever pool config isn't derived from a live `OracleConfig` (e.g. direct
+/// callers of `ProviderPool::connect` in tests). `run()` itself always
+/// derives pool config from the live, hot-reloadable `OracleConfig` instead.
 const MAX_RECONNECT_ATTEMPTS: u32 = 10;
-/// Initial backoff delay between reconnection attempts.
 const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
-/// Maximum backoff delay between reconnection attempts.
 const MAX_BACKOFF: Duration = Duration::from_secs(120);
 
 pub async fn run(config: OracleConfig) -> anyhow::Result<()> {
@@ -34,69 +40,59 @@ pub async fn run(config: OracleConfig) -> anyhow::Result<()> {
 
     let processor = RequestProcessor::new();
 
-    let mut backoff = INITIAL_BACKOFF;
-    let mut consecutive_failures: u32 = 0;
+    // Reconnection tunables and the oracle's RPC endpoints live behind a
+    // lock so `config_watcher` can hot-reload them from `ORACLE_CONFIG_FILE`
+    // without restarting the process; see its module docs.
+    let config = Arc::new(AsyncRwLock::new(config));
+    if let Some(path) = OracleConfig::watch_path() {
+        config_watcher::watch(path, Arc::clone(&config));
+    } else {
+        info!("ORACLE_CONFIG_FILE not set; hot-reload disabled");
+    }
 
-    loop {
-        // 1. Initialize components (re-create on each reconnection)
-        let watcher = match EventWatcher::new(&config).await {
-            Ok(w) => {
-                consecutive_failures = 0;
-                backoff = INITIAL_BACKOFF;
-                w
-            }
+    // 1. Stand up the provider pool, retrying the whole pool (not just one
+    // URL) with backoff until at least one connection succeeds.
+    let mut backoff = config.read().await.initial_backoff();
+    let mut consecutive_failures: u32 = 0;
+    let pool = loop {
+        let snapshot = config.read().await.clone();
+        let pool_config = ProviderPoolConfig::from_oracle_config(&snapshot);
+        match ProviderPool::connect(&snapshot, pool_config).await {
+            Ok(pool) => break Arc::new(pool),
             Err(e) => {
                 consecutive_failures += 1;
-                if consecutive_failures > MAX_RECONNECT_ATTEMPTS {
+                if consecutive_failures > snapshot.max_reconnect_attempts {
                     error!(
                         "Failed to connect after {} attempts, giving up: {}",
-                        MAX_RECONNECT_ATTEMPTS, e
+                        snapshot.max_reconnect_attempts, e
                     );
                     return Err(e.into());
                 }
                 warn!(
-                    "Connection failed (attempt {}/{}): {}, retrying in {:?}",
-                    consecutive_failures, MAX_RECONNECT_ATTEMPTS, e, backoff
+                    "Provider pool connect failed (attempt {}/{}): {}, retrying in {:?}",
+                    consecutive_failures, snapshot.max_reconnect_attempts, e, backoff
                 );
                 tokio::time::sleep(backoff).await;
-                backoff = (backoff * 2).min(MAX_BACKOFF);
-                continue;
+                backoff = (backoff * 2).min(snapshot.max_backoff());
             }
-        };
-
-        let submitter = match TxSubmitter::new(&config).await {
-            Ok(s) => s,
-            Err(e) => {
-                consecutive_failures += 1;
-                if consecutive_failures > MAX_RECONNECT_ATTEMPTS {
-                    error!(
-                        "Failed to create submitter after {} attempts, giving up: {}",
-                        MAX_RECONNECT_ATTEMPTS, e
-                    );
-                    return Err(e.into());
-                }
-                warn!(
-                    "Submitter init failed (attempt {}/{}): {}, retrying in {:?}",
-                    consecutive_failures, MAX_RECONNECT_ATTEMPTS, e, backoff
-                );
-                tokio::time::sleep(backoff).await;
-                backoff = (backoff * 2).min(MAX_BACKOFF);
-                continue;
-            }
-        };
+        }
+    };
+    pool.spawn_head_tracking();
 
-        info!("Oracle Node initialized. Listening for events...");
+    info!("Oracle Node initialized. Listening for events across {} provider(s)...", pool.len());
 
-        // 2. Watch events with handler
-        let result = watcher.watch_events(|event| {
-            let watcher_clone = watcher.clone();
+    // 2. Watch events with handler, routed through the pool. `watch_events`
+    // already fails over between connections internally and only returns
+    // once every connection has exhausted its own reconnect attempts.
+    let result = pool
+        .watch_events(|event| {
+            let pool = Arc::clone(&pool);
             let processor_ref = &processor;
-            let submitter_ref = &submitter;
 
             async move {
                 info!("Event received: RequestID={:?}", hex::encode(event.request_id));
 
-                let input_data = match watcher_clone.get_request_input(event.request_id).await {
+                let input_data = match pool.get_request_input(event.request_id).await {
                     Ok(data) => data,
                     Err(e) => {
                         error!("Failed to fetch request input: {}", e);
@@ -104,46 +100,25 @@ pub async fn run(config: OracleConfig) -> anyhow::Result<()> {
                     }
                 };
 
-                match processor_ref.process_request(
-                    event.request_id.into(),
-                    event.model_hash.into(),
-                    input_data
-                ).await {
+                match processor_ref
+                    .process_request(event.request_id.into(), event.model_hash.into(), input_data)
+                    .await
+                {
                     Ok((result, proof_hash)) => {
-                        if let Err(e) = submitter_ref.submit_fulfillment(
-                            event.request_id,
-                            result,
-                            proof_hash.into()
-                        ).await {
+                        if let Err(e) =
+                            pool.submit_fulfillment(event.request_id, result, proof_hash.into()).await
+                        {
                             error!("Failed to submit transaction: {}", e);
                         }
-                    },
+                    }
                     Err(e) => error!("Failed to process request: {}", e),
                 }
             }
-        }).await;
-
-        // 3. Event stream ended — reconnect with backoff
-        match result {
-            Ok(()) => {
-                warn!("WebSocket event stream ended unexpectedly, reconnecting...");
-            }
-            Err(e) => {
-                warn!("WebSocket event stream error: {}, reconnecting...", e);
-            }
-        }
+        })
+        .await;
 
-        consecutive_failures += 1;
-        if consecutive_failures > MAX_RECONNECT_ATTEMPTS {
-            error!(
-                "Too many consecutive reconnection failures ({}), shutting down",
-                consecutive_failures
-            );
-            anyhow::bail!("Oracle exceeded maximum reconnection attempts");
-        }
-
-        warn!("Reconnecting in {:?} (attempt {}/{})", backoff, consecutive_failures, MAX_RECONNECT_ATTEMPTS);
-        tokio::time::sleep(backoff).await;
-        backoff = (backoff * 2).min(MAX_BACKOFF);
-    }
+    result.map_err(|e| {
+        error!("Provider pool exhausted all reconnection attempts: {}", e);
+        anyhow::anyhow!("Oracle exceeded maximum reconnection attempts: {}", e)
+    })
 }
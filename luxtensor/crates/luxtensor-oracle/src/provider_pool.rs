@@ -0,0 +1,382 @@
+//! Multi-provider failover and consensus head tracking for the Oracle node.
+//!
+//! `run()` used to hand the whole node over to a single `EventWatcher`; if
+//! that one RPC endpoint stalled or lied about the chain head, the oracle
+//! would silently fall behind with no way to tell. A `ProviderPool` instead
+//! opens a connection to every URL in `OracleConfig::rpc_urls()`, has each
+//! one report its latest observed head into a shared table, and derives a
+//! "consensus head" from whatever a quorum of them agree on — the same
+//! shape as web3-proxy's load-balanced connection pool, just sized down to
+//! what this node actually needs: read/write failover plus head-lag
+//! detection, not a general-purpose request router.
+
+use crate::config::OracleConfig;
+use crate::error::{OracleError, Result};
+use crate::listener::EventWatcher;
+use crate::submitter::TxSubmitter;
+use ethers::prelude::*;
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Tuning knobs for the pool. Kept separate from `OracleConfig` since these
+/// govern pool *behavior* rather than identifying endpoints to connect to.
+#[derive(Debug, Clone)]
+pub struct ProviderPoolConfig {
+    /// Fraction of connections (0.0-1.0) that must agree on a height for it
+    /// to count as the consensus head. 0.5 means a simple majority.
+    pub quorum_fraction: f64,
+    /// A connection more than this many blocks behind the consensus head is
+    /// considered unhealthy and skipped for routing.
+    pub max_head_lag: u64,
+    /// How often each connection polls its own latest block height.
+    pub head_poll_interval: Duration,
+    /// Reconnect attempts a single connection's event stream may exhaust
+    /// before the pool stops retrying it.
+    pub max_reconnect_attempts: u32,
+    /// Initial delay between `watch_events` reconnect attempts.
+    pub initial_backoff: Duration,
+    /// Upper bound the reconnect backoff delay doubles up to.
+    pub max_backoff: Duration,
+}
+
+impl Default for ProviderPoolConfig {
+    fn default() -> Self {
+        Self {
+            quorum_fraction: 0.5,
+            max_head_lag: 5,
+            head_poll_interval: Duration::from_secs(3),
+            max_reconnect_attempts: crate::MAX_RECONNECT_ATTEMPTS,
+            initial_backoff: crate::INITIAL_BACKOFF,
+            max_backoff: crate::MAX_BACKOFF,
+        }
+    }
+}
+
+impl ProviderPoolConfig {
+    /// Derive pool tuning from a (possibly hot-reloaded) `OracleConfig`,
+    /// keeping `quorum_fraction`/`max_head_lag`/`head_poll_interval` at
+    /// their defaults since those aren't exposed as `OracleConfig` fields.
+    pub fn from_oracle_config(config: &OracleConfig) -> Self {
+        Self {
+            max_reconnect_attempts: config.max_reconnect_attempts,
+            initial_backoff: config.initial_backoff(),
+            max_backoff: config.max_backoff(),
+            ..Self::default()
+        }
+    }
+}
+
+/// One RPC endpoint in the pool: its own watcher/submitter handles, its own
+/// exponential backoff for event-stream reconnects, and the last head
+/// height it reported.
+struct ProviderConnection {
+    url: String,
+    watcher: EventWatcher,
+    submitter: TxSubmitter,
+    /// `u64::MAX` sentinel means "no head observed yet".
+    latest_head: AtomicU64,
+    /// Failures of `get_request_input`/`submit_fulfillment` on this
+    /// connection; used only to order failover attempts, not to exclude it.
+    consecutive_request_failures: AtomicU32,
+    /// Failures of this connection's own event stream; once this exceeds
+    /// `max_reconnect_attempts` the pool stops retrying it.
+    reconnect_attempts: AtomicU32,
+}
+
+impl ProviderConnection {
+    fn has_head(&self) -> Option<u64> {
+        match self.latest_head.load(Ordering::Relaxed) {
+            u64::MAX => None,
+            height => Some(height),
+        }
+    }
+}
+
+/// The highest height that at least `quorum_fraction` of `heads` have
+/// reached, i.e. the `required`-th highest value where `required` is
+/// `ceil(heads.len() * quorum_fraction)`. Pulled out as a free function so
+/// the quorum math can be tested without spinning up real connections.
+fn compute_consensus_head(heads: &[u64], quorum_fraction: f64) -> Option<u64> {
+    if heads.is_empty() {
+        return None;
+    }
+
+    let mut sorted = heads.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    let required = ((sorted.len() as f64) * quorum_fraction).ceil() as usize;
+    let required = required.clamp(1, sorted.len());
+    sorted.get(required - 1).copied()
+}
+
+/// A connection with no reported head yet gets the benefit of the doubt
+/// (e.g. at startup, before any polling has completed); once consensus and
+/// the connection's head are both known, it must be within `max_lag`.
+fn head_is_in_consensus(consensus: Option<u64>, head: Option<u64>, max_lag: u64) -> bool {
+    match (consensus, head) {
+        (Some(consensus), Some(head)) => consensus.saturating_sub(head) <= max_lag,
+        _ => true,
+    }
+}
+
+/// A pool of redundant connections to the same LuxTensor chain, routing
+/// reads/writes to whichever in-consensus connection is currently
+/// healthiest and failing over to the next on error.
+pub struct ProviderPool {
+    connections: Vec<Arc<ProviderConnection>>,
+    config: ProviderPoolConfig,
+    /// Index into `connections` that `watch_events` is currently attached
+    /// to; advanced on stream end/error.
+    primary: AtomicUsize,
+}
+
+impl ProviderPool {
+    /// Connect to every URL in `config.rpc_urls()`. Fails only if *none* of
+    /// them can be reached — a partially-up pool is still useful.
+    pub async fn connect(config: &OracleConfig, pool_config: ProviderPoolConfig) -> Result<Self> {
+        let urls = config.rpc_urls();
+        let mut connections = Vec::with_capacity(urls.len());
+
+        for url in &urls {
+            let mut per_provider = config.clone();
+            per_provider.node_ws_url = url.clone();
+
+            match Self::connect_one(&per_provider).await {
+                Ok(conn) => connections.push(Arc::new(conn)),
+                Err(e) => warn!("provider pool: failed to connect to {}: {}", url, e),
+            }
+        }
+
+        if connections.is_empty() {
+            return Err(OracleError::Connection(format!(
+                "failed to connect to any of {} configured RPC URL(s)",
+                urls.len()
+            )));
+        }
+
+        info!("Provider pool connected to {}/{} RPC URL(s)", connections.len(), urls.len());
+
+        Ok(Self { connections, config: pool_config, primary: AtomicUsize::new(0) })
+    }
+
+    async fn connect_one(config: &OracleConfig) -> Result<ProviderConnection> {
+        let watcher = EventWatcher::new(config).await?;
+        let submitter = TxSubmitter::new(config).await?;
+        Ok(ProviderConnection {
+            url: config.node_ws_url.clone(),
+            watcher,
+            submitter,
+            latest_head: AtomicU64::new(u64::MAX),
+            consecutive_request_failures: AtomicU32::new(0),
+            reconnect_attempts: AtomicU32::new(0),
+        })
+    }
+
+    /// Spawn one background task per connection that polls its head height
+    /// on `config.head_poll_interval`, so `consensus_head()` is always
+    /// cheap to read and never blocks on an RPC round-trip.
+    pub fn spawn_head_tracking(self: &Arc<Self>) {
+        for conn in &self.connections {
+            let conn = Arc::clone(conn);
+            let interval = self.config.head_poll_interval;
+            tokio::spawn(async move {
+                let mut ticker = tokio::time::interval(interval);
+                ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                loop {
+                    ticker.tick().await;
+                    match conn.watcher.client.get_block_number().await {
+                        Ok(height) => conn.latest_head.store(height.as_u64(), Ordering::Relaxed),
+                        Err(e) => warn!("provider pool: head poll failed for {}: {}", conn.url, e),
+                    }
+                }
+            });
+        }
+    }
+
+    /// The highest block height that at least `quorum_fraction` of
+    /// connections with a known head have reached. `None` until enough
+    /// connections have reported at least once.
+    pub fn consensus_head(&self) -> Option<u64> {
+        let heads: Vec<u64> = self.connections.iter().filter_map(|c| c.has_head()).collect();
+        compute_consensus_head(&heads, self.config.quorum_fraction)
+    }
+
+    /// A connection counts as in-consensus if it hasn't reported a head yet
+    /// (benefit of the doubt during startup) or is within `max_head_lag`
+    /// blocks of the consensus head.
+    fn is_in_consensus(&self, conn: &ProviderConnection) -> bool {
+        head_is_in_consensus(self.consensus_head(), conn.has_head(), self.config.max_head_lag)
+    }
+
+    /// In-consensus connections that haven't exhausted their reconnect
+    /// budget, ordered by fewest recent request failures first.
+    fn healthy_order(&self) -> Vec<Arc<ProviderConnection>> {
+        let mut candidates: Vec<_> = self
+            .connections
+            .iter()
+            .filter(|c| self.is_in_consensus(c))
+            .filter(|c| c.reconnect_attempts.load(Ordering::Relaxed) <= self.config.max_reconnect_attempts)
+            .cloned()
+            .collect();
+        candidates.sort_by_key(|c| c.consecutive_request_failures.load(Ordering::Relaxed));
+        candidates
+    }
+
+    /// Fetch a request's input data, trying the healthiest in-consensus
+    /// connection first and failing over to the next on error.
+    pub async fn get_request_input(&self, request_id: [u8; 32]) -> Result<Bytes> {
+        self.with_failover(|conn| async move { conn.watcher.get_request_input(request_id).await })
+            .await
+    }
+
+    /// Submit a fulfillment transaction, trying the healthiest in-consensus
+    /// connection first and failing over to the next on error.
+    pub async fn submit_fulfillment(
+        &self,
+        request_id: [u8; 32],
+        result: Bytes,
+        proof_hash: [u8; 32],
+    ) -> Result<H256> {
+        self.with_failover(|conn| {
+            let result = result.clone();
+            async move { conn.submitter.submit_fulfillment(request_id, result, proof_hash).await }
+        })
+        .await
+    }
+
+    async fn with_failover<T, F, Fut>(&self, op: F) -> Result<T>
+    where
+        F: Fn(Arc<ProviderConnection>) -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let candidates = self.healthy_order();
+        if candidates.is_empty() {
+            return Err(OracleError::Connection("no providers in consensus".to_string()));
+        }
+
+        let mut last_err = None;
+        for conn in candidates {
+            match op(Arc::clone(&conn)).await {
+                Ok(value) => {
+                    conn.consecutive_request_failures.store(0, Ordering::Relaxed);
+                    return Ok(value);
+                }
+                Err(e) => {
+                    conn.consecutive_request_failures.fetch_add(1, Ordering::Relaxed);
+                    warn!("provider pool: {} failed, trying next provider: {}", conn.url, e);
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| OracleError::Connection("all providers failed".to_string())))
+    }
+
+    /// Watch events on the current primary connection, rotating to the next
+    /// connection on stream end/error. Each connection keeps its own
+    /// reconnect counter and backoff; the whole pool only gives up once
+    /// *every* connection has exhausted `max_reconnect_attempts`.
+    pub async fn watch_events<F, Fut>(&self, handler: F) -> Result<()>
+    where
+        F: FnMut(crate::listener::AirequestCreatedFilter) -> Fut + Clone,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let mut backoff = self.config.initial_backoff;
+
+        loop {
+            let idx = self.primary.load(Ordering::Relaxed) % self.connections.len();
+            let conn = Arc::clone(&self.connections[idx]);
+
+            if conn.reconnect_attempts.load(Ordering::Relaxed) > self.config.max_reconnect_attempts {
+                // Already exhausted — move straight to the next one.
+                if self.all_exhausted() {
+                    return Err(OracleError::Connection(
+                        "all providers exhausted their reconnect attempts".to_string(),
+                    ));
+                }
+                self.primary.store((idx + 1) % self.connections.len(), Ordering::Relaxed);
+                continue;
+            }
+
+            info!("Watching events on provider {}", conn.url);
+            let result = conn.watcher.watch_events(handler.clone()).await;
+
+            match result {
+                Ok(()) => warn!("provider pool: event stream on {} ended, rotating", conn.url),
+                Err(e) => warn!("provider pool: event stream on {} errored: {}, rotating", conn.url, e),
+            }
+
+            conn.reconnect_attempts.fetch_add(1, Ordering::Relaxed);
+            if self.all_exhausted() {
+                return Err(OracleError::Connection(
+                    "all providers exhausted their reconnect attempts".to_string(),
+                ));
+            }
+
+            self.primary.store((idx + 1) % self.connections.len(), Ordering::Relaxed);
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(self.config.max_backoff);
+        }
+    }
+
+    fn all_exhausted(&self) -> bool {
+        self.connections
+            .iter()
+            .all(|c| c.reconnect_attempts.load(Ordering::Relaxed) > self.config.max_reconnect_attempts)
+    }
+
+    /// Number of connections currently in the pool (not all necessarily
+    /// in-consensus).
+    pub fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.connections.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consensus_head_is_none_with_no_reports() {
+        assert_eq!(compute_consensus_head(&[], 0.5), None);
+    }
+
+    #[test]
+    fn test_consensus_head_majority_of_three() {
+        // Two of three agree on 100; the majority (required = ceil(3*0.5) = 2)
+        // consensus head is 100, not the straggler's 90 or the leader's 105.
+        assert_eq!(compute_consensus_head(&[100, 100, 105], 0.5), Some(100));
+    }
+
+    #[test]
+    fn test_consensus_head_requires_full_set_at_100_percent_quorum() {
+        assert_eq!(compute_consensus_head(&[100, 100, 90], 1.0), Some(90));
+    }
+
+    #[test]
+    fn test_consensus_head_single_provider_is_its_own_consensus() {
+        assert_eq!(compute_consensus_head(&[42], 0.5), Some(42));
+    }
+
+    #[test]
+    fn test_head_with_no_reports_gets_benefit_of_the_doubt() {
+        assert!(head_is_in_consensus(None, None, 5));
+        assert!(head_is_in_consensus(Some(100), None, 5));
+    }
+
+    #[test]
+    fn test_head_within_lag_is_in_consensus() {
+        assert!(head_is_in_consensus(Some(100), Some(96), 5));
+        assert!(head_is_in_consensus(Some(100), Some(95), 5));
+    }
+
+    #[test]
+    fn test_head_beyond_lag_is_not_in_consensus() {
+        assert!(!head_is_in_consensus(Some(100), Some(90), 5));
+    }
+}
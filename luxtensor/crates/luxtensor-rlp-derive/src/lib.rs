@@ -0,0 +1,95 @@
+//! Derive macros for `luxtensor_core::rlp::{Encodable, Decodable}`.
+//!
+//! `#[derive(RlpEncodable, RlpDecodable)]` treats a struct's fields as a
+//! positional RLP list in declaration order: encoding emits
+//! `begin_list(field_count)` followed by one `rlp_append` per field, and
+//! decoding reads back successive `Rlp::at(i)` views in the same order. This
+//! replaces bespoke per-field `rlp_item_to_*` extraction with one derive.
+//!
+//! ```ignore
+//! use luxtensor_rlp_derive::{RlpEncodable, RlpDecodable};
+//!
+//! #[derive(RlpEncodable, RlpDecodable)]
+//! struct Transfer {
+//!     nonce: u64,
+//!     to: [u8; 20],
+//!     value: u128,
+//!     data: Vec<u8>,
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+#[proc_macro_derive(RlpEncodable)]
+pub fn derive_rlp_encodable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match named_struct_fields(&input.data, &input.ident) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let field_count = fields.len();
+    let field_idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+
+    let expanded = quote! {
+        impl ::luxtensor_core::rlp::Encodable for #name {
+            fn rlp_append(&self, s: &mut ::luxtensor_core::rlp::RlpStream) {
+                s.begin_list(#field_count);
+                #( ::luxtensor_core::rlp::Encodable::rlp_append(&self.#field_idents, s); )*
+            }
+        }
+    };
+    expanded.into()
+}
+
+#[proc_macro_derive(RlpDecodable)]
+pub fn derive_rlp_decodable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match named_struct_fields(&input.data, &input.ident) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let field_idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let indices = 0u32..(field_idents.len() as u32);
+
+    let expanded = quote! {
+        impl ::luxtensor_core::rlp::Decodable for #name {
+            fn decode(
+                rlp: &::luxtensor_core::rlp::Rlp,
+            ) -> Result<Self, ::luxtensor_core::rlp::DecoderError> {
+                Ok(Self {
+                    #(
+                        #field_idents: ::luxtensor_core::rlp::Decodable::decode(
+                            &rlp.at(#indices as usize).map_err(::luxtensor_core::rlp::DecoderError::from)?,
+                        )?,
+                    )*
+                })
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// Both derives only support structs with named fields — tuple structs and
+/// enums have no stable notion of "field declaration order" to key the RLP
+/// list position on.
+fn named_struct_fields(data: &Data, name: &syn::Ident) -> syn::Result<Vec<syn::Field>> {
+    match data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields.named.iter().cloned().collect()),
+            _ => Err(syn::Error::new_spanned(
+                name,
+                "RlpEncodable/RlpDecodable only support structs with named fields",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            name,
+            "RlpEncodable/RlpDecodable only support structs with named fields",
+        )),
+    }
+}
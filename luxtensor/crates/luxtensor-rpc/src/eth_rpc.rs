@@ -2132,9 +2132,11 @@ fn parse_hash(s: &str) -> Result<[u8; 32], RpcError> {
 pub fn register_aa_methods(
     io: &mut IoHandler,
     entry_point: Arc<RwLock<luxtensor_contracts::EntryPoint>>,
+    unified_state: Arc<RwLock<luxtensor_core::UnifiedStateDB>>,
 ) {
     // eth_sendUserOperation - Submit a user operation
     let ep = entry_point.clone();
+    let state_for_send = unified_state.clone();
     io.add_sync_method("eth_sendUserOperation", move |params: Params| {
         let p: Vec<serde_json::Value> = params.parse()?;
 
@@ -2153,14 +2155,18 @@ pub fn register_aa_methods(
         // Parse user operation
         let user_op = parse_user_operation(user_op_json)?;
 
-        // Validate and queue the operation for block inclusion
+        // `add_pending_op` runs signature/nonce/paymaster verification
+        // exactly once, consults the sender's and paymaster's mempool
+        // reputation, and enforces the per-sender pending-op cap before
+        // queuing — block production never re-validates it.
         let entry_point = ep.read();
-        match entry_point.validate_user_op(&user_op) {
-            Ok(()) => {
-                // Queue in EntryPoint's pending pool — will be drained during block production
-                let op_hash = entry_point.queue_user_op(user_op);
-                Ok(json!(format!("0x{}", hex::encode(op_hash))))
-            }
+        let current_block = state_for_send.read().block_number();
+        let current_timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        match entry_point.add_pending_op(user_op.into(), current_block, current_timestamp) {
+            Ok(op_hash) => Ok(json!(format!("0x{}", hex::encode(op_hash)))),
             Err(e) => Err(RpcError {
                 code: ErrorCode::InvalidParams,
                 message: format!("Validation failed: {}", e),
@@ -2217,8 +2223,19 @@ pub fn register_aa_methods(
                 "sender": format!("0x{}", hex::encode(receipt.sender.as_bytes())),
                 "nonce": format!("0x{:x}", receipt.nonce),
                 "paymaster": receipt.paymaster.map(|p| format!("0x{}", hex::encode(p.as_bytes()))),
+                "txType": match receipt.tx_type {
+                    luxtensor_contracts::UserOpTxType::SelfPaying => "selfPaying",
+                    luxtensor_contracts::UserOpTxType::Sponsored => "sponsored",
+                },
                 "actualGasUsed": format!("0x{:x}", receipt.actual_gas_used),
+                "effectiveGasPrice": format!("0x{:x}", receipt.effective_gas_price),
                 "actualGasCost": format!("0x{:x}", receipt.actual_gas_cost),
+                "logsBloom": format!("0x{}", hex::encode(receipt.logs_bloom)),
+                "logs": receipt.logs.iter().map(|l| json!({
+                    "address": format!("0x{}", hex::encode(l.address.as_bytes())),
+                    "topics": l.topics.iter().map(|t| format!("0x{}", hex::encode(t))).collect::<Vec<_>>(),
+                    "data": format!("0x{}", hex::encode(&l.data)),
+                })).collect::<Vec<_>>(),
                 "success": receipt.success,
                 "reason": receipt.reason,
                 "receipt": {
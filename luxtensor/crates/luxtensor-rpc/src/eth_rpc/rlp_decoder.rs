@@ -183,18 +183,62 @@ pub(crate) fn rlp_item_to_32(item: &[u8]) -> [u8; 32] {
     buf
 }
 
+/// secp256k1 curve order `n`, big-endian.
+const SECP256K1_N: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// secp256k1 curve order halved, `n / 2`, big-endian (EIP-2 low-s threshold).
+const SECP256K1_N_HALF: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+fn is_zero(x: &[u8; 32]) -> bool {
+    x.iter().all(|&b| b == 0)
+}
+
+/// Reject `r`/`s` that are zero, that are `>= n`, or (unless `allow_high_s` is set) that
+/// violate the EIP-2 low-s requirement (`s > n/2`). Malleable/out-of-range signatures must
+/// never be allowed to recover to a "valid looking" address.
+fn validate_signature_scalars(r: &[u8; 32], s: &[u8; 32], allow_high_s: bool) -> Result<(), String> {
+    if is_zero(r) {
+        return Err("Invalid signature: r is zero".into());
+    }
+    if is_zero(s) {
+        return Err("Invalid signature: s is zero".into());
+    }
+    if r >= &SECP256K1_N {
+        return Err("Invalid signature: r >= secp256k1 n".into());
+    }
+    if s >= &SECP256K1_N {
+        return Err("Invalid signature: s >= secp256k1 n".into());
+    }
+    if !allow_high_s && s > &SECP256K1_N_HALF {
+        return Err(
+            "Invalid signature: s > n/2 (EIP-2 high-s malleable signature rejected)".into(),
+        );
+    }
+    Ok(())
+}
+
 /// Recover sender address from ECDSA signature using secp256k1 ecrecover
 /// msg_hash: 32-byte Keccak256 of the signing payload
 /// v: recovery ID (0 or 1 after EIP-155 normalization)
 /// r, s: 32-byte signature components
+/// allow_high_s: permit signatures with `s > n/2` (needed for historical pre-EIP-2 txs)
 pub(crate) fn ecrecover_address(
     msg_hash: &[u8; 32],
     v: u8,
     r: &[u8; 32],
     s: &[u8; 32],
+    allow_high_s: bool,
 ) -> Result<Address, String> {
     use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
 
+    validate_signature_scalars(r, s, allow_high_s)?;
+
     // Build the 64-byte compact signature (r || s)
     let mut sig_bytes = [0u8; 64];
     sig_bytes[..32].copy_from_slice(r);
@@ -307,7 +351,7 @@ fn decode_legacy_tx(raw: &[u8]) -> Result<RlpDecodedTx, String> {
     };
 
     // Recover sender
-    let from = ecrecover_address(&signing_hash_arr, recovery_id, &r, &s)?;
+    let from = ecrecover_address(&signing_hash_arr, recovery_id, &r, &s, false)?;
 
     // Compute tx hash = keccak256(raw RLP)
     let tx_hash_arr = {
@@ -382,7 +426,7 @@ fn decode_eip2930_tx(raw: &[u8]) -> Result<RlpDecodedTx, String> {
         arr
     };
 
-    let from = ecrecover_address(&signing_hash_arr, recovery_id, &r, &s)?;
+    let from = ecrecover_address(&signing_hash_arr, recovery_id, &r, &s, false)?;
 
     // tx hash = keccak256(full raw bytes)
     let tx_hash_arr = {
@@ -459,7 +503,7 @@ fn decode_eip1559_tx(raw: &[u8]) -> Result<RlpDecodedTx, String> {
         arr
     };
 
-    let from = ecrecover_address(&signing_hash_arr, recovery_id, &r, &s)?;
+    let from = ecrecover_address(&signing_hash_arr, recovery_id, &r, &s, false)?;
 
     let tx_hash_arr = {
         let h = Keccak256::digest(raw);
@@ -708,6 +752,54 @@ mod tests {
         assert!(rlp_decode_item(&data).is_err());
     }
 
+    // -----------------------------------------------------------------------
+    // ecrecover_address: signature scalar validation (EIP-2 low-s)
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_ecrecover_rejects_zero_r_and_s() {
+        let msg_hash = [0x11u8; 32];
+        let zero = [0u8; 32];
+        let one = {
+            let mut b = [0u8; 32];
+            b[31] = 1;
+            b
+        };
+        assert!(ecrecover_address(&msg_hash, 0, &zero, &one, false).is_err());
+        assert!(ecrecover_address(&msg_hash, 0, &one, &zero, false).is_err());
+    }
+
+    #[test]
+    fn test_ecrecover_rejects_scalars_at_or_above_n() {
+        let msg_hash = [0x22u8; 32];
+        let one = {
+            let mut b = [0u8; 32];
+            b[31] = 1;
+            b
+        };
+        assert!(ecrecover_address(&msg_hash, 0, &SECP256K1_N, &one, false).is_err());
+        assert!(ecrecover_address(&msg_hash, 0, &one, &SECP256K1_N, false).is_err());
+    }
+
+    #[test]
+    fn test_ecrecover_rejects_high_s_unless_allowed() {
+        let msg_hash = [0x33u8; 32];
+        let one = {
+            let mut b = [0u8; 32];
+            b[31] = 1;
+            b
+        };
+        // n/2 + 1 is high-s and malleable
+        let mut high_s = SECP256K1_N_HALF;
+        high_s[31] = high_s[31].wrapping_add(1);
+
+        assert!(ecrecover_address(&msg_hash, 0, &one, &high_s, false).is_err());
+        // With allow_high_s the scalar check passes (the bogus signature still fails at the
+        // crypto layer, but that's a different error than the one we're testing for here).
+        let err = ecrecover_address(&msg_hash, 0, &one, &high_s, true).unwrap_err();
+        assert!(!err.contains("EIP-2"), "allow_high_s should bypass the low-s check: {err}");
+    }
+
     #[test]
     fn test_rlp_address_parsing_edge_cases() {
         assert_eq!(rlp_item_to_address(&[]).unwrap(), None);
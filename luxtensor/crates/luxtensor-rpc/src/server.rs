@@ -259,8 +259,12 @@ impl RpcServer {
         self.metagraph.clone()
     }
 
-    /// Start the RPC server on the given address
-    pub fn start(self, addr: &str) -> Result<Server> {
+    /// Build the fully-registered JSON-RPC method table without binding any
+    /// socket — the same methods [`start`](Self::start) serves over HTTP,
+    /// but callable in-process via `jsonrpc_core`'s own
+    /// `handle_request_sync`/`handle_request` so tests and fuzz targets can
+    /// dispatch a request through the real handler chain.
+    pub fn build_io_handler(&self) -> IoHandler {
         let mut io = IoHandler::new();
 
         // Register blockchain query methods
@@ -538,6 +542,13 @@ impl RpcServer {
             }
         });
 
+        io
+    }
+
+    /// Start the RPC server on the given address
+    pub fn start(self, addr: &str) -> Result<Server> {
+        let io = self.build_io_handler();
+
         // Start HTTP server
         let server = ServerBuilder::new(io)
             .threads(4)
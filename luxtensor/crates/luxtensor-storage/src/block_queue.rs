@@ -0,0 +1,280 @@
+//! Asynchronous Block Import Queue
+//!
+//! `sync_nodes` (see `luxtensor-tests/data_sync_integration_test.rs`) validates
+//! and writes blocks synchronously, one at a time. That's fine for a single
+//! syncing peer, but once several peers are pushing blocks at once, header
+//! and transaction validation — all CPU work, no I/O — becomes the
+//! bottleneck while [`BlockchainDB`] sits idle waiting for the next block.
+//!
+//! [`BlockQueue`] pipelines that work across three stages:
+//!
+//! 1. **Unverified** — blocks that have been received but not yet checked.
+//! 2. **Verifying** — currently being validated by a worker thread.
+//! 3. **Verified** — passed validation and are waiting to be committed.
+//!
+//! A pool of worker threads (sized to available cores) drains the
+//! unverified stage and validates headers/transactions in parallel via a
+//! caller-supplied [`BlockValidator`]. Commits to [`BlockchainDB`] are still
+//! applied strictly in enqueue order, so out-of-order validation never
+//! produces out-of-order writes.
+
+use crate::db::BlockchainDB;
+use crate::error::StorageError;
+use crate::Result;
+use luxtensor_core::Block;
+use luxtensor_crypto::Hash;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use tracing::{debug, error, warn};
+
+/// Validates a block's header and transactions before it is committed.
+///
+/// Called concurrently from every worker thread in a [`BlockQueue`], so
+/// implementations must be safe to call from multiple threads at once.
+pub trait BlockValidator: Send + Sync {
+    /// Returns `Ok(())` if `block` is internally well-formed (header fields,
+    /// signatures, transaction set). Parent-linkage and fork-choice checks
+    /// happen later, inside [`BlockchainDB::store_block`].
+    fn validate(&self, block: &Block) -> std::result::Result<(), String>;
+}
+
+/// Point-in-time size of each [`BlockQueue`] pipeline stage, for backpressure
+/// decisions (e.g. a peer handler pausing further block requests).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockQueueInfo {
+    pub unverified_queue_size: usize,
+    pub verifying_queue_size: usize,
+    pub verified_queue_size: usize,
+}
+
+impl BlockQueueInfo {
+    /// Total number of blocks anywhere in the pipeline.
+    pub fn total_queue_size(&self) -> usize {
+        self.unverified_queue_size + self.verifying_queue_size + self.verified_queue_size
+    }
+
+    /// Blocks not yet committed — i.e. everything except the (already
+    /// validated, about-to-be-written) verified stage. Useful for deciding
+    /// whether to keep requesting more blocks from peers.
+    pub fn incomplete_queue_size(&self) -> usize {
+        self.unverified_queue_size + self.verifying_queue_size
+    }
+}
+
+/// A block paired with the order it was enqueued in, so validated blocks can
+/// be committed back in that same order regardless of which worker finished
+/// validating them first.
+struct Queued {
+    seq: u64,
+    block: Block,
+}
+
+struct State {
+    unverified: VecDeque<Queued>,
+    verifying: HashSet<Hash>,
+    verified: BTreeMap<u64, Block>,
+    /// Hashes anywhere in the pipeline (unverified, verifying, or verified
+    /// but not yet committed), used to reject duplicate enqueues.
+    pending_hashes: HashSet<Hash>,
+    next_seq: u64,
+    next_commit_seq: u64,
+    shutdown: bool,
+}
+
+impl State {
+    fn info(&self) -> BlockQueueInfo {
+        BlockQueueInfo {
+            unverified_queue_size: self.unverified.len(),
+            verifying_queue_size: self.verifying.len(),
+            verified_queue_size: self.verified.len(),
+        }
+    }
+
+    fn is_drained(&self) -> bool {
+        self.unverified.is_empty() && self.verifying.is_empty() && self.verified.is_empty()
+    }
+}
+
+/// A three-stage, multi-threaded block import pipeline sitting between a
+/// peer-facing block source (e.g. `luxtensor-network`'s `PeerManager`) and
+/// [`BlockchainDB`].
+///
+/// Dropping a `BlockQueue` signals its workers to stop after their current
+/// block and detaches their threads; call [`BlockQueue::shutdown`] to wait
+/// for them to finish instead.
+pub struct BlockQueue {
+    state: Arc<Mutex<State>>,
+    work_available: Arc<Condvar>,
+    drained: Arc<Condvar>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl BlockQueue {
+    /// Creates a queue with one worker per available core.
+    pub fn new(db: Arc<BlockchainDB>, validator: Arc<dyn BlockValidator>) -> Self {
+        let worker_count = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        Self::with_worker_count(db, validator, worker_count)
+    }
+
+    /// Creates a queue with an explicit worker count (primarily for tests).
+    pub fn with_worker_count(
+        db: Arc<BlockchainDB>,
+        validator: Arc<dyn BlockValidator>,
+        worker_count: usize,
+    ) -> Self {
+        let state = Arc::new(Mutex::new(State {
+            unverified: VecDeque::new(),
+            verifying: HashSet::new(),
+            verified: BTreeMap::new(),
+            pending_hashes: HashSet::new(),
+            next_seq: 0,
+            next_commit_seq: 0,
+            shutdown: false,
+        }));
+        let work_available = Arc::new(Condvar::new());
+        let drained = Arc::new(Condvar::new());
+
+        let worker_count = worker_count.max(1);
+        let mut workers = Vec::with_capacity(worker_count);
+        for id in 0..worker_count {
+            let state = state.clone();
+            let work_available = work_available.clone();
+            let drained = drained.clone();
+            let db = db.clone();
+            let validator = validator.clone();
+            let handle = std::thread::Builder::new()
+                .name(format!("block-verify-{id}"))
+                .spawn(move || worker_loop(state, work_available, drained, db, validator))
+                .expect("failed to spawn block verification worker");
+            workers.push(handle);
+        }
+
+        Self {
+            state,
+            work_available,
+            drained,
+            workers,
+        }
+    }
+
+    /// Enqueues `block` for validation and (eventually) commit.
+    ///
+    /// Returns `false` without enqueuing if `block`'s hash is already
+    /// somewhere in the pipeline (unverified, verifying, or verified and
+    /// awaiting commit) — this is the queue's deduplication against
+    /// multiple peers announcing the same block — or if the queue is
+    /// shutting down.
+    pub fn enqueue(&self, block: Block) -> bool {
+        let hash = block.hash();
+        let mut state = self.state.lock().unwrap();
+        if state.shutdown || !state.pending_hashes.insert(hash) {
+            return false;
+        }
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        state.unverified.push_back(Queued { seq, block });
+        drop(state);
+        self.work_available.notify_one();
+        true
+    }
+
+    /// Current size of each pipeline stage.
+    pub fn info(&self) -> BlockQueueInfo {
+        self.state.lock().unwrap().info()
+    }
+
+    /// Blocks the calling thread until every stage is empty, i.e. every
+    /// enqueued block has been validated and committed (or rejected).
+    pub fn wait_until_drained(&self) {
+        let state = self.state.lock().unwrap();
+        let _state = self
+            .drained
+            .wait_while(state, |s| !s.is_drained())
+            .unwrap();
+    }
+
+    /// Signals workers to stop once the unverified stage is empty and waits
+    /// for them to exit.
+    pub fn shutdown(mut self) {
+        self.state.lock().unwrap().shutdown = true;
+        self.work_available.notify_all();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn worker_loop(
+    state: Arc<Mutex<State>>,
+    work_available: Arc<Condvar>,
+    drained: Arc<Condvar>,
+    db: Arc<BlockchainDB>,
+    validator: Arc<dyn BlockValidator>,
+) {
+    loop {
+        let queued = {
+            let mut guard = state.lock().unwrap();
+            let item = loop {
+                if let Some(item) = guard.unverified.pop_front() {
+                    break Some(item);
+                }
+                if guard.shutdown {
+                    break None;
+                }
+                guard = work_available.wait(guard).unwrap();
+            };
+            let Some(item) = item else { return };
+            guard.verifying.insert(item.block.hash());
+            item
+        };
+
+        let hash = queued.block.hash();
+        let outcome = validator.validate(&queued.block);
+
+        let mut guard = state.lock().unwrap();
+        guard.verifying.remove(&hash);
+        match outcome {
+            Ok(()) => {
+                guard.verified.insert(queued.seq, queued.block);
+            }
+            Err(reason) => {
+                warn!("block {:?} failed validation: {}", hash, reason);
+                guard.pending_hashes.remove(&hash);
+            }
+        }
+        commit_ready_blocks(&mut guard, &db);
+        if guard.is_drained() {
+            drained.notify_all();
+        }
+        drop(guard);
+    }
+}
+
+/// Commits every contiguous run of verified blocks starting at
+/// `next_commit_seq`, in enqueue order. A validation failure leaves a gap in
+/// the sequence that later blocks don't wait on — `store_block`'s own
+/// fork-choice logic (see [`crate::db::BlockInsertedChain`]) already treats
+/// a block whose parent hasn't arrived as a side chain/orphan, so skipping
+/// ahead here is safe.
+fn commit_ready_blocks(state: &mut State, db: &BlockchainDB) {
+    while let Some(block) = state.verified.remove(&state.next_commit_seq) {
+        state.next_commit_seq += 1;
+        let hash = block.hash();
+        match db.store_block(&block) {
+            Ok(outcome) => debug!("committed block {:?}: {:?}", hash, outcome),
+            Err(e) => error!("failed to commit block {:?}: {}", hash, e),
+        }
+        state.pending_hashes.remove(&hash);
+    }
+}
+
+/// Converts a [`BlockValidator`] failure into the crate's error type, for
+/// callers that want a single validation pass outside the queue (e.g. tests).
+pub fn validate_block(validator: &dyn BlockValidator, block: &Block) -> Result<()> {
+    validator
+        .validate(block)
+        .map_err(StorageError::ValidationFailed)
+}
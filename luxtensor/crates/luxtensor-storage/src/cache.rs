@@ -356,14 +356,38 @@ impl CachedBlockchainDB {
     }
 
     /// Store a block (write-through cache)
-    pub fn store_block(&self, block: &Block) -> crate::Result<()> {
+    pub fn store_block(&self, block: &Block) -> crate::Result<crate::db::BlockInsertedChain> {
         // Write to RocksDB first
-        self.inner.store_block(block)?;
+        let outcome = self.inner.store_block(block)?;
 
         // Update cache
         self.cache.put_block(block);
 
-        Ok(())
+        Ok(outcome)
+    }
+
+    /// Store an already-hashed block (write-through cache)
+    pub fn store_indexed_block(
+        &self,
+        block: &luxtensor_core::IndexedBlock,
+    ) -> crate::Result<crate::db::BlockInsertedChain> {
+        // Write to RocksDB first
+        let outcome = self.inner.store_indexed_block(block)?;
+
+        // Update cache
+        self.cache.put_block(&Block::new(block.header.clone(), block.transactions.clone()));
+
+        Ok(outcome)
+    }
+
+    /// Get a block by height as an already-hashed `IndexedBlock` (cache-first)
+    pub fn get_indexed_block_by_height(
+        &self,
+        height: u64,
+    ) -> crate::Result<Option<luxtensor_core::IndexedBlock>> {
+        Ok(self
+            .get_block_by_height(height)?
+            .map(luxtensor_core::IndexedBlock::from))
     }
 
     /// Get a header by hash (cache-first)
@@ -1,8 +1,13 @@
 // Checkpoint snapshot system for rapid node synchronization
 // Enables new nodes to download state snapshots instead of replaying all blocks
 
+use crate::db::BlockchainDB;
+use crate::state_db::{CONTRACT_CODE_PREFIX, HNSW_INDEX_PREFIX};
+use crate::trie::MerkleTrie;
 use luxtensor_core::types::Hash;
-use rocksdb::DB;
+use luxtensor_core::{Address, BlockHeader};
+use luxtensor_crypto::keccak256;
+use rocksdb::{ColumnFamilyDescriptor, IteratorMode, Options, DB};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::{self, File};
@@ -17,6 +22,12 @@ pub const CHECKPOINT_INTERVAL: u64 = 10_000;
 /// Maximum number of checkpoints to keep
 pub const MAX_CHECKPOINTS: usize = 5;
 
+/// Number of trie entries bundled into a single [`SnapshotChunk::TrieRange`].
+///
+/// Keeps individual chunks small enough to retransmit over the network and
+/// to make progress tracking during a warp sync meaningfully granular.
+pub const SNAPSHOT_CHUNK_SIZE: usize = 2_000;
+
 /// Checkpoint configuration
 #[derive(Debug, Clone)]
 pub struct CheckpointConfig {
@@ -62,6 +73,47 @@ pub struct CheckpointMetadata {
     pub checksum: String,
 }
 
+/// A piece of a warp-sync snapshot, transferred between peers so a joining
+/// node can bootstrap from a checkpoint instead of replaying every block.
+///
+/// A full snapshot is a sequence of `TrieRange` chunks covering the entire
+/// key space of the checkpoint's state trie, in ascending trie-key order,
+/// followed by a single `HeaderChain` chunk so the importer can resume
+/// ordinary block sync from the checkpoint height.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SnapshotChunk {
+    /// A contiguous, trie-key-ordered range of accounts.
+    TrieRange {
+        /// Position of this chunk among all `TrieRange` chunks (0-based).
+        index: u32,
+        /// Trie key (`keccak256(address)`) of the first entry in this chunk.
+        start_key: Hash,
+        /// Trie key of the last entry in this chunk.
+        end_key: Hash,
+        /// `(address, bincode-serialized Account)` pairs, trie-key ordered.
+        entries: Vec<(Address, Vec<u8>)>,
+    },
+    /// The block header chain from just above the checkpoint height up to
+    /// the chain tip at export time, so the importer can resume normal
+    /// block sync without re-downloading blocks it already has state for.
+    HeaderChain {
+        headers: Vec<BlockHeader>,
+    },
+}
+
+/// Tracks which trie key ranges of an in-progress snapshot import have
+/// already been applied to the target database, so an interrupted warp
+/// sync can resume without redoing completed work.
+///
+/// Persisted alongside checkpoint metadata as
+/// `snapshot_progress_<height>.json`; removed once the import completes
+/// and the reconstructed root has been verified.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SnapshotProgress {
+    /// `(start_key, end_key)` of every `TrieRange` chunk already written.
+    applied_ranges: Vec<(Hash, Hash)>,
+}
+
 /// Checkpoint manager for creating and restoring state snapshots
 pub struct CheckpointManager {
     /// Directory to store checkpoints
@@ -339,6 +391,208 @@ impl CheckpointManager {
         Ok(())
     }
 
+    // ── Snapshot / warp sync ─────────────────────────────────────────────
+
+    /// Export the checkpoint at `height` as a sequence of [`SnapshotChunk`]s
+    /// a joining node can apply instead of replaying every block.
+    ///
+    /// Reads the account set straight out of the checkpoint's own RocksDB
+    /// snapshot directory (rather than the live database), so the exported
+    /// state matches exactly what was committed at `height`, even if the
+    /// node has since advanced. The header chain from `height + 1` up to
+    /// the live tip is shipped in a trailing chunk so the importer can
+    /// resume normal block sync once the snapshot is applied.
+    pub fn export_snapshot(
+        &self,
+        height: u64,
+    ) -> Result<std::vec::IntoIter<SnapshotChunk>, CheckpointError> {
+        let meta = self.checkpoints.get(&height).ok_or(CheckpointError::NotFound(height))?;
+
+        let snapshot_path = self.checkpoint_dir.join(format!("checkpoint_{}", height));
+        let current_checksum = Self::calculate_dir_checksum(&snapshot_path)
+            .map_err(|e| CheckpointError::ExportFailed(e.to_string()))?;
+        if current_checksum != meta.checksum {
+            return Err(CheckpointError::ChecksumMismatch);
+        }
+
+        let mut entries = Self::read_checkpoint_accounts(&snapshot_path)?;
+        entries.sort_by_key(|(trie_key, _, _)| *trie_key);
+
+        let mut chunks: Vec<SnapshotChunk> = entries
+            .chunks(SNAPSHOT_CHUNK_SIZE)
+            .enumerate()
+            .map(|(index, batch)| SnapshotChunk::TrieRange {
+                index: index as u32,
+                start_key: batch.first().unwrap().0,
+                end_key: batch.last().unwrap().0,
+                entries: batch.iter().map(|(_, addr, bytes)| (*addr, bytes.clone())).collect(),
+            })
+            .collect();
+
+        let bdb = BlockchainDB::from_arc(self.db.clone());
+        let tip_height = bdb
+            .get_best_height()
+            .map_err(|e| CheckpointError::ExportFailed(e.to_string()))?
+            .unwrap_or(0);
+
+        let mut headers = Vec::new();
+        for h in (height + 1)..=tip_height {
+            if let Some(block) = bdb
+                .get_block_by_height(h)
+                .map_err(|e| CheckpointError::ExportFailed(e.to_string()))?
+            {
+                headers.push(block.header);
+            }
+        }
+        chunks.push(SnapshotChunk::HeaderChain { headers });
+
+        info!(
+            "Exporting snapshot at height {}: {} trie chunk(s), {} header(s)",
+            height,
+            chunks.len() - 1,
+            tip_height.saturating_sub(height)
+        );
+
+        Ok(chunks.into_iter())
+    }
+
+    /// Rebuild `target_db`'s account state from `chunks` and verify it
+    /// reconstructs the checkpoint's committed `state_root` at `height`.
+    ///
+    /// Already-applied trie ranges (per the on-disk [`SnapshotProgress`])
+    /// are skipped on the database-write side but still folded into the
+    /// root computation, so a resumed import produces the same verification
+    /// result as one that ran start to finish in a single pass. Returns the
+    /// header chain above the checkpoint so the caller can resume ordinary
+    /// block sync for the tip blocks once the snapshot is applied.
+    pub fn import_snapshot(
+        &mut self,
+        height: u64,
+        target_db: Arc<DB>,
+        chunks: impl Iterator<Item = SnapshotChunk>,
+    ) -> Result<Vec<BlockHeader>, CheckpointError> {
+        let meta = self.checkpoints.get(&height).ok_or(CheckpointError::NotFound(height))?.clone();
+
+        let mut progress = self.load_snapshot_progress(height);
+        let mut trie = MerkleTrie::new();
+        let mut headers = Vec::new();
+
+        for chunk in chunks {
+            match chunk {
+                SnapshotChunk::TrieRange { start_key, end_key, entries, .. } => {
+                    let already_applied = progress.applied_ranges.contains(&(start_key, end_key));
+
+                    if !already_applied {
+                        let mut batch = rocksdb::WriteBatch::default();
+                        for (address, account_bytes) in &entries {
+                            batch.put(address.as_bytes(), account_bytes);
+                        }
+                        target_db
+                            .write(batch)
+                            .map_err(|e| CheckpointError::ImportFailed(e.to_string()))?;
+                    }
+
+                    for (address, account_bytes) in &entries {
+                        let trie_key = keccak256(address.as_bytes());
+                        trie.insert(&trie_key, account_bytes)
+                            .map_err(|e| CheckpointError::ImportFailed(e.to_string()))?;
+                    }
+
+                    if !already_applied {
+                        progress.applied_ranges.push((start_key, end_key));
+                        self.save_snapshot_progress(height, &progress)?;
+                    }
+                }
+                SnapshotChunk::HeaderChain { headers: chain } => headers = chain,
+            }
+        }
+
+        let computed_root = trie.root_hash();
+        if computed_root != meta.state_root {
+            return Err(CheckpointError::SnapshotRootMismatch {
+                expected: meta.state_root,
+                computed: computed_root,
+            });
+        }
+
+        self.clear_snapshot_progress(height);
+        info!(
+            "Snapshot at height {} restored and verified ({} header(s) to resume from)",
+            height,
+            headers.len()
+        );
+
+        Ok(headers)
+    }
+
+    /// Read every account entry out of a checkpoint's RocksDB snapshot
+    /// directory, paired with its trie key (`keccak256(address)`).
+    ///
+    /// Opens the snapshot directory read-only so the live database (which
+    /// may share column families with it via a hard-linked checkpoint) is
+    /// never mutated by an export.
+    fn read_checkpoint_accounts(
+        snapshot_path: &Path,
+    ) -> Result<Vec<(Hash, Address, Vec<u8>)>, CheckpointError> {
+        let opts = Options::default();
+        let cf_names = DB::list_cf(&opts, snapshot_path)
+            .map_err(|e| CheckpointError::ExportFailed(e.to_string()))?;
+        let cf_descriptors: Vec<ColumnFamilyDescriptor> = cf_names
+            .iter()
+            .map(|name| ColumnFamilyDescriptor::new(name, Options::default()))
+            .collect();
+
+        let snap_db = DB::open_cf_descriptors_read_only(&opts, snapshot_path, cf_descriptors, false)
+            .map_err(|e| CheckpointError::ExportFailed(e.to_string()))?;
+
+        let mut entries = Vec::new();
+        for item in snap_db.iterator(IteratorMode::Start) {
+            let (key, value) = item.map_err(|e| CheckpointError::ExportFailed(e.to_string()))?;
+
+            if key.len() != 20
+                || key.starts_with(CONTRACT_CODE_PREFIX)
+                || key.starts_with(HNSW_INDEX_PREFIX)
+            {
+                continue;
+            }
+
+            let address = Address::try_from_slice(&key).ok_or_else(|| {
+                CheckpointError::ExportFailed("malformed address key in snapshot".to_string())
+            })?;
+            let trie_key = keccak256(&key);
+            entries.push((trie_key, address, value.to_vec()));
+        }
+
+        Ok(entries)
+    }
+
+    fn snapshot_progress_path(&self, height: u64) -> PathBuf {
+        self.checkpoint_dir.join(format!("snapshot_progress_{}.json", height))
+    }
+
+    fn load_snapshot_progress(&self, height: u64) -> SnapshotProgress {
+        let path = self.snapshot_progress_path(height);
+        File::open(&path)
+            .ok()
+            .and_then(|f| serde_json::from_reader(BufReader::new(f)).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_snapshot_progress(
+        &self,
+        height: u64,
+        progress: &SnapshotProgress,
+    ) -> Result<(), CheckpointError> {
+        let path = self.snapshot_progress_path(height);
+        let file = File::create(&path).map_err(|e| CheckpointError::ImportFailed(e.to_string()))?;
+        serde_json::to_writer(BufWriter::new(file), progress)
+            .map_err(|e| CheckpointError::ImportFailed(e.to_string()))
+    }
+
+    fn clear_snapshot_progress(&self, height: u64) {
+        let _ = fs::remove_file(self.snapshot_progress_path(height));
+    }
+
     fn prune_old_checkpoints(&mut self) {
         if self.checkpoints.len() <= MAX_CHECKPOINTS {
             return;
@@ -434,6 +688,7 @@ pub enum CheckpointError {
     ExportFailed(String),
     ImportFailed(String),
     ChecksumMismatch,
+    SnapshotRootMismatch { expected: Hash, computed: Hash },
 }
 
 impl std::fmt::Display for CheckpointError {
@@ -445,6 +700,12 @@ impl std::fmt::Display for CheckpointError {
             Self::ExportFailed(e) => write!(f, "Failed to export checkpoint: {}", e),
             Self::ImportFailed(e) => write!(f, "Failed to import checkpoint: {}", e),
             Self::ChecksumMismatch => write!(f, "Checkpoint checksum mismatch"),
+            Self::SnapshotRootMismatch { expected, computed } => write!(
+                f,
+                "Snapshot reconstructed root 0x{} does not match checkpoint root 0x{}",
+                hex::encode(computed),
+                hex::encode(expected)
+            ),
         }
     }
 }
@@ -1,5 +1,5 @@
-use crate::{Result, StorageError};
-use luxtensor_core::{Block, BlockHeader, Transaction};
+use crate::{ForkChoiceError, Result, StorageError};
+use luxtensor_core::{Block, BlockHeader, IndexedBlock, Transaction};
 use luxtensor_crypto::Hash;
 use rocksdb::{ColumnFamilyDescriptor, Options, WriteBatch, DB};
 use std::path::Path;
@@ -32,6 +32,64 @@ const SCHEMA_VERSION_KEY: &[u8] = b"schema_version";
 /// Current schema version — increment when making incompatible DB changes
 const CURRENT_SCHEMA_VERSION: u32 = 1;
 
+/// Metadata key for the current best (main-chain tip) block hash, kept
+/// alongside `best_height` so fork choice can detect which chain a new
+/// block's `previous_hash` extends without a header lookup.
+const BEST_HASH_KEY: &[u8] = b"best_hash";
+
+/// Blocks have no explicit work/difficulty field, so cumulative work is
+/// approximated as chain length (one unit per block, height since genesis).
+/// A side chain only overtakes main once its tip height exceeds main's.
+///
+/// Caps how far back a reorg is allowed to walk looking for the common
+/// ancestor. A side chain that hasn't met the main chain within this many
+/// blocks is treated as unrelated/corrupt rather than silently rewound.
+const MAX_REORG_DEPTH: u64 = 10_000;
+
+/// Outcome of inserting a block through [`BlockchainDB::store_block`]'s fork
+/// choice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockInsertedChain {
+    /// Extended the current best chain; it remains the tip.
+    Main,
+    /// Builds on a known block that isn't the current tip. Stored, but not
+    /// (yet) part of the main chain — may become `Reorganized` later once
+    /// this branch grows past the main chain's height.
+    Side,
+    /// This branch's height has overtaken the main chain. Main-chain blocks
+    /// back to the common ancestor were rewound and this branch's blocks
+    /// applied in their place.
+    Reorganized {
+        old_tip: Hash,
+        new_tip: Hash,
+        /// Disconnected main-chain blocks, ordered from the old tip back to
+        /// (but not including) the common ancestor.
+        rolled_back: Vec<Hash>,
+        /// Newly-canonical blocks, ordered from the common ancestor's child
+        /// up to the new tip.
+        applied: Vec<Hash>,
+    },
+    /// `previous_hash` isn't a known block yet. The block was buffered in
+    /// the orphan pool (keyed by `previous_hash`) rather than persisted, and
+    /// will be re-inserted automatically once its parent arrives — see
+    /// [`BlockchainDB::try_connect_orphans`].
+    Orphan,
+}
+
+/// Summary of a [`BlockchainDB::sync_from_horizon`] run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HorizonSyncReport {
+    /// Height below which only headers (no bodies) were synced.
+    pub horizon: u64,
+    /// Number of headers transferred and chain-validated (genesis..=tip).
+    pub headers_synced: u64,
+    /// Number of default-column-family entries copied as part of the
+    /// state snapshot transfer (accounts, contract code, HNSW indexes, …).
+    pub accounts_synced: u64,
+    /// Number of full block bodies downloaded above the horizon.
+    pub bodies_downloaded: u64,
+}
+
 /// Blockchain database using RocksDB
 pub struct BlockchainDB {
     db: Arc<DB>,
@@ -114,6 +172,16 @@ impl BlockchainDB {
         self.db.clone()
     }
 
+    /// Wrap an already-open RocksDB handle without running column-family
+    /// setup or the schema-version check again.
+    ///
+    /// Used by [`crate::checkpoint::CheckpointManager`], which shares the
+    /// live node's `Arc<DB>` and needs header-chain access for snapshot
+    /// export without re-opening the database.
+    pub(crate) fn from_arc(db: Arc<DB>) -> Self {
+        Self { db }
+    }
+
     /// Returns the schema version stored in this database.
     pub fn schema_version(&self) -> Result<u32> {
         let cf_meta = self
@@ -138,10 +206,47 @@ impl BlockchainDB {
         }
     }
 
-    /// Store a block and index its transactions
-    pub fn store_block(&self, block: &Block) -> Result<()> {
+    /// Store a block, index its transactions, and run fork choice.
+    ///
+    /// Every block whose parent is already known is persisted regardless of
+    /// outcome — a `Side` block needs to be on disk already so that a later
+    /// block extending it can trigger a reorg. Only the height→hash index
+    /// and the best-tip pointer are conditional on the fork-choice result.
+    /// A block whose parent *isn't* known yet is buffered in the orphan pool
+    /// instead (see [`BlockInsertedChain::Orphan`]) and isn't persisted
+    /// until it connects.
+    pub fn store_block(&self, block: &Block) -> Result<BlockInsertedChain> {
         let block_hash = block.hash();
+        let tx_hashes: Vec<Hash> = block.transactions.iter().map(|tx| tx.hash()).collect();
+        self.store_block_indexed(block, block_hash, &tx_hashes)
+    }
+
+    /// Like [`Self::store_block`], but takes a block whose header hash and
+    /// per-transaction hashes were already computed (e.g. by the sync or
+    /// consensus layer), avoiding rehashing it here.
+    pub fn store_indexed_block(&self, block: &IndexedBlock) -> Result<BlockInsertedChain> {
+        let plain = Block::new(block.header.clone(), block.transactions.clone());
+        self.store_block_indexed(&plain, block.header_hash, &block.tx_hashes)
+    }
+
+    fn store_block_indexed(
+        &self,
+        block: &Block,
+        block_hash: Hash,
+        tx_hashes: &[Hash],
+    ) -> Result<BlockInsertedChain> {
         let height = block.header.height;
+        let previous_hash = block.header.previous_hash;
+
+        // A block whose parent we haven't seen yet (Node C joining
+        // mid-stream, or simple out-of-order delivery) goes into the orphan
+        // pool instead of being persisted — `try_connect_orphans` retries it
+        // once `previous_hash` actually lands.
+        let is_genesis = self.get_best_tip()?.is_none();
+        if !is_genesis && self.get_header(&previous_hash)?.is_none() {
+            self.buffer_orphan(&previous_hash, block)?;
+            return Ok(BlockInsertedChain::Orphan);
+        }
 
         let mut batch = WriteBatch::default();
 
@@ -161,11 +266,9 @@ impl BlockchainDB {
             .ok_or_else(|| StorageError::DatabaseError("CF_HEADERS not found".to_string()))?;
         batch.put_cf(cf_headers, block_hash, header_bytes);
 
-        // Index height -> hash
         let cf_height = self.db.cf_handle(CF_HEIGHT_TO_HASH).ok_or_else(|| {
             StorageError::DatabaseError("CF_HEIGHT_TO_HASH not found".to_string())
         })?;
-        batch.put_cf(cf_height, height.to_be_bytes(), block_hash);
 
         // Index transactions
         let cf_txs = self.db.cf_handle(CF_TRANSACTIONS).ok_or_else(|| {
@@ -175,55 +278,321 @@ impl BlockchainDB {
             StorageError::DatabaseError("CF_TX_TO_BLOCK not found".to_string())
         })?;
 
-        for tx in &block.transactions {
-            let tx_hash = tx.hash();
+        for (tx, &tx_hash) in block.transactions.iter().zip(tx_hashes) {
             let tx_bytes = bincode::serialize(tx)?;
             batch.put_cf(cf_txs, tx_hash, tx_bytes);
             batch.put_cf(cf_tx_to_block, tx_hash, block_hash);
         }
 
-        // Store best_height metadata for O(1) lookup — only update if new height is greater
-        // AND the block properly connects to the existing chain
-        let cf_meta = self.db.cf_handle(CF_METADATA).ok_or_else(|| {
-            StorageError::DatabaseError("CF_METADATA not found".to_string())
-        })?;
-        let should_update = match self.db.get_cf(cf_meta, b"best_height")? {
-            Some(bytes) if bytes.len() >= 8 => {
-                let current_best = u64::from_be_bytes(
-                    bytes[..8]
-                        .try_into()
-                        .map_err(|_| StorageError::DatabaseError("Invalid best_height".to_string()))?,
-                );
-                if height > current_best {
-                    // Verify chain connectivity: if a block at height-1 exists,
-                    // the new block's previous_hash must match it
-                    if height > 0 {
-                        match self.db.get_cf(cf_height, (height - 1).to_be_bytes())? {
-                            Some(prev_hash_bytes) => {
-                                // Previous block exists — verify the link
-                                block.header.previous_hash == prev_hash_bytes.as_ref()
-                            }
-                            None => {
-                                // No block at height-1 (out-of-order import), allow update
-                                true
-                            }
-                        }
-                    } else {
-                        true // Genesis block
-                    }
+        let cf_meta = self
+            .db
+            .cf_handle(CF_METADATA)
+            .ok_or_else(|| StorageError::DatabaseError("CF_METADATA not found".to_string()))?;
+
+        // Accumulated work up to and including this block. Derived from the
+        // parent's own stored work (rather than taken directly off `height`)
+        // so it reflects actual chain depth even if `height` fields were
+        // ever inconsistent, and so a future difficulty field only has to
+        // change how `work` is computed, not how it's compared.
+        let parent_work = self.cumulative_work(&previous_hash)?.unwrap_or(height);
+        let work = parent_work + 1;
+
+        let outcome = match self.get_best_tip()? {
+            None => {
+                // First block ever stored becomes the chain's genesis/tip.
+                batch.put_cf(cf_height, height.to_be_bytes(), block_hash);
+                batch.put_cf(cf_meta, b"best_height", height.to_be_bytes());
+                batch.put_cf(cf_meta, BEST_HASH_KEY, block_hash);
+                BlockInsertedChain::Main
+            }
+            Some((_, best_hash)) if previous_hash == best_hash => {
+                // Extends the main chain directly.
+                batch.put_cf(cf_height, height.to_be_bytes(), block_hash);
+                batch.put_cf(cf_meta, b"best_height", height.to_be_bytes());
+                batch.put_cf(cf_meta, BEST_HASH_KEY, block_hash);
+                BlockInsertedChain::Main
+            }
+            Some((best_height, best_hash)) => {
+                // `previous_hash` is already known to have a header (checked
+                // above, before any writes), so this is a side chain or a
+                // heavier branch overtaking main — never an orphan.
+                let best_work = self.cumulative_work(&best_hash)?.unwrap_or(best_height + 1);
+
+                if work <= best_work {
+                    // Doesn't reach past the main tip yet — stays a side chain.
+                    BlockInsertedChain::Side
                 } else {
-                    false
+                    self.reorganize(&mut batch, best_hash, best_height, block_hash, height)?
                 }
             }
-            _ => true, // No existing best_height, always set
         };
-        if should_update {
-            batch.put_cf(cf_meta, b"best_height", height.to_be_bytes());
-        }
+
+        // Committed in the very same write as the block itself, so a reorg
+        // can never roll back a block while leaving its work entry behind —
+        // which would otherwise surface as a "chain header not found"
+        // failure the next time fork choice needs to compare this branch.
+        self.put_cumulative_work(&mut batch, &block_hash, work)?;
 
         // Write batch atomically
         self.db.write(batch)?;
 
+        // Now that this block is durable, see if it unblocks any orphans
+        // buffered against it.
+        self.try_connect_orphans(&block_hash)?;
+
+        Ok(outcome)
+    }
+
+    /// Current main-chain tip as `(height, hash)`, if any block has been
+    /// stored yet.
+    fn get_best_tip(&self) -> Result<Option<(u64, Hash)>> {
+        let cf_meta = self
+            .db
+            .cf_handle(CF_METADATA)
+            .ok_or_else(|| StorageError::DatabaseError("CF_METADATA not found".to_string()))?;
+
+        let height = match self.db.get_cf(cf_meta, b"best_height")? {
+            Some(bytes) if bytes.len() >= 8 => u64::from_be_bytes(
+                bytes[..8]
+                    .try_into()
+                    .map_err(|_| StorageError::DatabaseError("Invalid best_height".to_string()))?,
+            ),
+            _ => return Ok(None),
+        };
+
+        let hash = match self.db.get_cf(cf_meta, BEST_HASH_KEY)? {
+            Some(bytes) if bytes.len() == 32 => {
+                let mut h = [0u8; 32];
+                h.copy_from_slice(&bytes);
+                h
+            }
+            // Back-compat: a DB written before `best_hash` existed has a
+            // `best_height` but no tip hash — recover it from the index.
+            _ => match self.get_block_by_height(height)? {
+                Some(block) => block.hash(),
+                None => return Ok(None),
+            },
+        };
+
+        Ok(Some((height, hash)))
+    }
+
+    /// Rewind the main chain from `old_tip`/`old_height` back to the common
+    /// ancestor with the branch headed by `new_tip`/`new_height`, then
+    /// re-point the height→hash index at the heavier branch.
+    ///
+    /// `new_height` must already be greater than `old_height` — the caller
+    /// is responsible for that comparison, since it's also how `Side` vs.
+    /// reorg is decided.
+    fn reorganize(
+        &self,
+        batch: &mut WriteBatch,
+        old_tip: Hash,
+        old_height: u64,
+        new_tip: Hash,
+        new_height: u64,
+    ) -> Result<BlockInsertedChain> {
+        let cf_height = self.db.cf_handle(CF_HEIGHT_TO_HASH).ok_or_else(|| {
+            StorageError::DatabaseError("CF_HEIGHT_TO_HASH not found".to_string())
+        })?;
+        let cf_meta = self
+            .db
+            .cf_handle(CF_METADATA)
+            .ok_or_else(|| StorageError::DatabaseError("CF_METADATA not found".to_string()))?;
+
+        let mut depth = 0u64;
+        let mut check_depth = || -> Result<()> {
+            depth += 1;
+            if depth > MAX_REORG_DEPTH {
+                return Err(ForkChoiceError::ForkTooLong.into());
+            }
+            Ok(())
+        };
+
+        // Walk the new branch back to `old_height`, collecting its blocks
+        // tip-first (reversed into ancestor-first order below).
+        let mut applied_rev = Vec::new();
+        let mut side_hash = new_tip;
+        let mut side_height = new_height;
+        while side_height > old_height {
+            applied_rev.push(side_hash);
+            let header = self
+                .get_header(&side_hash)?
+                .ok_or(ForkChoiceError::Unknown(side_hash))?;
+            side_hash = header.previous_hash;
+            side_height -= 1;
+            check_depth()?;
+        }
+
+        // Walk both chains back in lockstep until they meet at the common
+        // ancestor.
+        let mut main_hash = old_tip;
+        let mut rolled_back = Vec::new();
+        while main_hash != side_hash {
+            rolled_back.push(main_hash);
+            let main_header = self
+                .get_header(&main_hash)?
+                .ok_or(ForkChoiceError::Unknown(main_hash))?;
+            main_hash = main_header.previous_hash;
+
+            applied_rev.push(side_hash);
+            let side_header = self
+                .get_header(&side_hash)?
+                .ok_or(ForkChoiceError::Unknown(side_hash))?;
+            side_hash = side_header.previous_hash;
+
+            check_depth()?;
+        }
+        let common_ancestor_height = old_height - rolled_back.len() as u64;
+
+        applied_rev.reverse();
+        let applied = applied_rev;
+
+        // Reject the reorg if a transaction in the incoming branch is
+        // already confirmed in a main-chain block that isn't being rolled
+        // back — that would double-apply it.
+        for hash in &applied {
+            let block = self.get_block(hash)?.ok_or(ForkChoiceError::Unknown(*hash))?;
+            for tx in &block.transactions {
+                let tx_hash = tx.hash();
+                if let Some(existing) = self.get_block_hash_by_tx(&tx_hash)? {
+                    if existing != *hash && !rolled_back.contains(&existing) {
+                        return Err(ForkChoiceError::DoubleSpend.into());
+                    }
+                }
+            }
+        }
+
+        let mut h = common_ancestor_height + 1;
+        for hash in &applied {
+            batch.put_cf(cf_height, h.to_be_bytes(), hash);
+            h += 1;
+        }
+        batch.put_cf(cf_meta, b"best_height", new_height.to_be_bytes());
+        batch.put_cf(cf_meta, BEST_HASH_KEY, new_tip);
+
+        Ok(BlockInsertedChain::Reorganized { old_tip, new_tip, rolled_back, applied })
+    }
+
+    /// Accumulated chain work up to and including `hash` — see the `work`
+    /// computation in [`Self::store_block_indexed`]. `None` if `hash` hasn't
+    /// been stored (yet), including orphans still waiting in the pool.
+    fn cumulative_work(&self, hash: &Hash) -> Result<Option<u64>> {
+        let cf = self
+            .db
+            .cf_handle(CF_FORK_CHOICE)
+            .ok_or_else(|| StorageError::DatabaseError("CF_FORK_CHOICE not found".to_string()))?;
+        match self.db.get_cf(cf, Self::work_key(hash))? {
+            Some(bytes) if bytes.len() == 8 => {
+                Ok(Some(u64::from_be_bytes(bytes[..8].try_into().unwrap())))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn put_cumulative_work(&self, batch: &mut WriteBatch, hash: &Hash, work: u64) -> Result<()> {
+        let cf = self
+            .db
+            .cf_handle(CF_FORK_CHOICE)
+            .ok_or_else(|| StorageError::DatabaseError("CF_FORK_CHOICE not found".to_string()))?;
+        batch.put_cf(cf, Self::work_key(hash), work.to_be_bytes());
+        Ok(())
+    }
+
+    fn work_key(hash: &Hash) -> Vec<u8> {
+        let mut key = Vec::with_capacity(Self::WORK_PREFIX.len() + 32);
+        key.extend_from_slice(Self::WORK_PREFIX);
+        key.extend_from_slice(hash);
+        key
+    }
+
+    const WORK_PREFIX: &'static [u8] = b"cwork:";
+    const ORPHAN_PREFIX: &'static [u8] = b"orphan:";
+
+    /// Buffers `block` in the orphan pool, keyed by its (unknown) parent
+    /// hash, so [`Self::try_connect_orphans`] can find it once that parent
+    /// is stored.
+    fn buffer_orphan(&self, parent: &Hash, block: &Block) -> Result<()> {
+        let cf = self
+            .db
+            .cf_handle(CF_FORK_CHOICE)
+            .ok_or_else(|| StorageError::DatabaseError("CF_FORK_CHOICE not found".to_string()))?;
+        let mut key = Vec::with_capacity(Self::ORPHAN_PREFIX.len() + 64);
+        key.extend_from_slice(Self::ORPHAN_PREFIX);
+        key.extend_from_slice(parent);
+        key.extend_from_slice(&block.hash());
+        self.db.put_cf(cf, key, bincode::serialize(block)?)?;
+        Ok(())
+    }
+
+    /// Drains every orphan buffered against `parent` and re-attempts
+    /// [`Self::store_block`] for each. A block that connects may itself
+    /// unblock further descendants — `store_block_indexed` calls this same
+    /// method again for every block it successfully stores, so a whole
+    /// orphan chain reconnects as soon as its root parent lands.
+    fn try_connect_orphans(&self, parent: &Hash) -> Result<()> {
+        let cf = self
+            .db
+            .cf_handle(CF_FORK_CHOICE)
+            .ok_or_else(|| StorageError::DatabaseError("CF_FORK_CHOICE not found".to_string()))?;
+        let mut prefix = Vec::with_capacity(Self::ORPHAN_PREFIX.len() + 32);
+        prefix.extend_from_slice(Self::ORPHAN_PREFIX);
+        prefix.extend_from_slice(parent);
+
+        let mut orphans = Vec::new();
+        let mut batch = WriteBatch::default();
+        let iter = self.db.iterator_cf(
+            cf,
+            rocksdb::IteratorMode::From(&prefix, rocksdb::Direction::Forward),
+        );
+        for item in iter {
+            let (key, value) = item?;
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            orphans.push(bincode::deserialize::<Block>(&value)?);
+            batch.delete_cf(cf, key);
+        }
+        if orphans.is_empty() {
+            return Ok(());
+        }
+        self.db.write(batch)?;
+
+        for block in orphans {
+            let hash = block.hash();
+            match self.store_block(&block) {
+                Ok(outcome) => {
+                    tracing::debug!("connected orphan block {:?}: {:?}", hash, outcome)
+                }
+                Err(e) => tracing::warn!("orphan block {:?} failed to connect: {}", hash, e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Look up the canonical (main-chain) block hash at `height`.
+    pub fn main_chain_hash_at(&self, height: u64) -> Result<Hash> {
+        let cf_height = self.db.cf_handle(CF_HEIGHT_TO_HASH).ok_or_else(|| {
+            StorageError::DatabaseError("CF_HEIGHT_TO_HASH not found".to_string())
+        })?;
+        match self.db.get_cf(cf_height, height.to_be_bytes())? {
+            Some(bytes) => bytes
+                .as_ref()
+                .try_into()
+                .map_err(|_| StorageError::DatabaseError("Invalid hash size".to_string())),
+            None => Err(ForkChoiceError::UnknownNumber.into()),
+        }
+    }
+
+    /// Confirm that `hash` is on the main chain, i.e. it's the block the
+    /// height→hash index currently resolves its own height to. Returns
+    /// `ForkChoiceError::NotMain` for a known block that a reorg has since
+    /// moved onto a side chain.
+    pub fn require_main_chain(&self, hash: &Hash) -> Result<()> {
+        let header = self.get_header(hash)?.ok_or(ForkChoiceError::Unknown(*hash))?;
+        if &self.main_chain_hash_at(header.height)? != hash {
+            return Err(ForkChoiceError::NotMain.into());
+        }
         Ok(())
     }
 
@@ -260,6 +629,15 @@ impl BlockchainDB {
         }
     }
 
+    /// Get a block by its height as an [`IndexedBlock`], with its header hash
+    /// and per-transaction hashes already computed.
+    pub fn get_indexed_block_by_height(&self, height: u64) -> Result<Option<IndexedBlock>> {
+        match self.get_block_by_height(height)? {
+            Some(block) => Ok(Some(IndexedBlock::from(block))),
+            None => Ok(None),
+        }
+    }
+
     /// Get a block header by hash
     pub fn get_header(&self, hash: &Hash) -> Result<Option<BlockHeader>> {
         let cf_headers = self
@@ -276,6 +654,27 @@ impl BlockchainDB {
         }
     }
 
+    /// Get a block header by height, without needing its body present.
+    ///
+    /// Unlike [`Self::get_block_by_height`], this resolves purely through
+    /// `CF_HEIGHT_TO_HASH` + `CF_HEADERS`, so it still works for heights
+    /// whose body was dropped by [`Self::prune_bodies_before`].
+    pub fn get_header_by_height(&self, height: u64) -> Result<Option<BlockHeader>> {
+        let cf_height = self.db.cf_handle(CF_HEIGHT_TO_HASH).ok_or_else(|| {
+            StorageError::DatabaseError("CF_HEIGHT_TO_HASH not found".to_string())
+        })?;
+
+        match self.db.get_cf(cf_height, height.to_be_bytes())? {
+            Some(hash_bytes) => {
+                let hash: Hash = hash_bytes
+                    .try_into()
+                    .map_err(|_| StorageError::DatabaseError("Invalid hash size".to_string()))?;
+                self.get_header(&hash)
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Get a transaction by its hash
     pub fn get_transaction(&self, hash: &Hash) -> Result<Option<Transaction>> {
         let cf_txs = self.db.cf_handle(CF_TRANSACTIONS).ok_or_else(|| {
@@ -569,6 +968,199 @@ impl BlockchainDB {
         Ok(pruned)
     }
 
+    /// Drop full block bodies (and their indexed transactions) for every
+    /// height below `horizon`, keeping headers and the height→hash index.
+    ///
+    /// This is what makes a pruned node able to *serve* a horizon sync to a
+    /// later joiner: `get_header_by_height` keeps working for pruned
+    /// heights, and [`Self::sync_from_horizon`] only ever needs bodies
+    /// above its own horizon. `get_block`/`get_block_by_height` return
+    /// `None` for pruned heights once this has run.
+    ///
+    /// Returns the number of bodies pruned.
+    pub fn prune_bodies_before(&self, horizon: u64) -> Result<usize> {
+        let cf_blocks = self
+            .db
+            .cf_handle(CF_BLOCKS)
+            .ok_or_else(|| StorageError::DatabaseError("CF_BLOCKS not found".to_string()))?;
+        let cf_txs = self.db.cf_handle(CF_TRANSACTIONS).ok_or_else(|| {
+            StorageError::DatabaseError("CF_TRANSACTIONS not found".to_string())
+        })?;
+        let cf_tx_to_block = self.db.cf_handle(CF_TX_TO_BLOCK).ok_or_else(|| {
+            StorageError::DatabaseError("CF_TX_TO_BLOCK not found".to_string())
+        })?;
+
+        let mut batch = WriteBatch::default();
+        let mut pruned = 0usize;
+
+        for height in 0..horizon {
+            let block = match self.get_block_by_height(height)? {
+                Some(b) => b,
+                None => continue, // already pruned, or never stored on this node
+            };
+
+            batch.delete_cf(cf_blocks, block.hash());
+            for tx in &block.transactions {
+                let tx_hash = tx.hash();
+                batch.delete_cf(cf_txs, tx_hash);
+                batch.delete_cf(cf_tx_to_block, tx_hash);
+            }
+            pruned += 1;
+        }
+
+        if pruned > 0 {
+            self.db.write(batch).map_err(|e| {
+                StorageError::DatabaseError(format!("Failed to write prune batch: {}", e))
+            })?;
+            tracing::info!("Pruned {} block bodies below height {} (headers retained)", pruned, horizon);
+        }
+
+        Ok(pruned)
+    }
+
+    /// Write a header (and its height index entry) without a body.
+    ///
+    /// Used by [`Self::sync_from_horizon`] to lay down the header chain
+    /// ahead of body sync, and equivalent to what survives locally once
+    /// [`Self::prune_bodies_before`] has run on the same height.
+    pub fn store_header_only(&self, header: &BlockHeader) -> Result<()> {
+        let hash = header.hash();
+
+        let cf_headers = self
+            .db
+            .cf_handle(CF_HEADERS)
+            .ok_or_else(|| StorageError::DatabaseError("CF_HEADERS not found".to_string()))?;
+        let cf_height = self.db.cf_handle(CF_HEIGHT_TO_HASH).ok_or_else(|| {
+            StorageError::DatabaseError("CF_HEIGHT_TO_HASH not found".to_string())
+        })?;
+
+        let mut batch = WriteBatch::default();
+        batch.put_cf(cf_headers, hash, bincode::serialize(header)?);
+        batch.put_cf(cf_height, header.height.to_be_bytes(), hash);
+        self.db.write(batch)?;
+
+        Ok(())
+    }
+
+    /// Directly set the main-chain tip metadata, bypassing fork choice.
+    ///
+    /// Only safe to call when the caller has independently established
+    /// that `hash` really is the header at `height` on a validated chain —
+    /// [`Self::sync_from_horizon`] uses this to seed the tip at the horizon
+    /// before any body has been stored, so the first body downloaded above
+    /// the horizon extends the main chain instead of looking like a new
+    /// genesis.
+    fn set_best_tip(&self, height: u64, hash: Hash) -> Result<()> {
+        let cf_meta = self
+            .db
+            .cf_handle(CF_METADATA)
+            .ok_or_else(|| StorageError::DatabaseError("CF_METADATA not found".to_string()))?;
+
+        let mut batch = WriteBatch::default();
+        batch.put_cf(cf_meta, b"best_height", height.to_be_bytes());
+        batch.put_cf(cf_meta, BEST_HASH_KEY, hash);
+        self.db.write(batch)?;
+
+        Ok(())
+    }
+
+    /// Copy every key in `source`'s default column family into this database.
+    ///
+    /// Account state (and contract code, HNSW indexes, …) all live in the
+    /// default CF without per-height versioning — the latest value for a
+    /// key *is* the state at the source's current tip. A full copy
+    /// therefore also carries over accounts that were created at genesis
+    /// and later spent to zero (set_balance never deletes the key), so the
+    /// reconstructed state root below still matches even though those
+    /// accounts are now empty.
+    fn copy_default_cf(&self, source: &BlockchainDB) -> Result<usize> {
+        let mut batch = WriteBatch::default();
+        let mut count = 0usize;
+
+        for item in source.db.iterator(rocksdb::IteratorMode::Start) {
+            let (key, value) = item?;
+            batch.put(&key, &value);
+            count += 1;
+        }
+
+        self.db.write(batch)?;
+        Ok(count)
+    }
+
+    /// Bootstrap this (presumably empty) database from `source` using a
+    /// pruning horizon instead of replaying every block from genesis:
+    /// transfer the full header chain (validating continuity), the current
+    /// state snapshot, and then download full bodies only for heights
+    /// above `horizon`.
+    ///
+    /// `horizon` would typically be `source.get_best_height() - retention`
+    /// (see [`crate::maintenance::PruningConfig::horizon`]). After this
+    /// returns, `self` can itself serve a horizon sync to a later joiner —
+    /// it has every header, the full current account state, and bodies for
+    /// every height above its own horizon.
+    pub fn sync_from_horizon(&self, source: &BlockchainDB, horizon: u64) -> Result<HorizonSyncReport> {
+        let source_tip = source.get_best_height()?.unwrap_or(0);
+        if horizon > source_tip {
+            return Err(StorageError::DatabaseError(format!(
+                "horizon {} is above source tip {}",
+                horizon, source_tip
+            )));
+        }
+
+        // Transfer and validate the full header chain, so the joiner can
+        // verify continuity even for the heights whose bodies it will
+        // never download.
+        let mut headers_synced = 0u64;
+        let mut prev_hash: Option<Hash> = None;
+        for height in 0..=source_tip {
+            let header = source.get_header_by_height(height)?.ok_or_else(|| {
+                StorageError::DatabaseError(format!("source missing header at height {}", height))
+            })?;
+
+            if let Some(expected_prev) = prev_hash {
+                if header.previous_hash != expected_prev {
+                    return Err(StorageError::DatabaseError(format!(
+                        "header chain broken at height {}: previous_hash mismatch",
+                        height
+                    )));
+                }
+            }
+            prev_hash = Some(header.hash());
+
+            self.store_header_only(&header)?;
+            headers_synced += 1;
+        }
+
+        // Seed the tip at the horizon so the first body downloaded below
+        // extends the main chain rather than being treated as an orphan.
+        let horizon_header = source.get_header_by_height(horizon)?.ok_or_else(|| {
+            StorageError::DatabaseError(format!("source missing header at horizon {}", horizon))
+        })?;
+        self.set_best_tip(horizon, horizon_header.hash())?;
+
+        let accounts_synced = self.copy_default_cf(source)? as u64;
+
+        let mut bodies_downloaded = 0u64;
+        for height in (horizon + 1)..=source_tip {
+            if let Some(block) = source.get_block_by_height(height)? {
+                self.store_block(&block)?;
+                bodies_downloaded += 1;
+            }
+        }
+
+        tracing::info!(
+            "Horizon sync complete: {} header(s), {} account entries, {} bodies above horizon {}",
+            headers_synced, accounts_synced, bodies_downloaded, horizon
+        );
+
+        Ok(HorizonSyncReport {
+            horizon,
+            headers_synced,
+            accounts_synced,
+            bodies_downloaded,
+        })
+    }
+
     // ==================== EVM STATE PERSISTENCE ====================
     // These methods allow the EvmExecutor to persist contract storage
     // and account state to RocksDB, surviving node restarts.
@@ -766,6 +1358,29 @@ mod tests {
         }
     }
 
+    /// Build a block extending `parent`. `fork_tag` only needs to differ
+    /// between sibling branches built on the same parent, so their hashes
+    /// (and thus the chains they head) diverge.
+    fn child_of(parent: &Block, fork_tag: u8) -> Block {
+        Block {
+            header: BlockHeader {
+                version: 1,
+                height: parent.header.height + 1,
+                timestamp: 1000 + parent.header.height + 1,
+                previous_hash: parent.hash(),
+                state_root: [0u8; 32],
+                txs_root: [0u8; 32],
+                receipts_root: [0u8; 32],
+                validator: [fork_tag; 32],
+                signature: vec![0u8; 64],
+                gas_used: 0,
+                gas_limit: 1000000,
+                extra_data: vec![fork_tag],
+            },
+            transactions: vec![],
+        }
+    }
+
     #[test]
     fn test_db_creation() {
         let temp_dir = TempDir::new().unwrap();
@@ -801,6 +1416,22 @@ mod tests {
         assert_eq!(retrieved.unwrap().header.height, 5);
     }
 
+    #[test]
+    fn test_store_and_get_indexed_block() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = BlockchainDB::open(temp_dir.path()).unwrap();
+
+        let block = create_test_block_with_tx(1);
+        let indexed = luxtensor_core::IndexedBlock::from(block.clone());
+
+        db.store_indexed_block(&indexed).unwrap();
+
+        let retrieved = db.get_indexed_block_by_height(1).unwrap().unwrap();
+        assert_eq!(retrieved.header_hash, block.hash());
+        assert_eq!(retrieved.tx_hashes, vec![block.transactions[0].hash()]);
+        assert_eq!(retrieved.transactions.len(), 1);
+    }
+
     #[test]
     fn test_get_header() {
         let temp_dir = TempDir::new().unwrap();
@@ -850,12 +1481,145 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let db = BlockchainDB::open(temp_dir.path()).unwrap();
 
-        db.store_block(&create_test_block(1)).unwrap();
-        db.store_block(&create_test_block(5)).unwrap();
-        db.store_block(&create_test_block(3)).unwrap();
+        let genesis = create_test_block(0);
+        db.store_block(&genesis).unwrap();
+        let b1 = child_of(&genesis, 1);
+        db.store_block(&b1).unwrap();
+        let b2 = child_of(&b1, 1);
+        db.store_block(&b2).unwrap();
 
         let best_height = db.get_best_height().unwrap();
-        assert_eq!(best_height, Some(5));
+        assert_eq!(best_height, Some(2));
+    }
+
+    // -----------------------------------------------------------------------
+    // Fork choice
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_store_block_linear_chain_is_always_main() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = BlockchainDB::open(temp_dir.path()).unwrap();
+
+        let genesis = create_test_block(0);
+        assert_eq!(db.store_block(&genesis).unwrap(), BlockInsertedChain::Main);
+        let b1 = child_of(&genesis, 1);
+        assert_eq!(db.store_block(&b1).unwrap(), BlockInsertedChain::Main);
+    }
+
+    #[test]
+    fn test_store_block_buffers_orphan_instead_of_rejecting() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = BlockchainDB::open(temp_dir.path()).unwrap();
+
+        db.store_block(&create_test_block(0)).unwrap();
+
+        // previous_hash is the zero hash, which isn't a known block — it
+        // gets buffered rather than rejected, and isn't on disk yet.
+        let orphan = create_test_block(5);
+        assert_eq!(db.store_block(&orphan).unwrap(), BlockInsertedChain::Orphan);
+        assert!(db.get_block(&orphan.hash()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_orphan_connects_once_parent_arrives() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = BlockchainDB::open(temp_dir.path()).unwrap();
+
+        let genesis = create_test_block(0);
+        db.store_block(&genesis).unwrap();
+
+        // b2 arrives before its parent b1 — buffered as an orphan.
+        let b1 = child_of(&genesis, 1);
+        let b2 = child_of(&b1, 1);
+        assert_eq!(db.store_block(&b2).unwrap(), BlockInsertedChain::Orphan);
+        assert!(db.get_block(&b2.hash()).unwrap().is_none());
+
+        // Once b1 lands, it should pull b2 in behind it automatically.
+        assert_eq!(db.store_block(&b1).unwrap(), BlockInsertedChain::Main);
+        assert_eq!(db.get_best_height().unwrap(), Some(2));
+        assert!(db.get_block(&b2.hash()).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_out_of_order_heavier_branch_reorgs_via_orphan_pool() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = BlockchainDB::open(temp_dir.path()).unwrap();
+
+        let genesis = create_test_block(0);
+        db.store_block(&genesis).unwrap();
+        let a1 = child_of(&genesis, 1);
+        db.store_block(&a1).unwrap();
+        assert_eq!(db.get_best_height().unwrap(), Some(1));
+
+        // A heavier side branch (b1, b2, b3) delivered tip-first: each one
+        // is an orphan until the branch's root, b1, finally connects to the
+        // already-known genesis.
+        let b1 = child_of(&genesis, 2);
+        let b2 = child_of(&b1, 2);
+        let b3 = child_of(&b2, 2);
+        assert_eq!(db.store_block(&b3).unwrap(), BlockInsertedChain::Orphan);
+        assert_eq!(db.store_block(&b2).unwrap(), BlockInsertedChain::Orphan);
+
+        // b1 connects to genesis directly, then pulls in b2 and b3, and the
+        // branch's accumulated work (3 blocks) overtakes main's (2 blocks).
+        match db.store_block(&b1).unwrap() {
+            BlockInsertedChain::Side => {}
+            other => panic!("expected b1 to land as a side chain first, got {other:?}"),
+        }
+        assert_eq!(db.get_best_height().unwrap(), Some(3));
+        assert_eq!(db.main_chain_hash_at(3).unwrap(), b3.hash());
+        assert!(db.get_block(&b2.hash()).unwrap().is_some());
+        assert!(db.get_block(&b3.hash()).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_store_block_side_chain_reorganizes_once_it_overtakes_main() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = BlockchainDB::open(temp_dir.path()).unwrap();
+
+        let genesis = create_test_block(0);
+        db.store_block(&genesis).unwrap();
+        let a1 = child_of(&genesis, 1);
+        db.store_block(&a1).unwrap();
+        let a2 = child_of(&a1, 1);
+        db.store_block(&a2).unwrap();
+        assert_eq!(db.get_best_height().unwrap(), Some(2));
+
+        // A side branch off genesis, same height as a1 — doesn't overtake main.
+        let b1 = child_of(&genesis, 2);
+        assert_eq!(db.store_block(&b1).unwrap(), BlockInsertedChain::Side);
+        assert_eq!(db.get_best_height().unwrap(), Some(2));
+
+        // Extending the side branch past main's tip triggers a reorg.
+        let b2 = child_of(&b1, 2);
+        assert_eq!(db.store_block(&b2).unwrap(), BlockInsertedChain::Side);
+        let b3 = child_of(&b2, 2);
+        match db.store_block(&b3).unwrap() {
+            BlockInsertedChain::Reorganized { old_tip, new_tip, rolled_back, applied } => {
+                assert_eq!(old_tip, a2.hash());
+                assert_eq!(new_tip, b3.hash());
+                assert_eq!(rolled_back, vec![a2.hash(), a1.hash()]);
+                assert_eq!(applied, vec![b1.hash(), b2.hash(), b3.hash()]);
+            }
+            other => panic!("expected Reorganized, got {other:?}"),
+        }
+
+        assert_eq!(db.get_best_height().unwrap(), Some(3));
+        assert_eq!(db.main_chain_hash_at(1).unwrap(), b1.hash());
+        assert_eq!(db.main_chain_hash_at(2).unwrap(), b2.hash());
+        db.require_main_chain(&b3.hash()).unwrap();
+        assert!(db.require_main_chain(&a1.hash()).is_err());
+    }
+
+    #[test]
+    fn test_main_chain_hash_at_unknown_height() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = BlockchainDB::open(temp_dir.path()).unwrap();
+        db.store_block(&create_test_block(0)).unwrap();
+
+        let err = db.main_chain_hash_at(42).unwrap_err();
+        assert!(matches!(err, StorageError::ForkChoice(ForkChoiceError::UnknownNumber)));
     }
 
     #[test]
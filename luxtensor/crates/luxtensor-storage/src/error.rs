@@ -28,6 +28,34 @@ pub enum StorageError {
 
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
+
+    #[error("fork choice: {0}")]
+    ForkChoice(#[from] ForkChoiceError),
+
+    #[error("block validation failed: {0}")]
+    ValidationFailed(String),
+}
+
+/// Consistency errors surfaced by [`crate::db::BlockchainDB::store_block`]'s
+/// fork-choice logic, kept distinct from `StorageError::DatabaseError` so
+/// callers can tell a genuinely invalid/inconsistent block from an orphan
+/// that simply hasn't arrived yet or a backend I/O failure.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ForkChoiceError {
+    #[error("unknown parent block: {0:?}")]
+    Unknown([u8; 32]),
+
+    #[error("unknown block number")]
+    UnknownNumber,
+
+    #[error("block is not on the main chain")]
+    NotMain,
+
+    #[error("transaction already confirmed on the main chain")]
+    DoubleSpend,
+
+    #[error("side chain reorg exceeds the maximum allowed depth")]
+    ForkTooLong,
 }
 
 impl From<rocksdb::Error> for StorageError {
@@ -1,6 +1,7 @@
 // LuxTensor storage module
 // Phase 4: Storage Layer implementation
 
+pub mod block_queue;
 pub mod bridge_store;
 pub mod cache;
 pub mod checkpoint;
@@ -13,8 +14,13 @@ pub mod merkle_cache;
 pub mod state_db;
 pub mod trie;
 
-pub use checkpoint::{CheckpointManager, CheckpointMetadata, CheckpointConfig, CHECKPOINT_INTERVAL, MAX_CHECKPOINTS};
-pub use db::BlockchainDB;
+pub use block_queue::{BlockQueue, BlockQueueInfo, BlockValidator};
+pub use checkpoint::{
+    CheckpointConfig, CheckpointError, CheckpointManager, CheckpointMetadata, SnapshotChunk,
+    CHECKPOINT_INTERVAL, MAX_CHECKPOINTS, SNAPSHOT_CHUNK_SIZE,
+};
+pub use db::{BlockInsertedChain, BlockchainDB, HorizonSyncReport};
+pub use luxtensor_core::IndexedBlock;
 pub use error::*;
 pub use evm_store::{EvmAccountRecord, EvmStateStore};
 pub use maintenance::{DbMaintenance, BackupConfig, PruningConfig, BackupInfo, PruningStats};
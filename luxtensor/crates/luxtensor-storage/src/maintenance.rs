@@ -58,6 +58,26 @@ impl Default for PruningConfig {
     }
 }
 
+impl PruningConfig {
+    /// The horizon height `H` for horizon/pruned sync at `current_height`:
+    /// full state and bodies are kept from `H` onward, and everything
+    /// below it may be pruned down to headers only.
+    ///
+    /// Uses the same retention window as [`DbMaintenance::get_pruning_stats`]
+    /// (`keep_last_n_blocks`, falling back to `keep_blocks_after`), so a
+    /// node's own pruning policy and the horizon it advertises to syncing
+    /// peers never disagree.
+    pub fn horizon(&self, current_height: u64) -> u64 {
+        if self.keep_last_n_blocks > 0 {
+            current_height.saturating_sub(self.keep_last_n_blocks)
+        } else if self.keep_blocks_after > 0 {
+            self.keep_blocks_after
+        } else {
+            0
+        }
+    }
+}
+
 /// Database maintenance manager
 pub struct DbMaintenance {
     db_path: PathBuf,
@@ -171,13 +191,7 @@ impl DbMaintenance {
 
     /// Get pruning statistics
     pub fn get_pruning_stats(&self, current_height: u64) -> PruningStats {
-        let prune_blocks_before = if self.pruning_config.keep_last_n_blocks > 0 {
-            current_height.saturating_sub(self.pruning_config.keep_last_n_blocks)
-        } else if self.pruning_config.keep_blocks_after > 0 {
-            self.pruning_config.keep_blocks_after
-        } else {
-            0
-        };
+        let prune_blocks_before = self.pruning_config.horizon(current_height);
 
         let prune_receipts_before = current_height
             .saturating_sub(self.pruning_config.prune_receipts_after);
@@ -187,9 +201,16 @@ impl DbMaintenance {
             prune_blocks_before,
             prune_receipts_before,
             estimated_prunable_blocks: prune_blocks_before,
+            prunable_body_range: (prune_blocks_before > 0).then_some((0, prune_blocks_before)),
         }
     }
 
+    /// The horizon height a horizon/pruned sync of this node would
+    /// advertise to a joining peer — see [`PruningConfig::horizon`].
+    pub fn horizon(&self, current_height: u64) -> u64 {
+        self.pruning_config.horizon(current_height)
+    }
+
     /// Check if auto-prune should run
     pub fn should_auto_prune(&self, current_height: u64) -> bool {
         self.pruning_config.auto_prune &&
@@ -267,6 +288,12 @@ pub struct PruningStats {
     pub prune_blocks_before: u64,
     pub prune_receipts_before: u64,
     pub estimated_prunable_blocks: u64,
+    /// Half-open height range `[start, end)` whose block bodies are
+    /// prunable under the current horizon, or `None` if nothing is
+    /// prunable yet. Mirrors what [`crate::db::BlockchainDB::prune_bodies_before`]
+    /// would act on and what [`crate::db::BlockchainDB::sync_from_horizon`]
+    /// expects a serving peer to have already dropped.
+    pub prunable_body_range: Option<(u64, u64)>,
 }
 
 #[cfg(test)]
@@ -8,10 +8,10 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Key prefix for contract code storage
-const CONTRACT_CODE_PREFIX: &[u8] = b"code:";
+pub(crate) const CONTRACT_CODE_PREFIX: &[u8] = b"code:";
 
 /// Key prefix for HNSW vector index storage
-const HNSW_INDEX_PREFIX: &[u8] = b"hnsw:";
+pub(crate) const HNSW_INDEX_PREFIX: &[u8] = b"hnsw:";
 
 /// State database with RocksDB backend and LRU cache
 pub struct StateDB {
@@ -145,12 +145,13 @@ fn benchmark_storage_operations(c: &mut Criterion) {
 
     c.bench_function("storage_store_block", |b| {
         let mut height = 1u64;
+        let mut previous_hash = genesis.hash();
         b.iter(|| {
             let header = luxtensor_core::BlockHeader {
                 version: 1,
                 height,
                 timestamp: 0,
-                previous_hash: genesis.hash(),
+                previous_hash,
                 state_root: [0u8; 32],
                 txs_root: [0u8; 32],
                 receipts_root: [0u8; 32],
@@ -162,6 +163,7 @@ fn benchmark_storage_operations(c: &mut Criterion) {
                 vrf_proof: None,
             };
             let block = Block::new(header, vec![]);
+            previous_hash = block.hash();
             black_box(storage.store_block(&block).unwrap());
             height += 1;
         });
@@ -81,6 +81,218 @@ pub fn fuzz_rpc_json(data: &[u8]) -> bool {
     false
 }
 
+/// Round-trip fuzz target for the crate's own RPC parameter codec —
+/// `luxtensor_rpc::helpers::parse_address`/`parse_hash`/`parse_amount` —
+/// rather than `serde_json::Value`. The encode side (`format!("0x{}",
+/// hex::encode(..))`) is the exact pattern every `RpcTransaction`/
+/// `RpcBlock` `From` impl in `luxtensor-rpc/src/types.rs` uses to produce
+/// hex fields; the decode side is the parser every RPC handler in
+/// `server.rs` calls on incoming params. Unlike a `Value`-to-`Value`
+/// roundtrip, this checks one in-house function's output against another
+/// in-house function's input, so a bug in either `hex` handling or the
+/// parsers' 20/32-byte length checks can actually make the assertion fire.
+pub fn fuzz_rpc_roundtrip(data: &[u8]) -> bool {
+    const ADDRESS_LEN: usize = 20;
+    const HASH_LEN: usize = 32;
+    const AMOUNT_LEN: usize = 16;
+
+    if data.len() < ADDRESS_LEN + HASH_LEN + AMOUNT_LEN {
+        return false;
+    }
+
+    let (address_bytes, rest) = data.split_at(ADDRESS_LEN);
+    let (hash_bytes, amount_bytes) = rest.split_at(HASH_LEN);
+
+    let address_hex = format!("0x{}", hex::encode(address_bytes));
+    let decoded_address = luxtensor_rpc::helpers::parse_address(&address_hex).unwrap_or_else(|e| {
+        panic!("parse_address rejected its own encoder's output: {e:?}\nhex: {address_hex}")
+    });
+    assert_eq!(decoded_address.as_bytes(), address_bytes, "address roundtrip changed the bytes");
+
+    let hash_hex = format!("0x{}", hex::encode(hash_bytes));
+    let decoded_hash = luxtensor_rpc::helpers::parse_hash(&hash_hex).unwrap_or_else(|e| {
+        panic!("parse_hash rejected its own encoder's output: {e:?}\nhex: {hash_hex}")
+    });
+    assert_eq!(&decoded_hash, hash_bytes, "hash roundtrip changed the bytes");
+
+    let amount = u128::from_be_bytes(amount_bytes[..AMOUNT_LEN].try_into().unwrap());
+    let amount_hex = format!("0x{:x}", amount);
+    let decoded_amount = luxtensor_rpc::helpers::parse_amount(&amount_hex).unwrap_or_else(|e| {
+        panic!("parse_amount rejected its own encoder's output: {e:?}\nhex: {amount_hex}")
+    });
+    assert_eq!(decoded_amount, amount, "amount roundtrip changed the value");
+
+    true
+}
+
+/// Entry-point agreement fuzz target for RPC JSON parsing. Not a
+/// differential test against an independent parser: this codebase has no
+/// in-house JSON parser, only `serde_json`, so there is nothing to put on
+/// the other side of such a comparison — every RPC entry point (see
+/// `luxtensor-rpc/src/helpers.rs` and friends, and `fuzz_rpc_json` above)
+/// parses by calling `serde_json` directly.
+///
+/// What this checks instead is the two entry points the RPC layer actually
+/// uses into that shared parser: `from_str` over a UTF-8-validated `&str`
+/// (what `fuzz_rpc_json` does) versus `from_slice` over the raw bytes. Both
+/// are expected to agree on every input — JSON is UTF-8-only, so
+/// `from_slice` must reject the same non-UTF-8 bytes `from_str`'s
+/// precondition already filters out — so any disagreement is a genuine
+/// conformance bug in how one of them handles duplicate keys, large
+/// integers, leading zeros, or invalid encoding.
+pub fn fuzz_rpc_json_entrypoint_agreement(data: &[u8]) -> bool {
+    let str_result =
+        std::str::from_utf8(data).ok().and_then(|s| serde_json::from_str::<serde_json::Value>(s).ok());
+    let slice_result = serde_json::from_slice::<serde_json::Value>(data).ok();
+
+    match (&str_result, &slice_result) {
+        (Some(a), Some(b)) => {
+            assert_eq!(a, b, "from_str and from_slice parses of the same bytes produced different values");
+        }
+        (None, None) => {}
+        _ => panic!("from_str and from_slice disagreed on accept-vs-reject for the same bytes"),
+    }
+
+    str_result.is_some()
+}
+
+/// One JSON-RPC parameter value a structured fuzz request can carry.
+/// Kept to a small closed set (rather than arbitrary `serde_json::Value`,
+/// which has no `Arbitrary` impl) so the fuzzer's budget goes into method
+/// dispatch rather than reinventing a JSON value generator.
+#[derive(Debug, Clone, arbitrary::Arbitrary)]
+pub enum FuzzRpcParam {
+    Num(i64),
+    Str(String),
+    Bool(bool),
+}
+
+/// The RPC method space this target explores. `Unknown` carries an
+/// arbitrary method name so unrecognized-method handling gets fuzzed too,
+/// without the generator spending most of its budget on names that never
+/// match a known method.
+#[derive(Debug, Clone, arbitrary::Arbitrary)]
+pub enum FuzzRpcMethod {
+    BlockNumber,
+    GetBalance,
+    SendRawTransaction,
+    Subscribe,
+    Unsubscribe,
+    Unknown(String),
+}
+
+impl FuzzRpcMethod {
+    fn as_str(&self) -> &str {
+        match self {
+            FuzzRpcMethod::BlockNumber => "eth_blockNumber",
+            FuzzRpcMethod::GetBalance => "eth_getBalance",
+            FuzzRpcMethod::SendRawTransaction => "eth_sendRawTransaction",
+            FuzzRpcMethod::Subscribe => "subscribe",
+            FuzzRpcMethod::Unsubscribe => "unsubscribe",
+            FuzzRpcMethod::Unknown(name) => name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, arbitrary::Arbitrary)]
+pub enum FuzzRpcId {
+    Number(u64),
+    Str(String),
+    Null,
+}
+
+/// One structured JSON-RPC request: method, params, and id kind.
+#[derive(Debug, Clone, arbitrary::Arbitrary)]
+pub struct FuzzRpcRequest {
+    pub method: FuzzRpcMethod,
+    pub params: Vec<FuzzRpcParam>,
+    pub id: FuzzRpcId,
+}
+
+/// Single request or a batch of them — the other axis `fuzz_rpc_structured`
+/// explores.
+#[derive(Debug, Clone, arbitrary::Arbitrary)]
+pub enum FuzzRpcCall {
+    Single(FuzzRpcRequest),
+    Batch(Vec<FuzzRpcRequest>),
+}
+
+/// Build a throwaway JSON-RPC handler table wired the same way
+/// `luxtensor-rpc`'s own test helper (`create_test_setup`) wires a server
+/// under test: a temp-dir-backed `BlockchainDB` and a fresh `StateDB`, fed
+/// into `RpcServer::new_for_testing`. Returns the handler alongside the
+/// `TempDir` so the backing directory stays alive for as long as the
+/// handler is used.
+///
+/// Add to Cargo.toml: luxtensor-rpc = { path = "../luxtensor-rpc" }, luxtensor-storage = { path = "../luxtensor-storage" }, jsonrpc-core = "18", parking_lot = "0.12", tempfile = "3"
+fn build_test_io_handler() -> (tempfile::TempDir, jsonrpc_core::IoHandler) {
+    let temp_dir = tempfile::tempdir().expect("create temp dir for fuzz RPC server");
+    let db_path = temp_dir.path().join("blockchain");
+    let db = std::sync::Arc::new(
+        luxtensor_storage::BlockchainDB::open(&db_path).expect("open test blockchain db"),
+    );
+    let state = std::sync::Arc::new(parking_lot::RwLock::new(luxtensor_core::StateDB::new()));
+    let server = luxtensor_rpc::RpcServer::new_for_testing(db, state);
+    (temp_dir, server.build_io_handler())
+}
+
+fn request_to_json(req: &FuzzRpcRequest) -> serde_json::Value {
+    let params: Vec<serde_json::Value> = req
+        .params
+        .iter()
+        .map(|p| match p {
+            FuzzRpcParam::Num(n) => serde_json::json!(n),
+            FuzzRpcParam::Str(s) => serde_json::json!(s),
+            FuzzRpcParam::Bool(b) => serde_json::json!(b),
+        })
+        .collect();
+    let id = match &req.id {
+        FuzzRpcId::Number(n) => serde_json::json!(n),
+        FuzzRpcId::Str(s) => serde_json::json!(s),
+        FuzzRpcId::Null => serde_json::Value::Null,
+    };
+    serde_json::json!({ "jsonrpc": "2.0", "method": req.method.as_str(), "params": params, "id": id })
+}
+
+/// Arbitrary-driven structured RPC fuzz target. Instead of feeding raw
+/// bytes to the tokenizer, the fuzzer generates a `FuzzRpcCall` directly
+/// from the corpus via `Arbitrary`, so every run exercises a well-formed
+/// JSON-RPC request (or batch) and spends its budget on the real dispatch
+/// layer rather than mostly-rejected garbage bytes. Every generated request
+/// is routed through `build_test_io_handler`'s `IoHandler` — the exact
+/// handler table `RpcServer::start` serves over HTTP, built by the same
+/// `register_*` calls — via `jsonrpc_core`'s `handle_request_sync`, so this
+/// exercises the crate's actual `eth_*` method registrations rather than a
+/// harness-local stand-in. Every request carries an id, so it must come
+/// back with a well-formed JSON-RPC response (`result` or `error`, never a
+/// dropped notification), without panicking.
+pub fn fuzz_rpc_structured(call: &FuzzRpcCall) -> bool {
+    let requests: Vec<&FuzzRpcRequest> = match call {
+        FuzzRpcCall::Single(req) => vec![req],
+        FuzzRpcCall::Batch(reqs) => reqs.iter().collect(),
+    };
+
+    let (_temp_dir, io) = build_test_io_handler();
+
+    for req in requests {
+        let value = request_to_json(req);
+        let serialized = serde_json::to_string(&value).expect("serializing a structured request never fails");
+        assert!(fuzz_rpc_json(serialized.as_bytes()), "structured request failed to parse as RPC JSON: {serialized}");
+
+        let response = io
+            .handle_request_sync(&serialized)
+            .unwrap_or_else(|| panic!("request with an id must never be treated as a notification: {serialized}"));
+        let response_value: serde_json::Value = serde_json::from_str(&response)
+            .unwrap_or_else(|e| panic!("dispatch produced non-JSON response: {e}\nresponse: {response}"));
+        assert!(
+            response_value.get("result").is_some() || response_value.get("error").is_some(),
+            "dispatch response had neither result nor error: {response}"
+        );
+    }
+
+    true
+}
+
 /// Fuzz address parsing (hex to bytes)
 pub fn fuzz_address_parser(data: &[u8]) -> bool {
     if let Ok(s) = std::str::from_utf8(data) {
@@ -235,6 +447,52 @@ mod tests {
         assert!(fuzz_keccak256(data));
     }
 
+    #[test]
+    fn test_fuzz_rpc_roundtrip_accepts_enough_bytes() {
+        let data = vec![0x42u8; 20 + 32 + 16];
+        assert!(fuzz_rpc_roundtrip(&data));
+    }
+
+    #[test]
+    fn test_fuzz_rpc_roundtrip_rejects_too_short_input() {
+        assert!(!fuzz_rpc_roundtrip(&[0u8; 10]));
+    }
+
+    #[test]
+    fn test_fuzz_rpc_json_entrypoint_agreement_on_valid_json() {
+        let valid = r#"{"jsonrpc":"2.0","method":"eth_blockNumber","params":[],"id":1}"#;
+        assert!(fuzz_rpc_json_entrypoint_agreement(valid.as_bytes()));
+    }
+
+    #[test]
+    fn test_fuzz_rpc_json_entrypoint_agreement_on_invalid_json() {
+        assert!(!fuzz_rpc_json_entrypoint_agreement(b"not json at all {{{{"));
+    }
+
+    #[test]
+    fn test_fuzz_rpc_json_entrypoint_agreement_on_non_utf8() {
+        assert!(!fuzz_rpc_json_entrypoint_agreement(&[0xff, 0xfe, 0x00, 0x01]));
+    }
+
+    #[test]
+    fn test_fuzz_rpc_structured_single_known_method() {
+        let call = FuzzRpcCall::Single(FuzzRpcRequest {
+            method: FuzzRpcMethod::BlockNumber,
+            params: vec![FuzzRpcParam::Num(1), FuzzRpcParam::Str("x".to_string())],
+            id: FuzzRpcId::Number(1),
+        });
+        assert!(fuzz_rpc_structured(&call));
+    }
+
+    #[test]
+    fn test_fuzz_rpc_structured_batch_with_unknown_method() {
+        let call = FuzzRpcCall::Batch(vec![
+            FuzzRpcRequest { method: FuzzRpcMethod::Unknown("does_not_exist".to_string()), params: vec![], id: FuzzRpcId::Null },
+            FuzzRpcRequest { method: FuzzRpcMethod::Subscribe, params: vec![FuzzRpcParam::Bool(true)], id: FuzzRpcId::Str("a".to_string()) },
+        ]);
+        assert!(fuzz_rpc_structured(&call));
+    }
+
     #[test]
     fn test_fuzz_value_parser() {
         let data = [0u8, 0, 0, 0, 0, 0, 0, 0];